@@ -0,0 +1,35 @@
+use notion_client::{
+    endpoints::{blocks::retrieve::request::RetrieveBlockTreeOptionsBuilder, Client},
+    render,
+};
+use reqwest::ClientBuilder;
+
+const NOTION_PAGE_ID: &str = "";
+const NOTION_TOKEN: &str = "";
+
+#[tokio::main]
+async fn main() {
+    // Initialize client
+    let client = Client::new(NOTION_TOKEN.to_string(), Some(ClientBuilder::new()));
+    let Ok(client) = client else {
+        panic!("error");
+    };
+
+    // Set up options
+    let options = RetrieveBlockTreeOptionsBuilder::default()
+        .max_depth(5)
+        .build()
+        .unwrap();
+
+    // Send requests
+    let Ok(blocks) = client
+        .blocks
+        .retrieve_block_tree(NOTION_PAGE_ID, options)
+        .await
+    else {
+        panic!("error");
+    };
+
+    // See result
+    print!("{}", render::markdown::render(&blocks));
+}
@@ -0,0 +1,238 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, LitStr, Type};
+
+const KINDS: [(&str, &str); 14] = [
+    ("title", "Title"),
+    ("rich_text", "RichText"),
+    ("number", "Number"),
+    ("select", "Select"),
+    ("multi_select", "MultiSelect"),
+    ("status", "Status"),
+    ("date", "Date"),
+    ("checkbox", "Checkbox"),
+    ("url", "Url"),
+    ("email", "Email"),
+    ("phone_number", "PhoneNumber"),
+    ("people", "People"),
+    ("relation", "Relation"),
+    ("files", "Files"),
+];
+
+/// Implements `notion_client::objects::notion_page::NotionPage` for a struct
+/// with named fields.
+///
+/// Field attributes:
+/// - `#[notion(title)]` stores the field in the title property
+/// - `#[notion(rename = "Due")]` sets the property name, the field name by default
+/// - `#[notion(type = "select")]` picks the property type when the field type supports several
+/// - `#[notion(skip)]` leaves the field out, filling it with `Default::default()`
+#[proc_macro_derive(NotionPage, attributes(notion))]
+pub fn derive_notion_page(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct Field {
+    ident: syn::Ident,
+    ty: Type,
+    name: String,
+    kind: Option<syn::Ident>,
+    skip: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "NotionPage can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "NotionPage can only be derived for structs",
+            ))
+        }
+    };
+
+    let fields = fields
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let titles = fields
+        .iter()
+        .filter(|f| matches!(&f.kind, Some(kind) if kind == "Title"))
+        .count();
+    if titles > 1 {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "a database can only have one title property",
+        ));
+    }
+
+    let krate = quote!(::notion_client::objects::notion_page);
+    let properties = fields.iter().filter(|f| !f.skip);
+    let kind = |field: &Field| {
+        let ty = &field.ty;
+        match &field.kind {
+            Some(kind) => quote!(#krate::PropertyKind::#kind),
+            None => quote!(<#ty as #krate::PropertyValue>::KINDS[0]),
+        }
+    };
+
+    let reads = fields.iter().map(|field| {
+        let Field {
+            ident, ty, name, ..
+        } = field;
+        if field.skip {
+            return quote!(#ident: ::core::default::Default::default());
+        }
+        let kind = kind(field);
+        quote! {
+            #ident: <#ty as #krate::PropertyValue>::from_property(
+                #name,
+                properties.get_property(#name),
+                #kind,
+            )?
+        }
+    });
+
+    let writes = properties.clone().map(|field| {
+        let Field {
+            ident, ty, name, ..
+        } = field;
+        let kind = kind(field);
+        quote! {
+            properties.insert(
+                #name.to_string(),
+                <#ty as #krate::PropertyValue>::to_property(&self.#ident, #name, #kind)?,
+            );
+        }
+    });
+
+    let schema = properties.clone().map(|field| {
+        let name = &field.name;
+        let kind = kind(field);
+        quote! {
+            if let ::core::option::Option::Some(property) = #kind.database_property() {
+                properties.insert(#name.to_string(), property);
+            }
+        }
+    });
+
+    // Generic structs cannot name their field types in a free constant, so
+    // they are only checked when converting.
+    let checks = if input.generics.params.is_empty() {
+        properties
+            .clone()
+            .filter_map(|field| {
+                let kind = field.kind.as_ref()?;
+                let ty = &field.ty;
+                let message = format!(
+                    "field `{}` cannot be stored as a {} property",
+                    field.ident,
+                    KINDS.iter().find(|(_, v)| kind == v).unwrap().0
+                );
+                Some(quote! {
+                    const _: () = ::core::assert!(
+                        #krate::PropertyKind::#kind.is_in(<#ty as #krate::PropertyValue>::KINDS),
+                        #message,
+                    );
+                })
+            })
+            .collect()
+    } else {
+        quote!()
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #checks
+
+        impl #impl_generics #krate::NotionPage for #ident #ty_generics #where_clause {
+            fn from_properties(
+                properties: &impl #krate::PropertyMap,
+            ) -> ::core::result::Result<Self, #krate::PropertyError> {
+                ::core::result::Result::Ok(Self { #(#reads,)* })
+            }
+
+            fn to_properties(
+                &self,
+            ) -> ::core::result::Result<
+                ::std::collections::BTreeMap<::std::string::String, ::notion_client::objects::page::PageProperty>,
+                #krate::PropertyError,
+            > {
+                let mut properties = ::std::collections::BTreeMap::new();
+                #(#writes)*
+                ::core::result::Result::Ok(properties)
+            }
+
+            fn database_properties(
+            ) -> ::std::collections::BTreeMap<::std::string::String, ::notion_client::objects::database::DatabaseProperty> {
+                let mut properties = ::std::collections::BTreeMap::new();
+                #(#schema)*
+                properties
+            }
+        }
+    })
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+    let ident = field.ident.clone().expect("named field");
+    let mut parsed = Field {
+        name: ident.to_string(),
+        ident,
+        ty: field.ty.clone(),
+        kind: None,
+        skip: false,
+    };
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("notion")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("title") {
+                set_kind(&mut parsed, "title", meta.path.span())
+            } else if meta.path.is_ident("rename") {
+                parsed.name = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("type") {
+                let kind: LitStr = meta.value()?.parse()?;
+                set_kind(&mut parsed, &kind.value(), kind.span())
+            } else if meta.path.is_ident("skip") {
+                parsed.skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `title`, `rename`, `type` or `skip`"))
+            }
+        })?;
+    }
+
+    Ok(parsed)
+}
+
+fn set_kind(field: &mut Field, name: &str, span: Span) -> syn::Result<()> {
+    if field.kind.is_some() {
+        return Err(Error::new(span, "property type is already set"));
+    }
+    let (_, variant) = KINDS.iter().find(|(k, _)| *k == name).ok_or_else(|| {
+        let names: Vec<_> = KINDS.iter().map(|(k, _)| *k).collect();
+        Error::new(
+            span,
+            format!(
+                "unknown property type, expected one of: {}",
+                names.join(", ")
+            ),
+        )
+    })?;
+    field.kind = Some(format_ident!("{}", variant));
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use notion_client::{
+    objects::{
+        id::PageId,
+        notion_page::{NotionPage, PropertyError},
+        page::PageProperty,
+        parent::Parent,
+    },
+    NotionPage,
+};
+use serde_json::json;
+
+#[derive(NotionPage, Debug, PartialEq)]
+struct Task {
+    #[notion(title)]
+    name: String,
+    #[notion(rename = "Due")]
+    due: Option<NaiveDate>,
+    tags: Vec<String>,
+    done: bool,
+}
+
+#[derive(NotionPage, Debug, PartialEq, Default)]
+struct Issue {
+    #[notion(title)]
+    title: String,
+    #[notion(type = "select")]
+    priority: String,
+    #[notion(type = "status")]
+    state: Option<String>,
+    estimate: Option<u32>,
+    #[notion(rename = "Blocked by")]
+    blocked_by: Vec<PageId>,
+    #[notion(skip)]
+    cached: Option<String>,
+}
+
+fn properties(value: serde_json::Value) -> BTreeMap<String, PageProperty> {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_to_properties() {
+    let task = Task {
+        name: "Write docs".to_string(),
+        due: NaiveDate::from_ymd_opt(2024, 3, 1),
+        tags: vec!["docs".to_string()],
+        done: false,
+    };
+
+    let properties = serde_json::to_value(task.to_properties().unwrap()).unwrap();
+    assert_eq!(
+        properties,
+        json!({
+            "Due": {
+                "type": "date",
+                "date": { "start": "2024-03-01", "end": null, "time_zone": null }
+            },
+            "done": { "type": "checkbox", "checkbox": false },
+            "name": {
+                "type": "title",
+                "title": [{ "type": "text", "text": { "content": "Write docs" } }]
+            },
+            "tags": { "type": "multi_select", "multi_select": [{ "name": "docs" }] }
+        })
+    );
+
+    assert_eq!(
+        Task::from_properties(&task.to_properties().unwrap()),
+        Ok(task)
+    );
+}
+
+#[test]
+fn test_from_properties() {
+    let properties = properties(json!({
+        "Due": { "id": "a", "type": "date", "date": null },
+        "done": { "id": "b", "type": "checkbox", "checkbox": true },
+        "name": {
+            "id": "title",
+            "type": "title",
+            "title": [{
+                "type": "text",
+                "text": { "content": "Ship it", "link": null },
+                "plain_text": "Ship it",
+                "href": null
+            }]
+        },
+        "tags": {
+            "id": "c",
+            "type": "multi_select",
+            "multi_select": [
+                { "id": "1", "name": "a", "color": "red" },
+                { "id": "2", "name": "b", "color": "blue" }
+            ]
+        }
+    }));
+
+    assert_eq!(
+        Task::from_properties(&properties),
+        Ok(Task {
+            name: "Ship it".to_string(),
+            due: None,
+            tags: vec!["a".to_string(), "b".to_string()],
+            done: true,
+        })
+    );
+}
+
+#[test]
+fn test_from_properties_errors() {
+    let mut properties = Task {
+        name: "Task".to_string(),
+        due: None,
+        tags: vec![],
+        done: true,
+    }
+    .to_properties()
+    .unwrap();
+
+    properties.remove("done");
+    assert_eq!(
+        Task::from_properties(&properties),
+        Err(PropertyError::Missing {
+            property: "done".to_string(),
+            expected: "checkbox",
+        })
+    );
+
+    properties.insert(
+        "done".to_string(),
+        PageProperty::Number {
+            id: None,
+            number: Some(1.into()),
+        },
+    );
+    let error = Task::from_properties(&properties).unwrap_err();
+    assert_eq!(
+        error,
+        PropertyError::WrongType {
+            property: "done".to_string(),
+            expected: "checkbox",
+            found: "number",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Property `done` is number, expected checkbox"
+    );
+}
+
+#[test]
+fn test_property_types() {
+    let issue = Issue {
+        title: "Crash on start".to_string(),
+        priority: "High".to_string(),
+        state: Some("In progress".to_string()),
+        estimate: Some(3),
+        blocked_by: vec![PageId::from("b55c9c91384d452b81dbd1ef79372b75")],
+        cached: Some("ignored".to_string()),
+    };
+
+    let properties = issue.to_properties().unwrap();
+    assert_eq!(
+        serde_json::to_value(&properties).unwrap(),
+        json!({
+            "Blocked by": {
+                "type": "relation",
+                "relation": [{ "id": "b55c9c91-384d-452b-81db-d1ef79372b75" }]
+            },
+            "estimate": { "type": "number", "number": 3 },
+            "priority": { "type": "select", "select": { "name": "High" } },
+            "state": { "type": "status", "status": { "name": "In progress" } },
+            "title": {
+                "type": "title",
+                "title": [{ "type": "text", "text": { "content": "Crash on start" } }]
+            }
+        })
+    );
+
+    assert_eq!(
+        Issue::from_properties(&properties),
+        Ok(Issue {
+            cached: None,
+            ..issue
+        })
+    );
+}
+
+#[test]
+fn test_non_finite_numbers() {
+    #[derive(NotionPage, Debug, PartialEq)]
+    struct Measurement {
+        #[notion(title)]
+        name: String,
+        value: f64,
+    }
+
+    let measurement = Measurement {
+        name: "Ratio".to_string(),
+        value: f64::NAN,
+    };
+    assert_eq!(
+        measurement.to_properties(),
+        Err(PropertyError::Invalid {
+            property: "value".to_string(),
+            message: "NaN is not a finite number".to_string(),
+        })
+    );
+    assert!(measurement
+        .create_page_request(Parent::page("b55c9c91384d452b81dbd1ef79372b75"))
+        .is_err());
+}
+
+#[test]
+fn test_create_database_request() {
+    let request =
+        Task::create_database_request(Parent::page("b55c9c91384d452b81dbd1ef79372b75"), "Tasks");
+
+    assert_eq!(
+        serde_json::to_value(&request.properties).unwrap(),
+        json!({
+            "Due": { "type": "date", "date": {} },
+            "done": { "type": "checkbox", "checkbox": {} },
+            "name": { "type": "title", "title": {} },
+            "tags": { "type": "multi_select", "multi_select": { "options": [] } }
+        })
+    );
+
+    // Status and relation properties need extra configuration and are left out.
+    let schema = Issue::database_properties();
+    assert_eq!(
+        schema.keys().collect::<Vec<_>>(),
+        vec!["estimate", "priority", "title"]
+    );
+}
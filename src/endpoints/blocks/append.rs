@@ -1,19 +1,118 @@
+pub(crate) mod chunk;
 pub mod request;
 pub mod response;
 
-use crate::{endpoints::NOTION_URI, objects::Response, NotionClientError};
+use futures_util::future::BoxFuture;
 
-use self::{request::AppendBlockChildrenRequest, response::AppendBlockChildrenResponse};
+use crate::{
+    endpoints::NOTION_URI,
+    objects::{block::Block, id::BlockId, rich_text::normalize, Response},
+    NotionClientError,
+};
 
-use super::BlocksEndpoint;
+use self::{
+    chunk::{Deferred, Planned},
+    request::AppendBlockChildrenRequest,
+    response::AppendBlockChildrenResponse,
+};
+
+use super::{retrieve::request::RetrieveBlockTreeOptions, BlocksEndpoint};
 
 impl BlocksEndpoint {
+    /// Appends `request.children` to a block or page.
+    ///
+    /// Children beyond Notion's limits of 100 blocks per array, two levels of
+    /// nesting and the request size are split into several requests, kept in
+    /// order with `after`; deeper descendants are appended to their parents
+    /// once those exist. The response then holds every appended top-level block.
+    ///
+    /// Rich text is normalized first, see [`normalize`]. If some of it is still
+    /// over the limits nothing is sent and the error is
+    /// [`NotionClientError::UnfixableRichText`].
     pub async fn append_block_children(
         &self,
-        block_id: &str,
-        request: AppendBlockChildrenRequest,
+        block_id: impl Into<BlockId>,
+        mut request: AppendBlockChildrenRequest,
     ) -> Result<AppendBlockChildrenResponse, NotionClientError> {
-        let json = serde_json::to_string(&request)
+        let block_id = block_id.into();
+        let unfixable = normalize::blocks(&mut request.children);
+        if !unfixable.is_empty() {
+            return Err(NotionClientError::UnfixableRichText { unfixable });
+        }
+        if chunk::fits(&request.children) {
+            return self.send_append(&block_id, &request).await;
+        }
+
+        let results = self
+            .append_tree(block_id, request.children, request.after)
+            .await?;
+        Ok(AppendBlockChildrenResponse {
+            object: "list".to_string(),
+            results,
+            next_cursor: None,
+            has_more: false,
+        })
+    }
+
+    fn append_tree(
+        &self,
+        block_id: BlockId,
+        blocks: Vec<Block>,
+        mut after: Option<String>,
+    ) -> BoxFuture<'_, Result<Vec<Block>, NotionClientError>> {
+        Box::pin(async move {
+            let mut results = vec![];
+            for batch in chunk::plan(blocks) {
+                let (children, deferred): (Vec<_>, Vec<_>) = batch
+                    .into_iter()
+                    .map(|Planned { block, deferred }| (block, deferred))
+                    .unzip();
+                let request = AppendBlockChildrenRequest {
+                    children,
+                    after: after.take(),
+                };
+                let response = self.send_append(&block_id, &request).await?;
+                after = response
+                    .results
+                    .last()
+                    .and_then(|block| block.id.as_ref())
+                    .map(BlockId::to_string);
+
+                for (created, deferred) in response.results.iter().zip(deferred) {
+                    let Some(id) = created.id.clone() else {
+                        continue;
+                    };
+                    match deferred {
+                        Deferred::None => {}
+                        Deferred::Children(children) => {
+                            self.append_tree(id, children, None).await?;
+                        }
+                        Deferred::Descendants(descendants) => {
+                            let options = RetrieveBlockTreeOptions {
+                                max_depth: Some(2),
+                                ..Default::default()
+                            };
+                            let created = self.retrieve_block_tree(&id, options).await?;
+                            for (path, children) in descendants {
+                                if let Some(id) = id_at(&created, &path) {
+                                    self.append_tree(id, children, None).await?;
+                                }
+                            }
+                        }
+                    }
+                }
+                results.extend(response.results);
+            }
+            Ok(results)
+        })
+    }
+
+    async fn send_append(
+        &self,
+        block_id: &BlockId,
+        request: &AppendBlockChildrenRequest,
+    ) -> Result<AppendBlockChildrenResponse, NotionClientError> {
+        let json = serde_json::to_string(request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
         let result = self
@@ -42,3 +141,13 @@ impl BlocksEndpoint {
         }
     }
 }
+
+fn id_at(blocks: &[Block], path: &[usize]) -> Option<BlockId> {
+    let (first, rest) = path.split_first()?;
+    let block = blocks.get(*first)?;
+    if rest.is_empty() {
+        block.id.clone()
+    } else {
+        id_at(block.block_type.children()?, rest)
+    }
+}
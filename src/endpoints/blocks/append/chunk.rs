@@ -0,0 +1,145 @@
+//! Splits block trees that exceed Notion's request limits into a sequence of
+//! requests that do not.
+
+use crate::objects::block::{Block, BlockType};
+
+use super::request::{MAX_BLOCKS_PER_REQUEST, MAX_CHILDREN, MAX_NESTING, MAX_PAYLOAD_BYTES};
+
+/// Room left in [`MAX_PAYLOAD_BYTES`] for the rest of the request body.
+const REQUEST_OVERHEAD_BYTES: usize = 1000;
+
+/// A block as sent in a request, with what is appended below it once it exists.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Planned {
+    pub block: Block,
+    pub deferred: Deferred,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Deferred {
+    None,
+    /// Children to append to the created block.
+    Children(Vec<Block>),
+    /// Children to append to blocks created along with it, by their position
+    /// below it, e.g. `[1, 0]` for the first block in the second column.
+    Descendants(Vec<(Vec<usize>, Vec<Block>)>),
+}
+
+/// Whether `blocks` can be sent in a single request as they are.
+pub(crate) fn fits(blocks: &[Block]) -> bool {
+    blocks.len() <= MAX_CHILDREN
+        && blocks.iter().all(|block| fits_nesting(block, MAX_NESTING))
+        && blocks.iter().map(count).sum::<usize>() <= MAX_BLOCKS_PER_REQUEST
+        && blocks.iter().map(size).sum::<usize>() + REQUEST_OVERHEAD_BYTES <= MAX_PAYLOAD_BYTES
+}
+
+/// Groups `blocks` into the children of consecutive requests.
+pub(crate) fn plan(blocks: Vec<Block>) -> Vec<Vec<Planned>> {
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let (mut blocks_in_batch, mut bytes_in_batch) = (0, REQUEST_OVERHEAD_BYTES);
+
+    for block in blocks {
+        let planned = plan_block(block);
+        let (block_count, block_size) = (count(&planned.block), size(&planned.block));
+        if !batch.is_empty()
+            && (batch.len() == MAX_CHILDREN
+                || blocks_in_batch + block_count > MAX_BLOCKS_PER_REQUEST
+                || bytes_in_batch + block_size > MAX_PAYLOAD_BYTES)
+        {
+            batches.push(std::mem::take(&mut batch));
+            (blocks_in_batch, bytes_in_batch) = (0, REQUEST_OVERHEAD_BYTES);
+        }
+        blocks_in_batch += block_count;
+        bytes_in_batch += block_size;
+        batch.push(planned);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+fn plan_block(mut block: Block) -> Planned {
+    // A block whose subtree is too large for a request on its own is sent
+    // without its children, even when it is shallow enough.
+    let subtree_fits = fits_nesting(&block, MAX_NESTING)
+        && count(&block) <= MAX_BLOCKS_PER_REQUEST
+        && size(&block) + REQUEST_OVERHEAD_BYTES <= MAX_PAYLOAD_BYTES;
+    let deferred = match &mut block.block_type {
+        // Tables must be created with their rows, and column lists with their
+        // columns and the blocks in them.
+        BlockType::Table { table } => {
+            let rest = split_off_overflow(table.children.get_or_insert_with(Vec::new));
+            if rest.is_empty() {
+                Deferred::None
+            } else {
+                Deferred::Children(rest)
+            }
+        }
+        BlockType::ColumnList { column_list } => {
+            let mut descendants = vec![];
+            for (i, column) in column_list.children.iter_mut().flatten().enumerate() {
+                let Some(Some(children)) = column.block_type.children_mut() else {
+                    continue;
+                };
+                let rest = split_off_overflow(children);
+                for (j, child) in children.iter_mut().enumerate() {
+                    // Tables in columns keep their rows too, like top-level ones.
+                    let grandchildren = match &mut child.block_type {
+                        BlockType::Table { table } => {
+                            split_off_overflow(table.children.get_or_insert_with(Vec::new))
+                        }
+                        block_type => block_type
+                            .children_mut()
+                            .and_then(Option::take)
+                            .unwrap_or_default(),
+                    };
+                    if !grandchildren.is_empty() {
+                        descendants.push((vec![i, j], grandchildren));
+                    }
+                }
+                if !rest.is_empty() {
+                    descendants.push((vec![i], rest));
+                }
+            }
+            if descendants.is_empty() {
+                Deferred::None
+            } else {
+                Deferred::Descendants(descendants)
+            }
+        }
+        _ if subtree_fits => Deferred::None,
+        block_type => match block_type.children_mut().and_then(Option::take) {
+            Some(children) if !children.is_empty() => Deferred::Children(children),
+            _ => Deferred::None,
+        },
+    };
+    Planned { block, deferred }
+}
+
+fn split_off_overflow(children: &mut Vec<Block>) -> Vec<Block> {
+    children.split_off(children.len().min(MAX_CHILDREN))
+}
+
+fn fits_nesting(block: &Block, levels: usize) -> bool {
+    match block.block_type.children() {
+        Some(children) if !children.is_empty() => {
+            levels > 0
+                && children.len() <= MAX_CHILDREN
+                && children.iter().all(|child| fits_nesting(child, levels - 1))
+        }
+        _ => true,
+    }
+}
+
+fn count(block: &Block) -> usize {
+    1 + block
+        .block_type
+        .children()
+        .map_or(0, |children| children.iter().map(count).sum())
+}
+
+fn size(block: &Block) -> usize {
+    serde_json::to_vec(block).map_or(0, |json| json.len()) + 1
+}
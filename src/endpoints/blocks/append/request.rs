@@ -4,6 +4,15 @@ use serde_with::skip_serializing_none;
 
 use crate::objects::block::Block;
 
+/// Notion accepts at most this many blocks in each `children` array.
+pub const MAX_CHILDREN: usize = 100;
+/// Notion accepts children and grandchildren of the appended blocks, but no deeper.
+pub const MAX_NESTING: usize = 2;
+/// Notion accepts at most this many blocks in one request, counting descendants.
+pub const MAX_BLOCKS_PER_REQUEST: usize = 1000;
+/// Notion rejects request bodies larger than this.
+pub const MAX_PAYLOAD_BYTES: usize = 500_000;
+
 #[skip_serializing_none]
 #[derive(Serialize, Debug, Eq, PartialEq, Clone, Default, Builder)]
 #[builder(setter(strip_option))]
@@ -1,13 +1,17 @@
 use crate::{
     endpoints::NOTION_URI,
-    objects::{block::Block, Response},
+    objects::{block::Block, id::BlockId, Response},
     NotionClientError,
 };
 
 use super::BlocksEndpoint;
 
 impl BlocksEndpoint {
-    pub async fn delete_a_block(&self, block_id: &str) -> Result<Block, NotionClientError> {
+    pub async fn delete_a_block(
+        &self,
+        block_id: impl Into<BlockId>,
+    ) -> Result<Block, NotionClientError> {
+        let block_id = block_id.into();
         let result = self
             .client
             .delete(format!(
@@ -1,17 +1,32 @@
+pub mod request;
 pub mod response;
 
+use futures_util::{stream, StreamExt, TryStreamExt};
+
 use crate::{
-    endpoints::NOTION_URI,
-    objects::{block::Block, Response},
+    endpoints::{
+        databases::{query::request::QueryDatabaseRequest, DatabasesEndpoint},
+        NOTION_URI,
+    },
+    objects::{
+        block::{Block, BlockType, ChildPageValue, SyncedFrom},
+        id::{BlockId, DatabaseId},
+        page::Page,
+        Response,
+    },
     NotionClientError,
 };
 
-use self::response::RetrieveBlockChilerenResponse;
+use self::{request::RetrieveBlockTreeOptions, response::RetrieveBlockChilerenResponse};
 
 use super::BlocksEndpoint;
 
 impl BlocksEndpoint {
-    pub async fn retrieve_a_block(&self, block_id: &str) -> Result<Block, NotionClientError> {
+    pub async fn retrieve_a_block(
+        &self,
+        block_id: impl Into<BlockId>,
+    ) -> Result<Block, NotionClientError> {
+        let block_id = block_id.into();
         let result = self
             .client
             .get(format!(
@@ -38,10 +53,11 @@ impl BlocksEndpoint {
 
     pub async fn retrieve_block_children(
         &self,
-        block_id: &str,
+        block_id: impl Into<BlockId>,
         start_cursor: Option<&str>,
         page_size: Option<u32>,
     ) -> Result<RetrieveBlockChilerenResponse, NotionClientError> {
+        let block_id = block_id.into();
         let mut query = vec![];
         if let Some(start_cursor) = start_cursor {
             query.insert(0, ("start_cursor", start_cursor));
@@ -75,4 +91,192 @@ impl BlocksEndpoint {
             Response::Error(e) => Err(NotionClientError::InvalidStatusCode { error: e }),
         }
     }
+
+    /// Retrieves the children of a block or page with all their descendants,
+    /// following `has_children` and pagination, and fills in their `children`.
+    ///
+    /// Blocks are retrieved level by level, with up to `options.concurrency`
+    /// requests in flight.
+    pub async fn retrieve_block_tree(
+        &self,
+        block_id: impl Into<BlockId>,
+        options: RetrieveBlockTreeOptions,
+    ) -> Result<Vec<Block>, NotionClientError> {
+        let mut tree = self.retrieve_all_children(block_id.into()).await?;
+        let mut level = (0..tree.len()).map(|i| vec![i]).collect::<Vec<_>>();
+        let mut depth = 1;
+
+        loop {
+            let sources = sources(&mut tree, level, depth, &options);
+            if sources.is_empty() {
+                break;
+            }
+
+            let retrieved = stream::iter(sources)
+                .map(|(path, source)| async move {
+                    let children = match source {
+                        Source::Block(id) => self.retrieve_all_children(id).await?,
+                        Source::Database(id) => self.retrieve_all_rows(id).await?,
+                    };
+                    Ok::<_, NotionClientError>((path, children))
+                })
+                .buffered(options.concurrency.max(1))
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            level = attach(&mut tree, retrieved);
+            depth += 1;
+        }
+
+        Ok(tree)
+    }
+
+    async fn retrieve_all_children(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Vec<Block>, NotionClientError> {
+        let mut blocks = vec![];
+        let mut cursor = None;
+        loop {
+            let response = self
+                .retrieve_block_children(&block_id, cursor.as_deref(), Some(100))
+                .await?;
+            blocks.extend(response.results);
+            match response.next_cursor {
+                Some(next) if response.has_more => cursor = Some(next),
+                _ => return Ok(blocks),
+            }
+        }
+    }
+
+    async fn retrieve_all_rows(
+        &self,
+        database_id: DatabaseId,
+    ) -> Result<Vec<Block>, NotionClientError> {
+        let databases = DatabasesEndpoint {
+            client: self.client.clone(),
+        };
+        let mut rows = vec![];
+        let mut cursor = None;
+        loop {
+            let request = QueryDatabaseRequest {
+                start_cursor: cursor,
+                page_size: Some(100),
+                ..Default::default()
+            };
+            let response = databases.query_a_database(&database_id, request).await?;
+            rows.extend(response.results.iter().map(row_block));
+            match response.next_cursor {
+                Some(next) if response.has_more => cursor = Some(next),
+                _ => return Ok(rows),
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Source {
+    Block(BlockId),
+    Database(DatabaseId),
+}
+
+/// Where to retrieve the children of the blocks at `level`, the paths of the
+/// blocks retrieved at `depth`. Nothing once `options.max_depth` is reached.
+pub(crate) fn sources(
+    tree: &mut [Block],
+    level: Vec<Vec<usize>>,
+    depth: usize,
+    options: &RetrieveBlockTreeOptions,
+) -> Vec<(Vec<usize>, Source)> {
+    if options.max_depth.is_some_and(|max| depth >= max) {
+        return vec![];
+    }
+    level
+        .into_iter()
+        .filter_map(|path| {
+            let block = block_at(tree, &path)?;
+            Some((path, children_source(block, options)?))
+        })
+        .collect()
+}
+
+/// Fills in the retrieved children, returning their paths as the next level.
+pub(crate) fn attach(
+    tree: &mut [Block],
+    retrieved: Vec<(Vec<usize>, Vec<Block>)>,
+) -> Vec<Vec<usize>> {
+    let mut level = vec![];
+    for (path, children) in retrieved {
+        level.extend((0..children.len()).map(|i| [path.as_slice(), &[i]].concat()));
+        if let Some(slot) =
+            block_at(tree, &path).and_then(|block| children_slot(&mut block.block_type))
+        {
+            *slot = Some(children);
+        }
+    }
+    level
+}
+
+/// Where the children of `block` are retrieved from, if they should be.
+pub(crate) fn children_source(block: &Block, options: &RetrieveBlockTreeOptions) -> Option<Source> {
+    let id = block.id.clone()?;
+    match &block.block_type {
+        BlockType::ChildPage { .. } => options.child_pages.then_some(Source::Block(id)),
+        BlockType::ChildDatabase { .. } => options
+            .child_databases
+            .then(|| Source::Database(DatabaseId::from(String::from(id)))),
+        BlockType::SyncedBlock { synced_block } => match &synced_block.synced_from {
+            Some(SyncedFrom::BlockId { block_id }) if options.resolve_synced_blocks => {
+                Some(Source::Block(block_id.clone()))
+            }
+            _ => block
+                .has_children
+                .unwrap_or_default()
+                .then_some(Source::Block(id)),
+        },
+        _ => block
+            .has_children
+            .unwrap_or_default()
+            .then_some(Source::Block(id)),
+    }
+}
+
+pub(crate) fn children_slot(block_type: &mut BlockType) -> Option<&mut Option<Vec<Block>>> {
+    match block_type {
+        BlockType::ChildPage { child_page } => Some(&mut child_page.children),
+        BlockType::ChildDatabase { child_database } => Some(&mut child_database.children),
+        block_type => block_type.children_mut(),
+    }
+}
+
+pub(crate) fn block_at<'a>(tree: &'a mut [Block], path: &[usize]) -> Option<&'a mut Block> {
+    let (first, rest) = path.split_first()?;
+    let mut block = tree.get_mut(*first)?;
+    for i in rest {
+        block = children_slot(&mut block.block_type)?
+            .as_mut()?
+            .get_mut(*i)?;
+    }
+    Some(block)
+}
+
+/// A database row as the `child_page` block Notion shows for it.
+fn row_block(page: &Page) -> Block {
+    Block {
+        object: Some("block".to_string()),
+        id: Some(BlockId::from(&page.id)),
+        parent: Some(page.parent.clone()),
+        block_type: BlockType::ChildPage {
+            child_page: ChildPageValue {
+                title: page.title_text().unwrap_or_default(),
+                children: None,
+            },
+        },
+        created_time: Some(page.created_time),
+        created_by: Some(page.created_by.clone()),
+        last_edited_time: Some(page.last_edited_time),
+        last_edited_by: Some(page.last_edited_by.clone()),
+        archived: Some(page.archived),
+        has_children: None,
+    }
 }
@@ -0,0 +1,31 @@
+use derive_builder::Builder;
+
+/// Options for [`BlocksEndpoint::retrieve_block_tree`](super::super::BlocksEndpoint::retrieve_block_tree).
+#[derive(Debug, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+pub struct RetrieveBlockTreeOptions {
+    /// Levels of blocks to retrieve, `1` for the direct children only.
+    /// Unlimited when `None`.
+    pub max_depth: Option<usize>,
+    /// Requests in flight at once. Notion allows about three per second.
+    pub concurrency: usize,
+    /// Retrieve the content of `child_page` blocks.
+    pub child_pages: bool,
+    /// Retrieve the rows of `child_database` blocks as `child_page` blocks.
+    pub child_databases: bool,
+    /// Retrieve the children of synced block references from their original.
+    pub resolve_synced_blocks: bool,
+}
+
+impl Default for RetrieveBlockTreeOptions {
+    fn default() -> Self {
+        RetrieveBlockTreeOptions {
+            max_depth: None,
+            concurrency: 3,
+            child_pages: false,
+            child_databases: false,
+            resolve_synced_blocks: true,
+        }
+    }
+}
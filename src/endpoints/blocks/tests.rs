@@ -1,13 +1,34 @@
 use crate::{
-    endpoints::blocks::{
-        append::{request::AppendBlockChildrenRequest, response::AppendBlockChildrenResponse},
-        retrieve::response::RetrieveBlockChilerenResponse,
-        update::request::UpdateABlockRequest,
+    endpoints::{
+        blocks::{
+            append::{
+                chunk::{fits, plan, Deferred},
+                request::AppendBlockChildrenRequest,
+                response::AppendBlockChildrenResponse,
+            },
+            retrieve::{
+                attach, block_at, children_source,
+                request::{RetrieveBlockTreeOptions, RetrieveBlockTreeOptionsBuilder},
+                response::RetrieveBlockChilerenResponse,
+                sources, Source,
+            },
+            update::request::UpdateABlockRequest,
+        },
+        Client,
     },
     objects::{
-        block::{Block, BlockType, HeadingsValue, ParagraphValue, ToDoValue},
-        rich_text::{Link, RichText, Text},
+        block::{
+            builder::{column, column_list, h2, p, table, toggle},
+            Block, BlockType, ChildDatabaseValue, ChildPageValue, HeadingsValue, ParagraphValue,
+            SyncedBlockValue, SyncedFrom, ToDoValue,
+        },
+        id::{BlockId, DatabaseId},
+        rich_text::{
+            normalize::{Location, Problem, Unfixable},
+            Link, RichText, Text,
+        },
     },
+    NotionClientError,
 };
 
 #[test]
@@ -61,6 +82,21 @@ fn test_append_request() {
     assert_eq!(result, actual.to_string())
 }
 
+#[test]
+fn test_append_request_from_builder() {
+    let request = AppendBlockChildrenRequest {
+        children: crate::blocks![
+            h2("Lacinato kale"),
+            p(RichText::text("Lacinato kale is a variety of kale with a long tradition in Italian cuisine, especially that of Tuscany. It is also known as Tuscan kale, Italian kale, dinosaur kale, kale, flat back kale, palm tree kale, or black Tuscan palm.").link("https://en.wikipedia.org/wiki/Lacinato_kale")),
+        ],
+        ..Default::default()
+    };
+
+    let result = serde_json::to_string_pretty(&request).unwrap();
+    let actual = include_str!("tests/append_request.json");
+    assert_eq!(result, actual.to_string())
+}
+
 #[test]
 fn test_update_request() {
     let request = UpdateABlockRequest {
@@ -128,3 +164,333 @@ fn test_deserialize_synced_from_block() {
     let result = serde_json::from_str::<Vec<Block>>(include_str!("tests/synced_from_block.json"));
     assert!(result.is_ok())
 }
+
+#[test]
+fn test_retrieve_block_tree_options() {
+    let options = RetrieveBlockTreeOptionsBuilder::default()
+        .max_depth(2)
+        .child_pages(true)
+        .build()
+        .unwrap();
+    assert_eq!(
+        options,
+        RetrieveBlockTreeOptions {
+            max_depth: Some(2),
+            concurrency: 3,
+            child_pages: true,
+            child_databases: false,
+            resolve_synced_blocks: true,
+        }
+    );
+}
+
+#[test]
+fn test_child_page_children() {
+    let json = serde_json::json!({ "type": "child_page", "child_page": { "title": "Kale" } });
+    let mut block_type = serde_json::from_value::<BlockType>(json.clone()).unwrap();
+    assert_eq!(
+        block_type,
+        BlockType::ChildPage {
+            child_page: ChildPageValue {
+                title: "Kale".to_string(),
+                children: None,
+            }
+        }
+    );
+    assert_eq!(serde_json::to_value(&block_type).unwrap(), json);
+
+    if let BlockType::ChildPage { child_page } = &mut block_type {
+        child_page.children = Some(vec![p("Lacinato")]);
+    }
+    assert_eq!(
+        serde_json::to_value(&block_type).unwrap()["child_page"]["children"][0]["type"],
+        "paragraph"
+    );
+}
+
+fn retrieved(block: Block, id: &str, has_children: bool) -> Block {
+    Block {
+        id: Some(BlockId::from(id)),
+        has_children: Some(has_children),
+        ..block
+    }
+}
+
+#[test]
+fn test_children_source() {
+    let options = RetrieveBlockTreeOptions::default();
+    let id = "c02fc1d3-db8b-45c5-a222-27595b15aea7";
+    let original = "59833787-2cf9-4fdf-8782-e53db20768a5";
+
+    assert_eq!(
+        children_source(&retrieved(toggle("More"), id, true), &options),
+        Some(Source::Block(BlockId::from(id)))
+    );
+    assert_eq!(
+        children_source(&retrieved(toggle("More"), id, false), &options),
+        None
+    );
+    assert_eq!(children_source(&toggle("More"), &options), None);
+
+    let reference = retrieved(
+        Block::from(BlockType::SyncedBlock {
+            synced_block: SyncedBlockValue {
+                synced_from: Some(SyncedFrom::BlockId {
+                    block_id: BlockId::from(original),
+                }),
+                children: None,
+            },
+        }),
+        id,
+        true,
+    );
+    assert_eq!(
+        children_source(&reference, &options),
+        Some(Source::Block(BlockId::from(original)))
+    );
+    let unresolved = RetrieveBlockTreeOptions {
+        resolve_synced_blocks: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        children_source(&reference, &unresolved),
+        Some(Source::Block(BlockId::from(id)))
+    );
+
+    let child_page = retrieved(
+        Block::from(BlockType::ChildPage {
+            child_page: ChildPageValue {
+                title: "Kale".to_string(),
+                children: None,
+            },
+        }),
+        id,
+        true,
+    );
+    let child_database = retrieved(
+        Block::from(BlockType::ChildDatabase {
+            child_database: ChildDatabaseValue {
+                title: "Recipes".to_string(),
+                children: None,
+            },
+        }),
+        id,
+        false,
+    );
+    assert_eq!(children_source(&child_page, &options), None);
+    assert_eq!(children_source(&child_database, &options), None);
+    let nested = RetrieveBlockTreeOptions {
+        child_pages: true,
+        child_databases: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        children_source(&child_page, &nested),
+        Some(Source::Block(BlockId::from(id)))
+    );
+    assert_eq!(
+        children_source(&child_database, &nested),
+        Some(Source::Database(DatabaseId::from(id)))
+    );
+
+    let mut tree = vec![child_page];
+    attach(&mut tree, vec![(vec![0], vec![p("Lacinato")])]);
+    assert_eq!(block_at(&mut tree, &[0, 0]), Some(&mut p("Lacinato")));
+}
+
+#[test]
+fn test_retrieve_block_tree_levels() {
+    let ids = [
+        "00000000-0000-4000-8000-000000000001",
+        "00000000-0000-4000-8000-000000000002",
+        "00000000-0000-4000-8000-000000000003",
+    ];
+    let top = || {
+        vec![
+            retrieved(p("Flat"), ids[0], false),
+            retrieved(toggle("Outer"), ids[1], true),
+        ]
+    };
+    let limited = RetrieveBlockTreeOptions {
+        max_depth: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        sources(&mut top(), vec![vec![0], vec![1]], 1, &limited),
+        vec![]
+    );
+
+    let mut tree = top();
+    let options = RetrieveBlockTreeOptions {
+        max_depth: Some(2),
+        ..Default::default()
+    };
+    let level = sources(&mut tree, vec![vec![0], vec![1]], 1, &options);
+    assert_eq!(level, vec![(vec![1], Source::Block(BlockId::from(ids[1])))]);
+
+    let children = vec![p("Before"), retrieved(toggle("Inner"), ids[2], true)];
+    let level = attach(&mut tree, vec![(vec![1], children.clone())]);
+    assert_eq!(level, vec![vec![1, 0], vec![1, 1]]);
+    assert_eq!(tree[1].block_type.children(), Some(&children));
+    assert_eq!(block_at(&mut tree, &[1, 1]), Some(&mut children[1].clone()));
+    assert_eq!(block_at(&mut tree, &[1, 2]), None);
+
+    assert_eq!(sources(&mut tree, level.clone(), 2, &options), vec![]);
+    assert_eq!(
+        sources(&mut tree, level, 2, &RetrieveBlockTreeOptions::default()),
+        vec![(vec![1, 1], Source::Block(BlockId::from(ids[2])))]
+    );
+}
+
+#[test]
+fn test_plan_splits_top_level_blocks() {
+    let blocks = (0..250).map(|i| p(i.to_string())).collect::<Vec<_>>();
+    assert!(fits(&blocks[..100]));
+    assert!(!fits(&blocks));
+
+    let batches = plan(blocks);
+    let sizes = batches.iter().map(Vec::len).collect::<Vec<_>>();
+    assert_eq!(sizes, vec![100, 100, 50]);
+    assert_eq!(batches[1][0].block, p("100"));
+    assert!(batches
+        .iter()
+        .flatten()
+        .all(|planned| planned.deferred == Deferred::None));
+}
+
+#[test]
+fn test_plan_defers_deep_children() {
+    let nested = toggle("1").children([toggle("2").children([toggle("3").children([p("4")])])]);
+    assert!(!fits(std::slice::from_ref(&nested)));
+    assert!(fits(&[
+        toggle("2").children([toggle("3").children([p("4")])])
+    ]));
+
+    let batches = plan(vec![nested]);
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0][0].block, toggle("1"));
+    assert_eq!(
+        batches[0][0].deferred,
+        Deferred::Children(vec![toggle("2").children([toggle("3").children([p("4")])])])
+    );
+}
+
+#[test]
+fn test_plan_defers_children_of_large_subtrees() {
+    let children = (0..100)
+        .map(|i| toggle(i.to_string()).children((0..10).map(|j| p(j.to_string()))))
+        .collect::<Vec<_>>();
+    let large = toggle("Large").children(children.clone());
+    assert!(!fits(std::slice::from_ref(&large)));
+
+    let batches = plan(vec![large]);
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0][0].block, toggle("Large"));
+    assert_eq!(batches[0][0].deferred, Deferred::Children(children.clone()));
+
+    let batches = plan(children);
+    assert!(batches.len() > 1);
+    assert!(batches.iter().all(|batch| fits(
+        &batch
+            .iter()
+            .map(|planned| planned.block.clone())
+            .collect::<Vec<_>>()
+    )));
+}
+
+#[test]
+fn test_plan_defers_table_rows() {
+    let rows = (0..150).map(|i| [i.to_string()]).collect::<Vec<_>>();
+    let batches = plan(vec![table(rows)]);
+
+    let planned = &batches[0][0];
+    let Some(sent) = planned.block.block_type.children() else {
+        panic!("table rows missing");
+    };
+    assert_eq!(sent.len(), 100);
+    let Deferred::Children(rest) = &planned.deferred else {
+        panic!("expected deferred rows, got {:?}", planned.deferred);
+    };
+    assert_eq!(rest.len(), 50);
+}
+
+#[test]
+fn test_plan_defers_column_descendants() {
+    let columns = column_list([
+        column([p("Left")]),
+        column([p("Right"), toggle("More").children([p("Hidden")])]),
+    ]);
+    let batches = plan(vec![columns]);
+
+    assert_eq!(
+        batches[0][0].block,
+        column_list([column([p("Left")]), column([p("Right"), toggle("More")]),])
+    );
+    assert_eq!(
+        batches[0][0].deferred,
+        Deferred::Descendants(vec![(vec![1, 1], vec![p("Hidden")])])
+    );
+}
+
+#[test]
+fn test_plan_keeps_rows_of_tables_in_columns() {
+    let rows = (0..120).map(|i| [i.to_string()]).collect::<Vec<_>>();
+    let columns = column_list([
+        column([table(rows[..2].to_vec())]),
+        column([table(rows.clone())]),
+    ]);
+    let batches = plan(vec![columns]);
+
+    assert_eq!(
+        batches[0][0].block,
+        column_list([
+            column([table(rows[..2].to_vec())]),
+            column([table(rows[..100].to_vec())]),
+        ])
+    );
+    let Deferred::Descendants(descendants) = &batches[0][0].deferred else {
+        panic!("expected deferred rows, got {:?}", batches[0][0].deferred);
+    };
+    assert_eq!(descendants.len(), 1);
+    assert_eq!(descendants[0].0, vec![1, 0]);
+    assert_eq!(descendants[0].1.len(), 20);
+}
+
+#[test]
+fn test_plan_splits_by_size() {
+    let text = "x".repeat(1900);
+    let blocks = (0..90)
+        .map(|_| p(vec![RichText::from(text.as_str()); 5]))
+        .collect::<Vec<_>>();
+    assert!(!fits(&blocks));
+
+    let batches = plan(blocks);
+    assert!(batches.len() > 1);
+    assert_eq!(batches.iter().map(Vec::len).sum::<usize>(), 90);
+}
+
+#[tokio::test]
+async fn test_append_rejects_unfixable_rich_text() {
+    let client = Client::new("token".to_string(), None).unwrap();
+    let url = format!("https://example.com/{}", "x".repeat(2000));
+    let request = AppendBlockChildrenRequest {
+        children: vec![p("Fine"), p(RichText::text("Long").link(url.clone()))],
+        after: None,
+    };
+
+    let result = client
+        .blocks
+        .append_block_children("b55c9c91384d452b81dbd1ef79372b75", request)
+        .await;
+
+    let Err(NotionClientError::UnfixableRichText { unfixable }) = result else {
+        panic!("expected unfixable rich text, got {:?}", result);
+    };
+    assert_eq!(
+        unfixable,
+        vec![Unfixable {
+            location: Location::Block(vec![1]),
+            problem: Problem::UrlTooLong { url },
+        }]
+    );
+}
@@ -1,6 +1,10 @@
 pub mod request;
 
-use crate::{endpoints::NOTION_URI, objects::Response, NotionClientError};
+use crate::{
+    endpoints::NOTION_URI,
+    objects::{id::BlockId, Response},
+    NotionClientError,
+};
 
 use self::request::UpdateABlockRequest;
 
@@ -9,9 +13,10 @@ use super::BlocksEndpoint;
 impl BlocksEndpoint {
     pub async fn update_a_block(
         &self,
-        block_id: &str,
+        block_id: impl Into<BlockId>,
         request: UpdateABlockRequest,
     ) -> Result<UpdateABlockRequest, NotionClientError> {
+        let block_id = block_id.into();
         let json = serde_json::to_string(&request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
@@ -1,6 +1,10 @@
 pub mod response;
 
-use crate::{endpoints::NOTION_URI, objects::Response, NotionClientError};
+use crate::{
+    endpoints::NOTION_URI,
+    objects::{id::BlockId, Response},
+    NotionClientError,
+};
 
 use response::RetrieveCommentsResponse;
 
@@ -9,10 +13,11 @@ use super::CommentsEndpoint;
 impl CommentsEndpoint {
     pub async fn retrieve_a_user(
         &self,
-        block_id: &str,
+        block_id: impl Into<BlockId>,
         start_cursor: Option<&str>,
         page_size: Option<u32>,
     ) -> Result<RetrieveCommentsResponse, NotionClientError> {
+        let block_id = block_id.into();
         let mut query = vec![];
         if let Some(start_cursor) = start_cursor {
             query.insert(0, ("start_cursor", start_cursor));
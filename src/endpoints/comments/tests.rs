@@ -13,7 +13,7 @@ use crate::{
 fn test_create_comment_request() {
     let request = CreateCommentRequest {
         parent: Some(Parent::PageId {
-            page_id: "5c6a28216bb14a7eb6e1c50111515c3d".to_string(),
+            page_id: "5c6a28216bb14a7eb6e1c50111515c3d".into(),
         }),
         rich_text: vec![RichText::Text {
             text: Text {
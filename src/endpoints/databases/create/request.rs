@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::objects::{
-    database::DatabaseProperty, database::Icon, file::File, parent::Parent, rich_text::RichText,
+    database::DatabaseProperty, file::File, icon::Icon, parent::Parent, rich_text::RichText,
 };
 
 #[skip_serializing_none]
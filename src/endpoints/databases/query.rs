@@ -1,7 +1,13 @@
+pub mod builder;
+pub mod expression;
 pub mod request;
 pub mod response;
 
-use crate::{endpoints::NOTION_URI, objects::Response, NotionClientError};
+use crate::{
+    endpoints::NOTION_URI,
+    objects::{id::DatabaseId, Response},
+    NotionClientError,
+};
 
 use self::{request::QueryDatabaseRequest, response::QueryDatabaseResponse};
 
@@ -10,9 +16,10 @@ use super::DatabasesEndpoint;
 impl DatabasesEndpoint {
     pub async fn query_a_database(
         &self,
-        database_id: &str,
+        database_id: impl Into<DatabaseId>,
         request: QueryDatabaseRequest,
     ) -> Result<QueryDatabaseResponse, NotionClientError> {
+        let database_id = database_id.into();
         let json = serde_json::to_string(&request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
@@ -0,0 +1,738 @@
+use chrono::{DateTime, Utc};
+use serde_json::Number;
+
+use super::request::{
+    CheckBoxCondition, DateCondition, FilesCondition, Filter, FilterType, FormulaCondition,
+    IDCondition, MultiSelectCondition, NumberCondition, PeopleCondition, PropertyCondition,
+    RelationCondition, RichTextCondition, RollupCondition, SelectCondition, Sort, SortDirection,
+    StatusCondition, Timestamp, TimestampCondition,
+};
+
+/// Starts a filter on the property `name`, e.g. `prop("Status").status().equals("Done")`.
+pub fn prop(name: impl Into<String>) -> PropertyFilter {
+    PropertyFilter {
+        property: name.into(),
+    }
+}
+
+/// Starts a filter on the page's creation time.
+pub fn created_time() -> DateFilter {
+    DateFilter {
+        target: DateTarget::Timestamp(Timestamp::CreatedTime),
+    }
+}
+
+/// Starts a filter on the page's last edit time.
+pub fn last_edited_time() -> DateFilter {
+    DateFilter {
+        target: DateTarget::Timestamp(Timestamp::LastEditedTime),
+    }
+}
+
+/// Starts a sort on the property `name`, e.g. `sort_by("Priority").desc()`.
+pub fn sort_by(name: impl Into<String>) -> SortBy {
+    SortBy {
+        key: SortKey::Property(name.into()),
+    }
+}
+
+pub fn sort_by_timestamp(timestamp: Timestamp) -> SortBy {
+    SortBy {
+        key: SortKey::Timestamp(timestamp),
+    }
+}
+
+/// A filter condition that can be combined with `and`/`or` and converted
+/// into a [`Filter`] or [`FilterType`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Condition(FilterType);
+
+impl Condition {
+    /// Combines with `other`; chained calls extend the same `and` group.
+    pub fn and(self, other: impl Into<FilterType>) -> Self {
+        match self.0 {
+            FilterType::And { mut and } => {
+                and.push(other.into());
+                Condition(FilterType::And { and })
+            }
+            filter => Condition(FilterType::and([filter, other.into()])),
+        }
+    }
+
+    /// Combines with `other`; chained calls extend the same `or` group.
+    pub fn or(self, other: impl Into<FilterType>) -> Self {
+        match self.0 {
+            FilterType::Or { mut or } => {
+                or.push(other.into());
+                Condition(FilterType::Or { or })
+            }
+            filter => Condition(FilterType::or([filter, other.into()])),
+        }
+    }
+
+    /// Returns the complementary condition, e.g. `does_not_contain` for
+    /// `contains` or `on_or_before` for `after`, applying De Morgan's laws to
+    /// `and`/`or` groups. Returns `None` when Notion has no complement, as for
+    /// `starts_with` or relative dates such as `past_week`.
+    ///
+    /// Pages with an empty value match neither a comparison nor its complement.
+    pub fn negate(self) -> Option<Self> {
+        negate_filter(self.0).map(Condition)
+    }
+
+    pub fn into_filter(self) -> Filter {
+        self.0.into()
+    }
+}
+
+impl From<Condition> for FilterType {
+    fn from(condition: Condition) -> Self {
+        condition.0
+    }
+}
+
+impl From<Condition> for Filter {
+    fn from(condition: Condition) -> Self {
+        condition.0.into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PropertyFilter {
+    property: String,
+}
+
+impl PropertyFilter {
+    pub fn title(self) -> TextFilter {
+        self.text(PropertyCondition::Title)
+    }
+
+    pub fn rich_text(self) -> TextFilter {
+        self.text(PropertyCondition::RichText)
+    }
+
+    pub fn url(self) -> TextFilter {
+        self.text(PropertyCondition::Url)
+    }
+
+    pub fn email(self) -> TextFilter {
+        self.text(PropertyCondition::Email)
+    }
+
+    pub fn phone_number(self) -> TextFilter {
+        self.text(PropertyCondition::PhoneNumber)
+    }
+
+    pub fn number(self) -> NumberFilter {
+        NumberFilter {
+            property: self.property,
+        }
+    }
+
+    pub fn checkbox(self) -> CheckboxFilter {
+        CheckboxFilter {
+            property: self.property,
+        }
+    }
+
+    pub fn select(self) -> SelectFilter {
+        SelectFilter {
+            property: self.property,
+            status: false,
+        }
+    }
+
+    pub fn status(self) -> SelectFilter {
+        SelectFilter {
+            property: self.property,
+            status: true,
+        }
+    }
+
+    pub fn multi_select(self) -> ListFilter {
+        self.list(ListKind::MultiSelect)
+    }
+
+    pub fn people(self) -> ListFilter {
+        self.list(ListKind::People)
+    }
+
+    pub fn relation(self) -> ListFilter {
+        self.list(ListKind::Relation)
+    }
+
+    pub fn date(self) -> DateFilter {
+        DateFilter {
+            target: DateTarget::Property(self.property),
+        }
+    }
+
+    pub fn files(self) -> FilesFilter {
+        FilesFilter {
+            property: self.property,
+        }
+    }
+
+    pub fn unique_id(self) -> UniqueIdFilter {
+        UniqueIdFilter {
+            property: self.property,
+        }
+    }
+
+    fn text(self, wrap: fn(RichTextCondition) -> PropertyCondition) -> TextFilter {
+        TextFilter {
+            property: self.property,
+            wrap,
+        }
+    }
+
+    fn list(self, kind: ListKind) -> ListFilter {
+        ListFilter {
+            property: self.property,
+            kind,
+        }
+    }
+}
+
+fn property(property: String, condition: PropertyCondition) -> Condition {
+    Condition(FilterType::Property {
+        property,
+        condition,
+    })
+}
+
+/// Conditions on `title`, `rich_text`, `url`, `email` and `phone_number` properties.
+#[derive(Debug, Clone)]
+pub struct TextFilter {
+    property: String,
+    wrap: fn(RichTextCondition) -> PropertyCondition,
+}
+
+impl TextFilter {
+    pub fn equals(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::Equals(value.into()))
+    }
+
+    pub fn does_not_equal(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::DoesNotEqual(value.into()))
+    }
+
+    pub fn contains(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::Contains(value.into()))
+    }
+
+    pub fn does_not_contain(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::DoesNotContain(value.into()))
+    }
+
+    pub fn starts_with(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::StartsWith(value.into()))
+    }
+
+    pub fn ends_with(self, value: impl Into<String>) -> Condition {
+        self.condition(RichTextCondition::EndsWith(value.into()))
+    }
+
+    pub fn is_empty(self) -> Condition {
+        self.condition(RichTextCondition::IsEmpty)
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        self.condition(RichTextCondition::IsNotEmpty)
+    }
+
+    fn condition(self, condition: RichTextCondition) -> Condition {
+        property(self.property, (self.wrap)(condition))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NumberFilter {
+    property: String,
+}
+
+impl NumberFilter {
+    pub fn equals(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::Equals(value.into()))
+    }
+
+    pub fn does_not_equal(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::DoesNotEqual(value.into()))
+    }
+
+    pub fn greater_than(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::GreaterThan(value.into()))
+    }
+
+    pub fn greater_than_or_equal_to(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::GreaterThanOrEqualTo(value.into()))
+    }
+
+    pub fn less_than(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::LessThan(value.into()))
+    }
+
+    pub fn less_than_or_equal_to(self, value: impl Into<Number>) -> Condition {
+        self.condition(NumberCondition::LessThanOrEqualTo(value.into()))
+    }
+
+    pub fn is_empty(self) -> Condition {
+        self.condition(NumberCondition::IsEmpty)
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        self.condition(NumberCondition::IsNotEmpty)
+    }
+
+    fn condition(self, condition: NumberCondition) -> Condition {
+        property(self.property, PropertyCondition::Number(condition))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckboxFilter {
+    property: String,
+}
+
+impl CheckboxFilter {
+    pub fn equals(self, value: bool) -> Condition {
+        self.condition(CheckBoxCondition::Equals(value))
+    }
+
+    pub fn does_not_equal(self, value: bool) -> Condition {
+        self.condition(CheckBoxCondition::DoesNotEqual(value))
+    }
+
+    fn condition(self, condition: CheckBoxCondition) -> Condition {
+        property(self.property, PropertyCondition::Checkbox(condition))
+    }
+}
+
+/// Conditions on `select` and `status` properties.
+#[derive(Debug, Clone)]
+pub struct SelectFilter {
+    property: String,
+    status: bool,
+}
+
+impl SelectFilter {
+    pub fn equals(self, value: impl Into<String>) -> Condition {
+        let value = value.into();
+        self.condition(
+            SelectCondition::Equals(value.clone()),
+            StatusCondition::Equals(value),
+        )
+    }
+
+    pub fn does_not_equal(self, value: impl Into<String>) -> Condition {
+        let value = value.into();
+        self.condition(
+            SelectCondition::DoesNotEqual(value.clone()),
+            StatusCondition::DoesNotEqual(value),
+        )
+    }
+
+    pub fn is_empty(self) -> Condition {
+        self.condition(SelectCondition::IsEmpty, StatusCondition::IsEmpty)
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        self.condition(SelectCondition::IsNotEmpty, StatusCondition::IsNotEmpty)
+    }
+
+    fn condition(self, select: SelectCondition, status: StatusCondition) -> Condition {
+        let condition = if self.status {
+            PropertyCondition::Status(status)
+        } else {
+            PropertyCondition::Select(select)
+        };
+        property(self.property, condition)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ListKind {
+    MultiSelect,
+    People,
+    Relation,
+}
+
+/// Conditions on `multi_select`, `people` and `relation` properties.
+#[derive(Debug, Clone)]
+pub struct ListFilter {
+    property: String,
+    kind: ListKind,
+}
+
+impl ListFilter {
+    pub fn contains(self, value: impl Into<String>) -> Condition {
+        let value = value.into();
+        self.condition(
+            MultiSelectCondition::Contains(value.clone()),
+            PeopleCondition::Contains(value.clone()),
+            RelationCondition::Contains(value),
+        )
+    }
+
+    pub fn does_not_contain(self, value: impl Into<String>) -> Condition {
+        let value = value.into();
+        self.condition(
+            MultiSelectCondition::DoesNotContain(value.clone()),
+            PeopleCondition::DoesNotContain(value.clone()),
+            RelationCondition::DoesNotContain(value),
+        )
+    }
+
+    pub fn is_empty(self) -> Condition {
+        self.condition(
+            MultiSelectCondition::IsEmpty,
+            PeopleCondition::IsEmpty,
+            RelationCondition::IsEmpty,
+        )
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        self.condition(
+            MultiSelectCondition::IsNotEmpty,
+            PeopleCondition::IsNotEmpty,
+            RelationCondition::IsNotEmpty,
+        )
+    }
+
+    fn condition(
+        self,
+        multi_select: MultiSelectCondition,
+        people: PeopleCondition,
+        relation: RelationCondition,
+    ) -> Condition {
+        let condition = match self.kind {
+            ListKind::MultiSelect => PropertyCondition::MultiSelect(multi_select),
+            ListKind::People => PropertyCondition::People(people),
+            ListKind::Relation => PropertyCondition::Relation(relation),
+        };
+        property(self.property, condition)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum DateTarget {
+    Property(String),
+    Timestamp(Timestamp),
+}
+
+/// Conditions on `date` properties and on the created/last edited timestamps.
+#[derive(Debug, Clone)]
+pub struct DateFilter {
+    target: DateTarget,
+}
+
+impl DateFilter {
+    pub fn equals(self, value: DateTime<Utc>) -> Condition {
+        self.condition(DateCondition::Equals(value))
+    }
+
+    pub fn before(self, value: DateTime<Utc>) -> Condition {
+        self.condition(DateCondition::Before(value))
+    }
+
+    pub fn after(self, value: DateTime<Utc>) -> Condition {
+        self.condition(DateCondition::After(value))
+    }
+
+    pub fn on_or_before(self, value: DateTime<Utc>) -> Condition {
+        self.condition(DateCondition::OnOrBefore(value))
+    }
+
+    pub fn on_or_after(self, value: DateTime<Utc>) -> Condition {
+        self.condition(DateCondition::OnOrAfter(value))
+    }
+
+    pub fn is_empty(self) -> Condition {
+        self.condition(DateCondition::IsEmpty)
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        self.condition(DateCondition::IsNotEmpty)
+    }
+
+    pub fn past_week(self) -> Condition {
+        self.condition(DateCondition::PastWeek)
+    }
+
+    pub fn past_month(self) -> Condition {
+        self.condition(DateCondition::PastMonth)
+    }
+
+    pub fn past_year(self) -> Condition {
+        self.condition(DateCondition::PastYear)
+    }
+
+    pub fn this_week(self) -> Condition {
+        self.condition(DateCondition::ThisWeek)
+    }
+
+    pub fn next_week(self) -> Condition {
+        self.condition(DateCondition::NextWeek)
+    }
+
+    pub fn next_month(self) -> Condition {
+        self.condition(DateCondition::NextMonth)
+    }
+
+    pub fn next_year(self) -> Condition {
+        self.condition(DateCondition::NextYear)
+    }
+
+    fn condition(self, condition: DateCondition) -> Condition {
+        match self.target {
+            DateTarget::Property(name) => property(name, PropertyCondition::Date(condition)),
+            DateTarget::Timestamp(timestamp) => {
+                let condition = match timestamp {
+                    Timestamp::CreatedTime => TimestampCondition::CreatedTime(condition),
+                    Timestamp::LastEditedTime => TimestampCondition::LastEditedTime(condition),
+                };
+                Condition(FilterType::Timestamp {
+                    timestamp,
+                    condition,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FilesFilter {
+    property: String,
+}
+
+impl FilesFilter {
+    pub fn is_empty(self) -> Condition {
+        property(
+            self.property,
+            PropertyCondition::Files(FilesCondition::IsEmpty),
+        )
+    }
+
+    pub fn is_not_empty(self) -> Condition {
+        property(
+            self.property,
+            PropertyCondition::Files(FilesCondition::IsNotEmpty),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UniqueIdFilter {
+    property: String,
+}
+
+impl UniqueIdFilter {
+    pub fn equals(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::Equals(value.into()))
+    }
+
+    pub fn does_not_equal(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::DoesNotEqual(value.into()))
+    }
+
+    pub fn greater_than(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::GreaterThan(value.into()))
+    }
+
+    pub fn greater_than_or_equal_to(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::GreaterThanOrEqualTo(value.into()))
+    }
+
+    pub fn less_than(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::LessThan(value.into()))
+    }
+
+    pub fn less_than_or_equal_to(self, value: impl Into<Number>) -> Condition {
+        self.condition(IDCondition::LessThanOrEqualTo(value.into()))
+    }
+
+    fn condition(self, condition: IDCondition) -> Condition {
+        property(self.property, PropertyCondition::ID(condition))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SortKey {
+    Property(String),
+    Timestamp(Timestamp),
+}
+
+#[derive(Debug, Clone)]
+pub struct SortBy {
+    key: SortKey,
+}
+
+impl SortBy {
+    pub fn asc(self) -> Sort {
+        self.direction(SortDirection::Ascending)
+    }
+
+    pub fn desc(self) -> Sort {
+        self.direction(SortDirection::Descending)
+    }
+
+    pub fn direction(self, direction: SortDirection) -> Sort {
+        match self.key {
+            SortKey::Property(property) => Sort::Property {
+                property,
+                direction,
+            },
+            SortKey::Timestamp(timestamp) => Sort::Timestamp {
+                timestamp,
+                direction,
+            },
+        }
+    }
+}
+
+pub(super) fn negate_filter(filter: FilterType) -> Option<FilterType> {
+    Some(match filter {
+        FilterType::Property {
+            property,
+            condition,
+        } => FilterType::Property {
+            property,
+            condition: negate_property(condition)?,
+        },
+        FilterType::Timestamp {
+            timestamp,
+            condition,
+        } => FilterType::Timestamp {
+            timestamp,
+            condition: negate_timestamp(condition)?,
+        },
+        FilterType::And { and } => FilterType::Or {
+            or: and.into_iter().map(negate_filter).collect::<Option<_>>()?,
+        },
+        FilterType::Or { or } => FilterType::And {
+            and: or.into_iter().map(negate_filter).collect::<Option<_>>()?,
+        },
+    })
+}
+
+fn negate_property(condition: PropertyCondition) -> Option<PropertyCondition> {
+    Some(match condition {
+        PropertyCondition::Checkbox(c) => PropertyCondition::Checkbox(negate_checkbox(c)),
+        PropertyCondition::Date(c) => PropertyCondition::Date(negate_date(c)?),
+        PropertyCondition::Files(c) => PropertyCondition::Files(match c {
+            FilesCondition::IsEmpty => FilesCondition::IsNotEmpty,
+            FilesCondition::IsNotEmpty => FilesCondition::IsEmpty,
+        }),
+        PropertyCondition::Formula(c) => PropertyCondition::Formula(match c {
+            FormulaCondition::Checkbox(c) => FormulaCondition::Checkbox(negate_checkbox(c)),
+            FormulaCondition::Date(c) => FormulaCondition::Date(negate_date(c)?),
+            FormulaCondition::Number(c) => FormulaCondition::Number(negate_number(c)),
+            FormulaCondition::String(c) => FormulaCondition::String(negate_text(c)?),
+        }),
+        PropertyCondition::MultiSelect(c) => PropertyCondition::MultiSelect(match c {
+            MultiSelectCondition::Contains(v) => MultiSelectCondition::DoesNotContain(v),
+            MultiSelectCondition::DoesNotContain(v) => MultiSelectCondition::Contains(v),
+            MultiSelectCondition::IsEmpty => MultiSelectCondition::IsNotEmpty,
+            MultiSelectCondition::IsNotEmpty => MultiSelectCondition::IsEmpty,
+        }),
+        PropertyCondition::Number(c) => PropertyCondition::Number(negate_number(c)),
+        PropertyCondition::People(c) => PropertyCondition::People(match c {
+            PeopleCondition::Contains(v) => PeopleCondition::DoesNotContain(v),
+            PeopleCondition::DoesNotContain(v) => PeopleCondition::Contains(v),
+            PeopleCondition::IsEmpty => PeopleCondition::IsNotEmpty,
+            PeopleCondition::IsNotEmpty => PeopleCondition::IsEmpty,
+        }),
+        PropertyCondition::Relation(c) => PropertyCondition::Relation(match c {
+            RelationCondition::Contains(v) => RelationCondition::DoesNotContain(v),
+            RelationCondition::DoesNotContain(v) => RelationCondition::Contains(v),
+            RelationCondition::IsEmpty => RelationCondition::IsNotEmpty,
+            RelationCondition::IsNotEmpty => RelationCondition::IsEmpty,
+        }),
+        PropertyCondition::RichText(c) => PropertyCondition::RichText(negate_text(c)?),
+        PropertyCondition::Title(c) => PropertyCondition::Title(negate_text(c)?),
+        PropertyCondition::Url(c) => PropertyCondition::Url(negate_text(c)?),
+        PropertyCondition::Email(c) => PropertyCondition::Email(negate_text(c)?),
+        PropertyCondition::PhoneNumber(c) => PropertyCondition::PhoneNumber(negate_text(c)?),
+        PropertyCondition::Rollup(c) => PropertyCondition::Rollup(Box::new(match *c {
+            RollupCondition::Any(c) => RollupCondition::None(c),
+            RollupCondition::None(c) => RollupCondition::Any(c),
+            RollupCondition::Every(c) => RollupCondition::Any(negate_property(c)?),
+            RollupCondition::Date(c) => RollupCondition::Date(negate_date(c)?),
+            RollupCondition::Number(c) => RollupCondition::Number(negate_number(c)),
+        })),
+        PropertyCondition::Select(c) => PropertyCondition::Select(match c {
+            SelectCondition::Equals(v) => SelectCondition::DoesNotEqual(v),
+            SelectCondition::DoesNotEqual(v) => SelectCondition::Equals(v),
+            SelectCondition::IsEmpty => SelectCondition::IsNotEmpty,
+            SelectCondition::IsNotEmpty => SelectCondition::IsEmpty,
+        }),
+        PropertyCondition::Status(c) => PropertyCondition::Status(match c {
+            StatusCondition::Equals(v) => StatusCondition::DoesNotEqual(v),
+            StatusCondition::DoesNotEqual(v) => StatusCondition::Equals(v),
+            StatusCondition::IsEmpty => StatusCondition::IsNotEmpty,
+            StatusCondition::IsNotEmpty => StatusCondition::IsEmpty,
+        }),
+        PropertyCondition::Timestamp(c) => PropertyCondition::Timestamp(negate_timestamp(c)?),
+        PropertyCondition::ID(c) => PropertyCondition::ID(match c {
+            IDCondition::Equals(v) => IDCondition::DoesNotEqual(v),
+            IDCondition::DoesNotEqual(v) => IDCondition::Equals(v),
+            IDCondition::GreaterThan(v) => IDCondition::LessThanOrEqualTo(v),
+            IDCondition::GreaterThanOrEqualTo(v) => IDCondition::LessThan(v),
+            IDCondition::LessThan(v) => IDCondition::GreaterThanOrEqualTo(v),
+            IDCondition::LessThanOrEqualTo(v) => IDCondition::GreaterThan(v),
+        }),
+    })
+}
+
+fn negate_checkbox(condition: CheckBoxCondition) -> CheckBoxCondition {
+    match condition {
+        CheckBoxCondition::Equals(v) => CheckBoxCondition::DoesNotEqual(v),
+        CheckBoxCondition::DoesNotEqual(v) => CheckBoxCondition::Equals(v),
+    }
+}
+
+fn negate_timestamp(condition: TimestampCondition) -> Option<TimestampCondition> {
+    Some(match condition {
+        TimestampCondition::CreatedTime(c) => TimestampCondition::CreatedTime(negate_date(c)?),
+        TimestampCondition::LastEditedTime(c) => {
+            TimestampCondition::LastEditedTime(negate_date(c)?)
+        }
+    })
+}
+
+fn negate_date(condition: DateCondition) -> Option<DateCondition> {
+    Some(match condition {
+        DateCondition::After(v) => DateCondition::OnOrBefore(v),
+        DateCondition::Before(v) => DateCondition::OnOrAfter(v),
+        DateCondition::OnOrAfter(v) => DateCondition::Before(v),
+        DateCondition::OnOrBefore(v) => DateCondition::After(v),
+        DateCondition::IsEmpty => DateCondition::IsNotEmpty,
+        DateCondition::IsNotEmpty => DateCondition::IsEmpty,
+        _ => return None,
+    })
+}
+
+fn negate_number(condition: NumberCondition) -> NumberCondition {
+    match condition {
+        NumberCondition::Equals(v) => NumberCondition::DoesNotEqual(v),
+        NumberCondition::DoesNotEqual(v) => NumberCondition::Equals(v),
+        NumberCondition::GreaterThan(v) => NumberCondition::LessThanOrEqualTo(v),
+        NumberCondition::GreaterThanOrEqualTo(v) => NumberCondition::LessThan(v),
+        NumberCondition::LessThan(v) => NumberCondition::GreaterThanOrEqualTo(v),
+        NumberCondition::LessThanOrEqualTo(v) => NumberCondition::GreaterThan(v),
+        NumberCondition::IsEmpty => NumberCondition::IsNotEmpty,
+        NumberCondition::IsNotEmpty => NumberCondition::IsEmpty,
+    }
+}
+
+fn negate_text(condition: RichTextCondition) -> Option<RichTextCondition> {
+    Some(match condition {
+        RichTextCondition::Equals(v) => RichTextCondition::DoesNotEqual(v),
+        RichTextCondition::DoesNotEqual(v) => RichTextCondition::Equals(v),
+        RichTextCondition::Contains(v) => RichTextCondition::DoesNotContain(v),
+        RichTextCondition::DoesNotContain(v) => RichTextCondition::Contains(v),
+        RichTextCondition::IsEmpty => RichTextCondition::IsNotEmpty,
+        RichTextCondition::IsNotEmpty => RichTextCondition::IsEmpty,
+        RichTextCondition::StartsWith(_) | RichTextCondition::EndsWith(_) => return None,
+    })
+}
@@ -0,0 +1,1248 @@
+//! A small text syntax for database query filters and sorts, e.g.
+//! `Status = "Done" and (Priority > 2 or Tags contains "urgent") order by Priority desc`.
+//!
+//! A condition is `property[:type] operator [value]`. Property names that are
+//! not plain identifiers are written in double quotes. The type picks the
+//! Notion condition (`title`, `rich_text`, `url`, `email`, `phone_number`,
+//! `number`, `checkbox`, `select`, `status`, `multi_select`, `people`,
+//! `relation`, `date`, `files`, `unique_id`, `created_time`,
+//! `last_edited_time`, `formula.<checkbox|date|number|string>`,
+//! `rollup.<any|every|none>.<type>`, `rollup.date`, `rollup.number`); without
+//! it the type comes from the database schema when one is given and is
+//! otherwise inferred from the operator and value. Unquoted `created_time`
+//! and `last_edited_time` filter on the page timestamps.
+//!
+//! Operators are `=`, `!=`, `>`, `>=`, `<`, `<=` and the Notion condition
+//! names such as `contains`, `starts_with`, `is_empty`, `before` or
+//! `past_week`. Conditions combine with `and`, `or`, `not` and parentheses.
+
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use serde_json::Number;
+
+use crate::{
+    objects::database::{Database, DatabaseProperty},
+    NotionClientError,
+};
+
+use super::{
+    builder::negate_filter,
+    request::{
+        CheckBoxCondition, DateCondition, FilesCondition, Filter, FilterType, FormulaCondition,
+        IDCondition, MultiSelectCondition, NumberCondition, PeopleCondition, PropertyCondition,
+        QueryDatabaseRequest, RelationCondition, RichTextCondition, RollupCondition,
+        SelectCondition, Sort, SortDirection, StatusCondition, Timestamp, TimestampCondition,
+    },
+};
+
+const KEYWORDS: &[&str] = &[
+    "and",
+    "or",
+    "not",
+    "order",
+    "by",
+    "asc",
+    "desc",
+    "true",
+    "false",
+    "created_time",
+    "last_edited_time",
+];
+
+/// Parses a filter expression. `schema` is used to pick the condition type
+/// of properties written without a `:type` annotation.
+pub fn parse_filter(input: &str, schema: Option<&Database>) -> Result<Filter, NotionClientError> {
+    let mut parser = Parser::new(input, schema)?;
+    let filter = parser.parse_or()?;
+    parser.expect_end()?;
+    Ok(filter.into())
+}
+
+/// Parses an optional filter expression followed by an optional
+/// `order by property [asc|desc], ...` clause.
+pub fn parse_query(
+    input: &str,
+    schema: Option<&Database>,
+) -> Result<QueryDatabaseRequest, NotionClientError> {
+    let mut parser = Parser::new(input, schema)?;
+
+    let filter = if parser.at_end() || parser.at_keyword("order") {
+        None
+    } else {
+        Some(parser.parse_or()?.into())
+    };
+    let sorts = if parser.at_keyword("order") {
+        Some(parser.parse_sorts()?)
+    } else {
+        None
+    };
+    parser.expect_end()?;
+
+    Ok(QueryDatabaseRequest {
+        filter,
+        sorts,
+        ..Default::default()
+    })
+}
+
+/// Formats a filter in the syntax accepted by [`parse_filter`], with every
+/// property condition annotated with its type.
+pub fn format_filter(filter: &Filter) -> String {
+    match filter {
+        Filter::Value { filter_type } => format_filter_type(filter_type, false),
+        Filter::And { and } => format_group(and, "and"),
+        Filter::Or { or } => format_group(or, "or"),
+    }
+}
+
+pub fn format_sorts(sorts: &[Sort]) -> String {
+    let sorts: Vec<String> = sorts
+        .iter()
+        .map(|sort| match sort {
+            Sort::Property {
+                property,
+                direction,
+            } => format!("{} {}", format_name(property), format_direction(direction)),
+            Sort::Timestamp {
+                timestamp,
+                direction,
+            } => format!(
+                "{} {}",
+                timestamp_name(timestamp),
+                format_direction(direction)
+            ),
+        })
+        .collect();
+    format!("order by {}", sorts.join(", "))
+}
+
+/// Formats the filter and sorts of `request` in the syntax accepted by [`parse_query`].
+pub fn format_query(request: &QueryDatabaseRequest) -> String {
+    let filter = request.filter.as_ref().map(format_filter);
+    let sorts = request
+        .sorts
+        .as_deref()
+        .filter(|sorts| !sorts.is_empty())
+        .map(format_sorts);
+    filter
+        .into_iter()
+        .chain(sorts)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Number(Number),
+    Symbol(&'static str),
+    LParen,
+    RParen,
+    Colon,
+    Comma,
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(ident) => write!(f, "`{}`", ident),
+            Token::Str(s) => write!(f, "string {:?}", s),
+            Token::Number(n) => write!(f, "number {}", n),
+            Token::Symbol(symbol) => write!(f, "`{}`", symbol),
+            Token::LParen => f.write_str("`(`"),
+            Token::RParen => f.write_str("`)`"),
+            Token::Colon => f.write_str("`:`"),
+            Token::Comma => f.write_str("`,`"),
+            Token::End => f.write_str("end of input"),
+        }
+    }
+}
+
+fn error(message: impl Into<String>, position: usize) -> NotionClientError {
+    NotionClientError::InvalidFilterExpression {
+        message: message.into(),
+        position,
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, NotionClientError> {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let token = match c {
+            '(' | ')' | ':' | ',' | '=' => {
+                chars.next();
+                match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    ':' => Token::Colon,
+                    ',' => Token::Comma,
+                    _ => Token::Symbol("="),
+                }
+            }
+            '!' | '<' | '>' => {
+                chars.next();
+                let equals = chars.next_if(|&(_, c)| c == '=').is_some();
+                match (c, equals) {
+                    ('!', true) => Token::Symbol("!="),
+                    ('<', true) => Token::Symbol("<="),
+                    ('<', false) => Token::Symbol("<"),
+                    ('>', true) => Token::Symbol(">="),
+                    ('>', false) => Token::Symbol(">"),
+                    _ => return Err(error("expected `!=`", start)),
+                }
+            }
+            '"' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c)) => value.push(c),
+                            None => return Err(error("unterminated string", start)),
+                        },
+                        Some((_, c)) => value.push(c),
+                        None => return Err(error("unterminated string", start)),
+                    }
+                }
+                Token::Str(value)
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut end = start;
+                while let Some((i, c)) =
+                    chars.next_if(|&(_, c)| c.is_ascii_alphanumeric() || "-+.".contains(c))
+                {
+                    end = i + c.len_utf8();
+                }
+                let literal = &input[start..end];
+                let number = literal
+                    .parse::<Number>()
+                    .map_err(|_| error(format!("invalid number `{}`", literal), start))?;
+                Token::Number(number)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start;
+                while let Some((i, c)) =
+                    chars.next_if(|&(_, c)| c.is_alphanumeric() || c == '_' || c == '.')
+                {
+                    end = i + c.len_utf8();
+                }
+                Token::Ident(input[start..end].to_string())
+            }
+            c => return Err(error(format!("unexpected character `{}`", c), start)),
+        };
+        tokens.push((token, start));
+    }
+
+    tokens.push((Token::End, input.len()));
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equals,
+    DoesNotEqual,
+    GreaterThan,
+    GreaterThanOrEqualTo,
+    LessThan,
+    LessThanOrEqualTo,
+    Contains,
+    DoesNotContain,
+    StartsWith,
+    EndsWith,
+    IsEmpty,
+    IsNotEmpty,
+    Before,
+    After,
+    OnOrBefore,
+    OnOrAfter,
+    PastWeek,
+    PastMonth,
+    PastYear,
+    ThisWeek,
+    NextWeek,
+    NextMonth,
+    NextYear,
+}
+
+const OPS: &[(&str, Op)] = &[
+    ("=", Op::Equals),
+    ("!=", Op::DoesNotEqual),
+    (">", Op::GreaterThan),
+    (">=", Op::GreaterThanOrEqualTo),
+    ("<", Op::LessThan),
+    ("<=", Op::LessThanOrEqualTo),
+    ("equals", Op::Equals),
+    ("does_not_equal", Op::DoesNotEqual),
+    ("greater_than", Op::GreaterThan),
+    ("greater_than_or_equal_to", Op::GreaterThanOrEqualTo),
+    ("less_than", Op::LessThan),
+    ("less_than_or_equal_to", Op::LessThanOrEqualTo),
+    ("contains", Op::Contains),
+    ("does_not_contain", Op::DoesNotContain),
+    ("starts_with", Op::StartsWith),
+    ("ends_with", Op::EndsWith),
+    ("is_empty", Op::IsEmpty),
+    ("is_not_empty", Op::IsNotEmpty),
+    ("before", Op::Before),
+    ("after", Op::After),
+    ("on_or_before", Op::OnOrBefore),
+    ("on_or_after", Op::OnOrAfter),
+    ("past_week", Op::PastWeek),
+    ("past_month", Op::PastMonth),
+    ("past_year", Op::PastYear),
+    ("this_week", Op::ThisWeek),
+    ("next_week", Op::NextWeek),
+    ("next_month", Op::NextMonth),
+    ("next_year", Op::NextYear),
+];
+
+impl Op {
+    fn parse(token: &Token) -> Option<Self> {
+        let name = match token {
+            Token::Symbol(symbol) => *symbol,
+            Token::Ident(ident) => ident.as_str(),
+            _ => return None,
+        };
+        OPS.iter()
+            .find(|(op, _)| op.eq_ignore_ascii_case(name))
+            .map(|(_, op)| *op)
+    }
+
+    fn takes_value(self) -> bool {
+        !matches!(
+            self,
+            Op::IsEmpty
+                | Op::IsNotEmpty
+                | Op::PastWeek
+                | Op::PastMonth
+                | Op::PastYear
+                | Op::ThisWeek
+                | Op::NextWeek
+                | Op::NextMonth
+                | Op::NextYear
+        )
+    }
+
+    fn is_date(self) -> bool {
+        matches!(
+            self,
+            Op::Before
+                | Op::After
+                | Op::OnOrBefore
+                | Op::OnOrAfter
+                | Op::PastWeek
+                | Op::PastMonth
+                | Op::PastYear
+                | Op::ThisWeek
+                | Op::NextWeek
+                | Op::NextMonth
+                | Op::NextYear
+        )
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = OPS
+            .iter()
+            .find(|(_, op)| op == self)
+            .map(|(name, _)| *name)
+            .unwrap_or_default();
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Str(String),
+    Number(Number),
+    Bool(bool),
+    Date(DateTime<Utc>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{}", quote(s)),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Date(d) => write!(
+                f,
+                "{}",
+                quote(&d.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            ),
+        }
+    }
+}
+
+impl Value {
+    fn string(self) -> Result<String, String> {
+        match self {
+            Value::Str(s) => Ok(s),
+            Value::Number(n) => Ok(n.to_string()),
+            _ => Err("expected a string".to_string()),
+        }
+    }
+
+    fn number(self) -> Result<Number, String> {
+        match self {
+            Value::Number(n) => Ok(n),
+            _ => Err("expected a number".to_string()),
+        }
+    }
+
+    fn bool(self) -> Result<bool, String> {
+        match self {
+            Value::Bool(b) => Ok(b),
+            _ => Err("expected `true` or `false`".to_string()),
+        }
+    }
+
+    fn date(self) -> Result<DateTime<Utc>, String> {
+        let Value::Str(s) = self else {
+            return Err("expected a quoted date".to_string());
+        };
+        if let Ok(date) = DateTime::parse_from_rfc3339(&s) {
+            return Ok(date.with_timezone(&Utc));
+        }
+        NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc())
+            .ok_or_else(|| format!("invalid date {:?}, expected RFC 3339 or YYYY-MM-DD", s))
+    }
+}
+
+/// The condition type of a property, as written after `:`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    Title,
+    RichText,
+    Url,
+    Email,
+    PhoneNumber,
+    Number,
+    Checkbox,
+    Select,
+    Status,
+    MultiSelect,
+    People,
+    Relation,
+    Date,
+    Files,
+    UniqueId,
+    CreatedTime,
+    LastEditedTime,
+    Formula(FormulaKind),
+    RollupAny(Box<Kind>),
+    RollupEvery(Box<Kind>),
+    RollupNone(Box<Kind>),
+    RollupDate,
+    RollupNumber,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormulaKind {
+    Checkbox,
+    Date,
+    Number,
+    String,
+}
+
+impl Kind {
+    fn parse(path: &str) -> Option<Self> {
+        Some(match path.split_once('.') {
+            None => match path {
+                "title" => Kind::Title,
+                "rich_text" => Kind::RichText,
+                "url" => Kind::Url,
+                "email" => Kind::Email,
+                "phone_number" => Kind::PhoneNumber,
+                "number" => Kind::Number,
+                "checkbox" => Kind::Checkbox,
+                "select" => Kind::Select,
+                "status" => Kind::Status,
+                "multi_select" => Kind::MultiSelect,
+                "people" => Kind::People,
+                "relation" => Kind::Relation,
+                "date" => Kind::Date,
+                "files" => Kind::Files,
+                "unique_id" => Kind::UniqueId,
+                "created_time" => Kind::CreatedTime,
+                "last_edited_time" => Kind::LastEditedTime,
+                _ => return None,
+            },
+            Some(("formula", result)) => Kind::Formula(match result {
+                "checkbox" => FormulaKind::Checkbox,
+                "date" => FormulaKind::Date,
+                "number" => FormulaKind::Number,
+                "string" => FormulaKind::String,
+                _ => return None,
+            }),
+            Some(("rollup", "date")) => Kind::RollupDate,
+            Some(("rollup", "number")) => Kind::RollupNumber,
+            Some(("rollup", rest)) => {
+                let (quantifier, kind) = rest.split_once('.')?;
+                let kind = Box::new(Kind::parse(kind)?);
+                match quantifier {
+                    "any" => Kind::RollupAny(kind),
+                    "every" => Kind::RollupEvery(kind),
+                    "none" => Kind::RollupNone(kind),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// Guesses the type from the operator and value when neither an
+    /// annotation nor a schema says otherwise.
+    fn infer(op: Op, value: Option<&Value>) -> Self {
+        match value {
+            _ if op.is_date() => Kind::Date,
+            Some(Value::Bool(_)) => Kind::Checkbox,
+            Some(Value::Number(_)) => Kind::Number,
+            _ => Kind::RichText,
+        }
+    }
+
+    fn from_schema(property: &DatabaseProperty, op: Op, value: Option<&Value>) -> Option<Self> {
+        Some(match property {
+            DatabaseProperty::Checkbox { .. } => Kind::Checkbox,
+            DatabaseProperty::CreatedBy { .. }
+            | DatabaseProperty::LastEditedBy { .. }
+            | DatabaseProperty::People { .. } => Kind::People,
+            DatabaseProperty::CreatedTime { .. } => Kind::CreatedTime,
+            DatabaseProperty::LastEditedTime { .. } => Kind::LastEditedTime,
+            DatabaseProperty::Date { .. } => Kind::Date,
+            DatabaseProperty::Email { .. } => Kind::Email,
+            DatabaseProperty::Files { .. } => Kind::Files,
+            DatabaseProperty::Formula { .. } => Kind::Formula(match Kind::infer(op, value) {
+                Kind::Date => FormulaKind::Date,
+                Kind::Checkbox => FormulaKind::Checkbox,
+                Kind::Number => FormulaKind::Number,
+                _ => FormulaKind::String,
+            }),
+            DatabaseProperty::MultiSelect { .. } => Kind::MultiSelect,
+            DatabaseProperty::Number { .. } => Kind::Number,
+            DatabaseProperty::PhoneNumber { .. } => Kind::PhoneNumber,
+            DatabaseProperty::Relation { .. } => Kind::Relation,
+            DatabaseProperty::RichText { .. } => Kind::RichText,
+            DatabaseProperty::Rollup { .. } => match Kind::infer(op, value) {
+                Kind::Date => Kind::RollupDate,
+                Kind::Number => Kind::RollupNumber,
+                _ => return None,
+            },
+            DatabaseProperty::Select { .. } => Kind::Select,
+            DatabaseProperty::Status { .. } => Kind::Status,
+            DatabaseProperty::Title { .. } => Kind::Title,
+            DatabaseProperty::Url { .. } => Kind::Url,
+            DatabaseProperty::UniqueID { .. } => Kind::UniqueId,
+            DatabaseProperty::Button { .. }
+            | DatabaseProperty::Verification { .. }
+            | DatabaseProperty::Place { .. } => return None,
+        })
+    }
+
+    fn condition(&self, op: Op, value: Option<Value>) -> Result<PropertyCondition, String> {
+        Ok(match self {
+            Kind::Title => PropertyCondition::Title(text_condition(op, value)?),
+            Kind::RichText => PropertyCondition::RichText(text_condition(op, value)?),
+            Kind::Url => PropertyCondition::Url(text_condition(op, value)?),
+            Kind::Email => PropertyCondition::Email(text_condition(op, value)?),
+            Kind::PhoneNumber => PropertyCondition::PhoneNumber(text_condition(op, value)?),
+            Kind::Number => PropertyCondition::Number(number_condition(op, value)?),
+            Kind::Checkbox => PropertyCondition::Checkbox(checkbox_condition(op, value)?),
+            Kind::Select => PropertyCondition::Select(match (op, value) {
+                (Op::Equals, Some(v)) => SelectCondition::Equals(v.string()?),
+                (Op::DoesNotEqual, Some(v)) => SelectCondition::DoesNotEqual(v.string()?),
+                (Op::IsEmpty, _) => SelectCondition::IsEmpty,
+                (Op::IsNotEmpty, _) => SelectCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "select")),
+            }),
+            Kind::Status => PropertyCondition::Status(match (op, value) {
+                (Op::Equals, Some(v)) => StatusCondition::Equals(v.string()?),
+                (Op::DoesNotEqual, Some(v)) => StatusCondition::DoesNotEqual(v.string()?),
+                (Op::IsEmpty, _) => StatusCondition::IsEmpty,
+                (Op::IsNotEmpty, _) => StatusCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "status")),
+            }),
+            Kind::MultiSelect => PropertyCondition::MultiSelect(match (op, value) {
+                (Op::Contains, Some(v)) => MultiSelectCondition::Contains(v.string()?),
+                (Op::DoesNotContain, Some(v)) => MultiSelectCondition::DoesNotContain(v.string()?),
+                (Op::IsEmpty, _) => MultiSelectCondition::IsEmpty,
+                (Op::IsNotEmpty, _) => MultiSelectCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "multi_select")),
+            }),
+            Kind::People => PropertyCondition::People(match (op, value) {
+                (Op::Contains, Some(v)) => PeopleCondition::Contains(v.string()?),
+                (Op::DoesNotContain, Some(v)) => PeopleCondition::DoesNotContain(v.string()?),
+                (Op::IsEmpty, _) => PeopleCondition::IsEmpty,
+                (Op::IsNotEmpty, _) => PeopleCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "people")),
+            }),
+            Kind::Relation => PropertyCondition::Relation(match (op, value) {
+                (Op::Contains, Some(v)) => RelationCondition::Contains(v.string()?),
+                (Op::DoesNotContain, Some(v)) => RelationCondition::DoesNotContain(v.string()?),
+                (Op::IsEmpty, _) => RelationCondition::IsEmpty,
+                (Op::IsNotEmpty, _) => RelationCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "relation")),
+            }),
+            Kind::Date => PropertyCondition::Date(date_condition(op, value)?),
+            Kind::Files => PropertyCondition::Files(match op {
+                Op::IsEmpty => FilesCondition::IsEmpty,
+                Op::IsNotEmpty => FilesCondition::IsNotEmpty,
+                _ => return Err(unsupported(op, "files")),
+            }),
+            Kind::UniqueId => PropertyCondition::ID(match (op, value) {
+                (Op::Equals, Some(v)) => IDCondition::Equals(v.number()?),
+                (Op::DoesNotEqual, Some(v)) => IDCondition::DoesNotEqual(v.number()?),
+                (Op::GreaterThan, Some(v)) => IDCondition::GreaterThan(v.number()?),
+                (Op::GreaterThanOrEqualTo, Some(v)) => {
+                    IDCondition::GreaterThanOrEqualTo(v.number()?)
+                }
+                (Op::LessThan, Some(v)) => IDCondition::LessThan(v.number()?),
+                (Op::LessThanOrEqualTo, Some(v)) => IDCondition::LessThanOrEqualTo(v.number()?),
+                _ => return Err(unsupported(op, "unique_id")),
+            }),
+            Kind::CreatedTime => PropertyCondition::Timestamp(TimestampCondition::CreatedTime(
+                date_condition(op, value)?,
+            )),
+            Kind::LastEditedTime => PropertyCondition::Timestamp(
+                TimestampCondition::LastEditedTime(date_condition(op, value)?),
+            ),
+            Kind::Formula(kind) => PropertyCondition::Formula(match kind {
+                FormulaKind::Checkbox => FormulaCondition::Checkbox(checkbox_condition(op, value)?),
+                FormulaKind::Date => FormulaCondition::Date(date_condition(op, value)?),
+                FormulaKind::Number => FormulaCondition::Number(number_condition(op, value)?),
+                FormulaKind::String => FormulaCondition::String(text_condition(op, value)?),
+            }),
+            Kind::RollupAny(kind) => rollup(RollupCondition::Any(kind.condition(op, value)?)),
+            Kind::RollupEvery(kind) => rollup(RollupCondition::Every(kind.condition(op, value)?)),
+            Kind::RollupNone(kind) => rollup(RollupCondition::None(kind.condition(op, value)?)),
+            Kind::RollupDate => rollup(RollupCondition::Date(date_condition(op, value)?)),
+            Kind::RollupNumber => rollup(RollupCondition::Number(number_condition(op, value)?)),
+        })
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Title => f.write_str("title"),
+            Kind::RichText => f.write_str("rich_text"),
+            Kind::Url => f.write_str("url"),
+            Kind::Email => f.write_str("email"),
+            Kind::PhoneNumber => f.write_str("phone_number"),
+            Kind::Number => f.write_str("number"),
+            Kind::Checkbox => f.write_str("checkbox"),
+            Kind::Select => f.write_str("select"),
+            Kind::Status => f.write_str("status"),
+            Kind::MultiSelect => f.write_str("multi_select"),
+            Kind::People => f.write_str("people"),
+            Kind::Relation => f.write_str("relation"),
+            Kind::Date => f.write_str("date"),
+            Kind::Files => f.write_str("files"),
+            Kind::UniqueId => f.write_str("unique_id"),
+            Kind::CreatedTime => f.write_str("created_time"),
+            Kind::LastEditedTime => f.write_str("last_edited_time"),
+            Kind::Formula(FormulaKind::Checkbox) => f.write_str("formula.checkbox"),
+            Kind::Formula(FormulaKind::Date) => f.write_str("formula.date"),
+            Kind::Formula(FormulaKind::Number) => f.write_str("formula.number"),
+            Kind::Formula(FormulaKind::String) => f.write_str("formula.string"),
+            Kind::RollupAny(kind) => write!(f, "rollup.any.{}", kind),
+            Kind::RollupEvery(kind) => write!(f, "rollup.every.{}", kind),
+            Kind::RollupNone(kind) => write!(f, "rollup.none.{}", kind),
+            Kind::RollupDate => f.write_str("rollup.date"),
+            Kind::RollupNumber => f.write_str("rollup.number"),
+        }
+    }
+}
+
+fn rollup(condition: RollupCondition) -> PropertyCondition {
+    PropertyCondition::Rollup(Box::new(condition))
+}
+
+fn unsupported(op: Op, kind: &str) -> String {
+    format!("`{}` is not a {} condition", op, kind)
+}
+
+fn text_condition(op: Op, value: Option<Value>) -> Result<RichTextCondition, String> {
+    Ok(match (op, value) {
+        (Op::Equals, Some(v)) => RichTextCondition::Equals(v.string()?),
+        (Op::DoesNotEqual, Some(v)) => RichTextCondition::DoesNotEqual(v.string()?),
+        (Op::Contains, Some(v)) => RichTextCondition::Contains(v.string()?),
+        (Op::DoesNotContain, Some(v)) => RichTextCondition::DoesNotContain(v.string()?),
+        (Op::StartsWith, Some(v)) => RichTextCondition::StartsWith(v.string()?),
+        (Op::EndsWith, Some(v)) => RichTextCondition::EndsWith(v.string()?),
+        (Op::IsEmpty, _) => RichTextCondition::IsEmpty,
+        (Op::IsNotEmpty, _) => RichTextCondition::IsNotEmpty,
+        _ => return Err(unsupported(op, "text")),
+    })
+}
+
+fn number_condition(op: Op, value: Option<Value>) -> Result<NumberCondition, String> {
+    Ok(match (op, value) {
+        (Op::Equals, Some(v)) => NumberCondition::Equals(v.number()?),
+        (Op::DoesNotEqual, Some(v)) => NumberCondition::DoesNotEqual(v.number()?),
+        (Op::GreaterThan, Some(v)) => NumberCondition::GreaterThan(v.number()?),
+        (Op::GreaterThanOrEqualTo, Some(v)) => NumberCondition::GreaterThanOrEqualTo(v.number()?),
+        (Op::LessThan, Some(v)) => NumberCondition::LessThan(v.number()?),
+        (Op::LessThanOrEqualTo, Some(v)) => NumberCondition::LessThanOrEqualTo(v.number()?),
+        (Op::IsEmpty, _) => NumberCondition::IsEmpty,
+        (Op::IsNotEmpty, _) => NumberCondition::IsNotEmpty,
+        _ => return Err(unsupported(op, "number")),
+    })
+}
+
+fn checkbox_condition(op: Op, value: Option<Value>) -> Result<CheckBoxCondition, String> {
+    Ok(match (op, value) {
+        (Op::Equals, Some(v)) => CheckBoxCondition::Equals(v.bool()?),
+        (Op::DoesNotEqual, Some(v)) => CheckBoxCondition::DoesNotEqual(v.bool()?),
+        _ => return Err(unsupported(op, "checkbox")),
+    })
+}
+
+fn date_condition(op: Op, value: Option<Value>) -> Result<DateCondition, String> {
+    Ok(match (op, value) {
+        (Op::Equals, Some(v)) => DateCondition::Equals(v.date()?),
+        (Op::Before | Op::LessThan, Some(v)) => DateCondition::Before(v.date()?),
+        (Op::After | Op::GreaterThan, Some(v)) => DateCondition::After(v.date()?),
+        (Op::OnOrBefore | Op::LessThanOrEqualTo, Some(v)) => DateCondition::OnOrBefore(v.date()?),
+        (Op::OnOrAfter | Op::GreaterThanOrEqualTo, Some(v)) => DateCondition::OnOrAfter(v.date()?),
+        (Op::IsEmpty, _) => DateCondition::IsEmpty,
+        (Op::IsNotEmpty, _) => DateCondition::IsNotEmpty,
+        (Op::PastWeek, _) => DateCondition::PastWeek,
+        (Op::PastMonth, _) => DateCondition::PastMonth,
+        (Op::PastYear, _) => DateCondition::PastYear,
+        (Op::ThisWeek, _) => DateCondition::ThisWeek,
+        (Op::NextWeek, _) => DateCondition::NextWeek,
+        (Op::NextMonth, _) => DateCondition::NextMonth,
+        (Op::NextYear, _) => DateCondition::NextYear,
+        _ => return Err(unsupported(op, "date")),
+    })
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    schema: Option<&'a Database>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &str, schema: Option<&'a Database>) -> Result<Self, NotionClientError> {
+        Ok(Self {
+            tokens: tokenize(input)?,
+            index: 0,
+            schema,
+        })
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.index].1
+    }
+
+    fn next(&mut self) -> (Token, usize) {
+        let token = self.tokens[self.index].clone();
+        if self.index + 1 < self.tokens.len() {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        *self.peek() == Token::End
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Token::Ident(ident) if ident.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.at_keyword(keyword);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn unexpected(&self, expected: &str) -> NotionClientError {
+        error(
+            format!("expected {}, found {}", expected, self.peek()),
+            self.position(),
+        )
+    }
+
+    fn expect_end(&self) -> Result<(), NotionClientError> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected("`and`, `or` or end of input"))
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterType, NotionClientError> {
+        let mut filters = vec![self.parse_and()?];
+        while self.eat_keyword("or") {
+            filters.push(self.parse_and()?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            FilterType::Or { or: filters }
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<FilterType, NotionClientError> {
+        let mut filters = vec![self.parse_unary()?];
+        while self.eat_keyword("and") {
+            filters.push(self.parse_unary()?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            FilterType::And { and: filters }
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterType, NotionClientError> {
+        let position = self.position();
+        if self.eat_keyword("not") {
+            let filter = self.parse_unary()?;
+            return negate_filter(filter)
+                .ok_or_else(|| error("condition has no negated equivalent in Notion", position));
+        }
+        if *self.peek() == Token::LParen {
+            self.next();
+            let filter = self.parse_or()?;
+            if *self.peek() != Token::RParen {
+                return Err(self.unexpected("`)`"));
+            }
+            self.next();
+            return Ok(filter);
+        }
+        self.parse_condition()
+    }
+
+    fn parse_name(&mut self) -> Result<(String, bool, usize), NotionClientError> {
+        match self.peek().clone() {
+            Token::Str(name) => Ok((name, true, self.next().1)),
+            Token::Ident(name) if !is_reserved(&name) || is_timestamp(&name) => {
+                Ok((name, false, self.next().1))
+            }
+            _ => Err(self.unexpected("a property name")),
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<FilterType, NotionClientError> {
+        let (name, quoted, name_position) = self.parse_name()?;
+
+        let kind = if *self.peek() == Token::Colon {
+            self.next();
+            let (token, position) = self.next();
+            let Token::Ident(path) = &token else {
+                return Err(error(
+                    format!("expected a property type, found {}", token),
+                    position,
+                ));
+            };
+            Some(
+                Kind::parse(path)
+                    .ok_or_else(|| error(format!("unknown property type `{}`", path), position))?,
+            )
+        } else {
+            None
+        };
+
+        let op_position = self.position();
+        let op = Op::parse(self.peek()).ok_or_else(|| self.unexpected("an operator"))?;
+        self.next();
+
+        let value = if op.takes_value() {
+            let (token, position) = self.next();
+            Some(match token {
+                Token::Str(s) => Value::Str(s),
+                Token::Number(n) => Value::Number(n),
+                Token::Ident(ident) if ident.eq_ignore_ascii_case("true") => Value::Bool(true),
+                Token::Ident(ident) if ident.eq_ignore_ascii_case("false") => Value::Bool(false),
+                token => {
+                    return Err(error(
+                        format!("expected a value, found {}", token),
+                        position,
+                    ))
+                }
+            })
+        } else {
+            None
+        };
+
+        let schema = self
+            .schema
+            .and_then(|database| database.properties.get(&name));
+        let kind = match (kind, schema) {
+            (Some(kind), _) => kind,
+            (None, Some(property)) => {
+                let kind = Kind::from_schema(property, op, value.as_ref()).ok_or_else(|| {
+                    error(
+                        format!(
+                            "cannot filter on property `{}` without a `:type` annotation",
+                            name
+                        ),
+                        name_position,
+                    )
+                })?;
+                // Notion filters created and last edited time properties as page timestamps.
+                match kind {
+                    Kind::CreatedTime => {
+                        return timestamp_filter("created_time", op, value)
+                            .map_err(|message| error(message, op_position))
+                    }
+                    Kind::LastEditedTime => {
+                        return timestamp_filter("last_edited_time", op, value)
+                            .map_err(|message| error(message, op_position))
+                    }
+                    kind => kind,
+                }
+            }
+            (None, None) if !quoted && is_timestamp(&name) => {
+                return timestamp_filter(&name, op, value)
+                    .map_err(|message| error(message, op_position));
+            }
+            (None, None) if self.schema.is_some() => {
+                return Err(error(format!("unknown property `{}`", name), name_position))
+            }
+            (None, None) => Kind::infer(op, value.as_ref()),
+        };
+
+        let condition = kind
+            .condition(op, value)
+            .map_err(|message| error(message, op_position))?;
+        Ok(FilterType::Property {
+            property: name,
+            condition,
+        })
+    }
+
+    fn parse_sorts(&mut self) -> Result<Vec<Sort>, NotionClientError> {
+        self.next();
+        if !self.eat_keyword("by") {
+            return Err(self.unexpected("`by`"));
+        }
+
+        let mut sorts = vec![];
+        loop {
+            let (name, quoted, _) = self.parse_name()?;
+            let direction = if self.eat_keyword("desc") {
+                SortDirection::Descending
+            } else {
+                self.eat_keyword("asc");
+                SortDirection::Ascending
+            };
+
+            sorts.push(match timestamp(&name) {
+                Some(timestamp) if !quoted => Sort::Timestamp {
+                    timestamp,
+                    direction,
+                },
+                _ => Sort::Property {
+                    property: name,
+                    direction,
+                },
+            });
+
+            if *self.peek() != Token::Comma {
+                break;
+            }
+            self.next();
+        }
+        Ok(sorts)
+    }
+}
+
+fn timestamp(name: &str) -> Option<Timestamp> {
+    match name {
+        "created_time" => Some(Timestamp::CreatedTime),
+        "last_edited_time" => Some(Timestamp::LastEditedTime),
+        _ => None,
+    }
+}
+
+fn timestamp_name(timestamp: &Timestamp) -> &'static str {
+    match timestamp {
+        Timestamp::CreatedTime => "created_time",
+        Timestamp::LastEditedTime => "last_edited_time",
+    }
+}
+
+fn is_timestamp(name: &str) -> bool {
+    timestamp(name).is_some()
+}
+
+fn is_reserved(name: &str) -> bool {
+    KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(name))
+}
+
+fn timestamp_filter(name: &str, op: Op, value: Option<Value>) -> Result<FilterType, String> {
+    let condition = date_condition(op, value)?;
+    Ok(match timestamp(name) {
+        Some(Timestamp::CreatedTime) => FilterType::Timestamp {
+            timestamp: Timestamp::CreatedTime,
+            condition: TimestampCondition::CreatedTime(condition),
+        },
+        _ => FilterType::Timestamp {
+            timestamp: Timestamp::LastEditedTime,
+            condition: TimestampCondition::LastEditedTime(condition),
+        },
+    })
+}
+
+fn format_group(filters: &[FilterType], operator: &str) -> String {
+    filters
+        .iter()
+        .map(|filter| format_filter_type(filter, true))
+        .collect::<Vec<_>>()
+        .join(&format!(" {} ", operator))
+}
+
+fn format_filter_type(filter: &FilterType, nested: bool) -> String {
+    match filter {
+        FilterType::Property {
+            property,
+            condition,
+        } => {
+            let (kind, op, value) = describe(condition);
+            format_condition(&format!("{}:{}", format_name(property), kind), op, value)
+        }
+        FilterType::Timestamp { condition, .. } => {
+            let (name, condition) = match condition {
+                TimestampCondition::CreatedTime(c) => ("created_time", c),
+                TimestampCondition::LastEditedTime(c) => ("last_edited_time", c),
+            };
+            let (op, value) = describe_date(condition);
+            format_condition(name, op, value)
+        }
+        FilterType::And { and } if nested => format!("({})", format_group(and, "and")),
+        FilterType::And { and } => format_group(and, "and"),
+        FilterType::Or { or } if nested => format!("({})", format_group(or, "or")),
+        FilterType::Or { or } => format_group(or, "or"),
+    }
+}
+
+fn format_condition(subject: &str, op: Op, value: Option<Value>) -> String {
+    match value {
+        Some(value) => format!("{} {} {}", subject, op, value),
+        None => format!("{} {}", subject, op),
+    }
+}
+
+fn format_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let identifier = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+    if identifier && !is_reserved(name) && Op::parse(&Token::Ident(name.to_string())).is_none() {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn format_direction(direction: &SortDirection) -> &'static str {
+    match direction {
+        SortDirection::Ascending => "asc",
+        SortDirection::Descending => "desc",
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn describe(condition: &PropertyCondition) -> (Kind, Op, Option<Value>) {
+    let (kind, (op, value)) = match condition {
+        PropertyCondition::Title(c) => (Kind::Title, describe_text(c)),
+        PropertyCondition::RichText(c) => (Kind::RichText, describe_text(c)),
+        PropertyCondition::Url(c) => (Kind::Url, describe_text(c)),
+        PropertyCondition::Email(c) => (Kind::Email, describe_text(c)),
+        PropertyCondition::PhoneNumber(c) => (Kind::PhoneNumber, describe_text(c)),
+        PropertyCondition::Number(c) => (Kind::Number, describe_number(c)),
+        PropertyCondition::Checkbox(c) => (Kind::Checkbox, describe_checkbox(c)),
+        PropertyCondition::Select(c) => (
+            Kind::Select,
+            match c {
+                SelectCondition::Equals(v) => (Op::Equals, Some(Value::Str(v.clone()))),
+                SelectCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Str(v.clone()))),
+                SelectCondition::IsEmpty => (Op::IsEmpty, None),
+                SelectCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::Status(c) => (
+            Kind::Status,
+            match c {
+                StatusCondition::Equals(v) => (Op::Equals, Some(Value::Str(v.clone()))),
+                StatusCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Str(v.clone()))),
+                StatusCondition::IsEmpty => (Op::IsEmpty, None),
+                StatusCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::MultiSelect(c) => (
+            Kind::MultiSelect,
+            match c {
+                MultiSelectCondition::Contains(v) => (Op::Contains, Some(Value::Str(v.clone()))),
+                MultiSelectCondition::DoesNotContain(v) => {
+                    (Op::DoesNotContain, Some(Value::Str(v.clone())))
+                }
+                MultiSelectCondition::IsEmpty => (Op::IsEmpty, None),
+                MultiSelectCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::People(c) => (
+            Kind::People,
+            match c {
+                PeopleCondition::Contains(v) => (Op::Contains, Some(Value::Str(v.clone()))),
+                PeopleCondition::DoesNotContain(v) => {
+                    (Op::DoesNotContain, Some(Value::Str(v.clone())))
+                }
+                PeopleCondition::IsEmpty => (Op::IsEmpty, None),
+                PeopleCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::Relation(c) => (
+            Kind::Relation,
+            match c {
+                RelationCondition::Contains(v) => (Op::Contains, Some(Value::Str(v.clone()))),
+                RelationCondition::DoesNotContain(v) => {
+                    (Op::DoesNotContain, Some(Value::Str(v.clone())))
+                }
+                RelationCondition::IsEmpty => (Op::IsEmpty, None),
+                RelationCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::Date(c) => (Kind::Date, describe_date(c)),
+        PropertyCondition::Files(c) => (
+            Kind::Files,
+            match c {
+                FilesCondition::IsEmpty => (Op::IsEmpty, None),
+                FilesCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+            },
+        ),
+        PropertyCondition::ID(c) => (
+            Kind::UniqueId,
+            match c {
+                IDCondition::Equals(v) => (Op::Equals, Some(Value::Number(v.clone()))),
+                IDCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Number(v.clone()))),
+                IDCondition::GreaterThan(v) => (Op::GreaterThan, Some(Value::Number(v.clone()))),
+                IDCondition::GreaterThanOrEqualTo(v) => {
+                    (Op::GreaterThanOrEqualTo, Some(Value::Number(v.clone())))
+                }
+                IDCondition::LessThan(v) => (Op::LessThan, Some(Value::Number(v.clone()))),
+                IDCondition::LessThanOrEqualTo(v) => {
+                    (Op::LessThanOrEqualTo, Some(Value::Number(v.clone())))
+                }
+            },
+        ),
+        PropertyCondition::Timestamp(TimestampCondition::CreatedTime(c)) => {
+            (Kind::CreatedTime, describe_date(c))
+        }
+        PropertyCondition::Timestamp(TimestampCondition::LastEditedTime(c)) => {
+            (Kind::LastEditedTime, describe_date(c))
+        }
+        PropertyCondition::Formula(c) => match c {
+            FormulaCondition::Checkbox(c) => {
+                (Kind::Formula(FormulaKind::Checkbox), describe_checkbox(c))
+            }
+            FormulaCondition::Date(c) => (Kind::Formula(FormulaKind::Date), describe_date(c)),
+            FormulaCondition::Number(c) => (Kind::Formula(FormulaKind::Number), describe_number(c)),
+            FormulaCondition::String(c) => (Kind::Formula(FormulaKind::String), describe_text(c)),
+        },
+        PropertyCondition::Rollup(c) => match c.as_ref() {
+            RollupCondition::Any(c) => {
+                let (kind, op, value) = describe(c);
+                (Kind::RollupAny(Box::new(kind)), (op, value))
+            }
+            RollupCondition::Every(c) => {
+                let (kind, op, value) = describe(c);
+                (Kind::RollupEvery(Box::new(kind)), (op, value))
+            }
+            RollupCondition::None(c) => {
+                let (kind, op, value) = describe(c);
+                (Kind::RollupNone(Box::new(kind)), (op, value))
+            }
+            RollupCondition::Date(c) => (Kind::RollupDate, describe_date(c)),
+            RollupCondition::Number(c) => (Kind::RollupNumber, describe_number(c)),
+        },
+    };
+    (kind, op, value)
+}
+
+fn describe_text(condition: &RichTextCondition) -> (Op, Option<Value>) {
+    match condition {
+        RichTextCondition::Equals(v) => (Op::Equals, Some(Value::Str(v.clone()))),
+        RichTextCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Str(v.clone()))),
+        RichTextCondition::Contains(v) => (Op::Contains, Some(Value::Str(v.clone()))),
+        RichTextCondition::DoesNotContain(v) => (Op::DoesNotContain, Some(Value::Str(v.clone()))),
+        RichTextCondition::StartsWith(v) => (Op::StartsWith, Some(Value::Str(v.clone()))),
+        RichTextCondition::EndsWith(v) => (Op::EndsWith, Some(Value::Str(v.clone()))),
+        RichTextCondition::IsEmpty => (Op::IsEmpty, None),
+        RichTextCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+    }
+}
+
+fn describe_number(condition: &NumberCondition) -> (Op, Option<Value>) {
+    match condition {
+        NumberCondition::Equals(v) => (Op::Equals, Some(Value::Number(v.clone()))),
+        NumberCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Number(v.clone()))),
+        NumberCondition::GreaterThan(v) => (Op::GreaterThan, Some(Value::Number(v.clone()))),
+        NumberCondition::GreaterThanOrEqualTo(v) => {
+            (Op::GreaterThanOrEqualTo, Some(Value::Number(v.clone())))
+        }
+        NumberCondition::LessThan(v) => (Op::LessThan, Some(Value::Number(v.clone()))),
+        NumberCondition::LessThanOrEqualTo(v) => {
+            (Op::LessThanOrEqualTo, Some(Value::Number(v.clone())))
+        }
+        NumberCondition::IsEmpty => (Op::IsEmpty, None),
+        NumberCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+    }
+}
+
+fn describe_checkbox(condition: &CheckBoxCondition) -> (Op, Option<Value>) {
+    match condition {
+        CheckBoxCondition::Equals(v) => (Op::Equals, Some(Value::Bool(*v))),
+        CheckBoxCondition::DoesNotEqual(v) => (Op::DoesNotEqual, Some(Value::Bool(*v))),
+    }
+}
+
+fn describe_date(condition: &DateCondition) -> (Op, Option<Value>) {
+    match condition {
+        DateCondition::Equals(v) => (Op::Equals, Some(Value::Date(*v))),
+        DateCondition::Before(v) => (Op::Before, Some(Value::Date(*v))),
+        DateCondition::After(v) => (Op::After, Some(Value::Date(*v))),
+        DateCondition::OnOrBefore(v) => (Op::OnOrBefore, Some(Value::Date(*v))),
+        DateCondition::OnOrAfter(v) => (Op::OnOrAfter, Some(Value::Date(*v))),
+        DateCondition::IsEmpty => (Op::IsEmpty, None),
+        DateCondition::IsNotEmpty => (Op::IsNotEmpty, None),
+        DateCondition::PastWeek => (Op::PastWeek, None),
+        DateCondition::PastMonth => (Op::PastMonth, None),
+        DateCondition::PastYear => (Op::PastYear, None),
+        DateCondition::ThisWeek => (Op::ThisWeek, None),
+        DateCondition::NextWeek => (Op::NextWeek, None),
+        DateCondition::NextMonth => (Op::NextMonth, None),
+        DateCondition::NextYear => (Op::NextYear, None),
+    }
+}
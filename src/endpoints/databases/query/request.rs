@@ -1,12 +1,16 @@
 use derive_builder::Builder;
-use serde::Serialize;
+use serde::{ser::Error, Serialize};
 use serde_with::skip_serializing_none;
 
+/// Notion accepts a compound filter nested in another, but no deeper.
+pub const MAX_FILTER_DEPTH: usize = 2;
+
 #[skip_serializing_none]
 #[derive(Serialize, Debug, Eq, PartialEq, Clone, Default, Builder)]
 #[builder(setter(strip_option))]
 #[builder(default)]
 pub struct QueryDatabaseRequest {
+    #[serde(serialize_with = "serialize_filter")]
     pub filter: Option<Filter>,
     pub sorts: Option<Vec<Sort>>,
     pub start_cursor: Option<String>,
@@ -41,6 +45,92 @@ pub enum FilterType {
         #[serde(flatten)]
         condition: TimestampCondition,
     },
+    And {
+        and: Vec<FilterType>,
+    },
+    Or {
+        or: Vec<FilterType>,
+    },
+}
+
+impl Filter {
+    pub fn and(filters: impl IntoIterator<Item = FilterType>) -> Self {
+        Filter::And {
+            and: filters.into_iter().collect(),
+        }
+    }
+
+    pub fn or(filters: impl IntoIterator<Item = FilterType>) -> Self {
+        Filter::Or {
+            or: filters.into_iter().collect(),
+        }
+    }
+
+    /// The number of nested `and`/`or` levels, 0 for a single condition.
+    pub fn depth(&self) -> usize {
+        match self {
+            Filter::Value { filter_type } => filter_type.depth(),
+            Filter::And { and: filters } | Filter::Or { or: filters } => {
+                1 + filters
+                    .iter()
+                    .map(FilterType::depth)
+                    .max()
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl FilterType {
+    pub fn and(filters: impl IntoIterator<Item = FilterType>) -> Self {
+        FilterType::And {
+            and: filters.into_iter().collect(),
+        }
+    }
+
+    pub fn or(filters: impl IntoIterator<Item = FilterType>) -> Self {
+        FilterType::Or {
+            or: filters.into_iter().collect(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            FilterType::Property { .. } | FilterType::Timestamp { .. } => 0,
+            FilterType::And { and: filters } | FilterType::Or { or: filters } => {
+                1 + filters
+                    .iter()
+                    .map(FilterType::depth)
+                    .max()
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl From<FilterType> for Filter {
+    fn from(filter_type: FilterType) -> Self {
+        match filter_type {
+            FilterType::And { and } => Filter::And { and },
+            FilterType::Or { or } => Filter::Or { or },
+            filter_type => Filter::Value { filter_type },
+        }
+    }
+}
+
+fn serialize_filter<S>(filter: &Option<Filter>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(depth) = filter.as_ref().map(Filter::depth) {
+        if depth > MAX_FILTER_DEPTH {
+            return Err(S::Error::custom(format!(
+                "filter nests {} compound levels, Notion allows at most {}",
+                depth, MAX_FILTER_DEPTH
+            )));
+        }
+    }
+    filter.serialize(serializer)
 }
 
 #[derive(Serialize, Debug, Eq, PartialEq, Clone)]
@@ -104,7 +194,12 @@ pub enum PropertyCondition {
     Select(SelectCondition),
     Status(StatusCondition),
     Timestamp(TimestampCondition),
+    #[serde(rename = "unique_id")]
     ID(IDCondition),
+    Title(RichTextCondition),
+    Url(RichTextCondition),
+    Email(RichTextCondition),
+    PhoneNumber(RichTextCondition),
 }
 
 #[derive(Serialize, Debug, Eq, PartialEq, Clone)]
@@ -1,6 +1,6 @@
 use crate::{
     endpoints::NOTION_URI,
-    objects::{database::Database, Response},
+    objects::{database::Database, id::DatabaseId, Response},
     NotionClientError,
 };
 
@@ -9,8 +9,9 @@ use super::DatabasesEndpoint;
 impl DatabasesEndpoint {
     pub async fn retrieve_a_database(
         &self,
-        database_id: &str,
+        database_id: impl Into<DatabaseId>,
     ) -> Result<Database, NotionClientError> {
+        let database_id = database_id.into();
         let result = self
             .client
             .get(format!(
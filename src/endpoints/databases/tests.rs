@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use serde_json::Number;
 
@@ -6,25 +6,31 @@ use crate::{
     endpoints::databases::{
         create::request::CreateADatabaseRequest,
         query::{
+            builder::{created_time, prop, sort_by, sort_by_timestamp},
+            expression::{format_filter, format_query, parse_filter, parse_query},
             request::{
-                CheckBoxCondition, Filter, NumberCondition, PropertyCondition,
-                QueryDatabaseRequest, Sort, SortDirection,
+                CheckBoxCondition, DateCondition, Filter, MultiSelectCondition, NumberCondition,
+                PropertyCondition, QueryDatabaseRequest, Sort, SortDirection, Timestamp,
+                TimestampCondition,
             },
             response::QueryDatabaseResponse,
         },
         update::request::UpdateADatabaseRequest,
     },
     objects::{
+        color::Color,
         database::{
-            Color, Database, DatabaseProperty, Icon, NumberFormat, NumberPropertyValue,
-            OptionValue, RelationPropertyValue, RollupFunction, RollupPropertyValue,
-            SelectPropertyValue,
+            Database, DatabaseProperty, DualPropertyValue, EmptyConfig, NumberFormat,
+            NumberPropertyValue, OptionValue, RelationPropertyValue, RelationType, RollupFunction,
+            RollupPropertyValue, SelectPropertyValue, UniqueIDPropertyValue,
         },
         emoji::Emoji,
         file::ExternalFile,
+        icon::Icon,
         parent::Parent,
         rich_text::{RichText, Text},
     },
+    NotionClientError,
 };
 
 use super::query::request::FilterType;
@@ -37,7 +43,7 @@ fn test_create_request() {
         DatabaseProperty::Title {
             id: None,
             name: None,
-            title: HashMap::new(),
+            title: EmptyConfig {},
         },
     );
     properties.insert(
@@ -45,7 +51,7 @@ fn test_create_request() {
         DatabaseProperty::RichText {
             id: None,
             name: None,
-            rich_text: HashMap::new(),
+            rich_text: EmptyConfig {},
         },
     );
     properties.insert(
@@ -53,7 +59,7 @@ fn test_create_request() {
         DatabaseProperty::Checkbox {
             id: None,
             name: None,
-            checkbox: HashMap::new(),
+            checkbox: EmptyConfig {},
         },
     );
     properties.insert(
@@ -97,7 +103,7 @@ fn test_create_request() {
         DatabaseProperty::Date {
             id: None,
             name: None,
-            date: HashMap::new(),
+            date: EmptyConfig {},
         },
     );
     properties.insert(
@@ -106,7 +112,7 @@ fn test_create_request() {
             id: None,
             name: None,
             relation: RelationPropertyValue {
-                database_id: Some("668d797c-76fa-4934-9b05-ad288df2d136".to_string()),
+                database_id: Some("668d797c-76fa-4934-9b05-ad288df2d136".into()),
                 ..Default::default()
             },
         },
@@ -160,7 +166,7 @@ fn test_create_request() {
         DatabaseProperty::People {
             id: None,
             name: None,
-            people: HashMap::new(),
+            people: EmptyConfig {},
         },
     );
     properties.insert(
@@ -168,13 +174,13 @@ fn test_create_request() {
         DatabaseProperty::Files {
             id: None,
             name: None,
-            files: HashMap::new(),
+            files: EmptyConfig {},
         },
     );
 
     let request = CreateADatabaseRequest {
         parent: Parent::PageId {
-            page_id: "98ad959b-2b6a-4774-80ee-00246fb0ea9b".to_string(),
+            page_id: "98ad959b-2b6a-4774-80ee-00246fb0ea9b".into(),
         },
         icon: Some(Icon::Emoji(Emoji::Emoji {
             emoji: "📝".to_string(),
@@ -230,6 +236,250 @@ fn test_query_request() {
     assert_eq!(result, actual.to_string())
 }
 
+#[test]
+fn test_query_request_nested() {
+    let request = QueryDatabaseRequest {
+        filter: Some(Filter::or([
+            FilterType::and([
+                FilterType::Property {
+                    property: "In stock".to_string(),
+                    condition: PropertyCondition::Checkbox(CheckBoxCondition::Equals(true)),
+                },
+                FilterType::Property {
+                    property: "Cost of next trip".to_string(),
+                    condition: PropertyCondition::Number(NumberCondition::GreaterThanOrEqualTo(
+                        Number::from(2),
+                    )),
+                },
+            ]),
+            FilterType::and([
+                FilterType::Property {
+                    property: "Store availability".to_string(),
+                    condition: PropertyCondition::MultiSelect(MultiSelectCondition::Contains(
+                        "Gus's Community Market".to_string(),
+                    )),
+                },
+                FilterType::Timestamp {
+                    timestamp: Timestamp::CreatedTime,
+                    condition: TimestampCondition::CreatedTime(DateCondition::PastWeek),
+                },
+            ]),
+        ])),
+        ..Default::default()
+    };
+
+    let result = serde_json::to_string_pretty(&request).unwrap();
+    let actual = include_str!("tests/query_nested_request.json");
+    assert_eq!(result, actual.to_string())
+}
+
+#[test]
+fn test_query_request_too_deep() {
+    let in_stock = FilterType::Property {
+        property: "In stock".to_string(),
+        condition: PropertyCondition::Checkbox(CheckBoxCondition::Equals(true)),
+    };
+    let filter = Filter::or([FilterType::and([FilterType::or([in_stock.clone()])])]);
+    assert_eq!(filter.depth(), 3);
+
+    let request = QueryDatabaseRequest {
+        filter: Some(filter),
+        ..Default::default()
+    };
+    assert!(serde_json::to_string(&request).is_err());
+
+    let request = QueryDatabaseRequest {
+        filter: Some(FilterType::and([in_stock]).into()),
+        ..Default::default()
+    };
+    assert!(serde_json::to_string(&request).is_ok());
+}
+
+#[test]
+fn test_query_request_from_builder() {
+    let request = QueryDatabaseRequest {
+        filter: Some(
+            prop("In stock")
+                .checkbox()
+                .equals(true)
+                .or(prop("Cost of next trip")
+                    .number()
+                    .greater_than_or_equal_to(2))
+                .into(),
+        ),
+        sorts: Some(vec![sort_by("Last ordered").asc()]),
+        ..Default::default()
+    };
+
+    let result = serde_json::to_string_pretty(&request).unwrap();
+    let actual = include_str!("tests/query_request.json");
+    assert_eq!(result, actual.to_string())
+}
+
+#[test]
+fn test_filter_builder_nesting() {
+    let filter: Filter = prop("In stock")
+        .checkbox()
+        .equals(true)
+        .and(
+            prop("Cost of next trip")
+                .number()
+                .greater_than_or_equal_to(2),
+        )
+        .or(prop("Store availability")
+            .multi_select()
+            .contains("Gus's Community Market")
+            .and(created_time().past_week()))
+        .into();
+
+    let request = QueryDatabaseRequest {
+        filter: Some(filter),
+        ..Default::default()
+    };
+    let result = serde_json::to_string_pretty(&request).unwrap();
+    let actual = include_str!("tests/query_nested_request.json");
+    assert_eq!(result, actual.to_string())
+}
+
+#[test]
+fn test_filter_builder_negate() {
+    let negated = prop("Status")
+        .status()
+        .equals("Done")
+        .and(prop("Cost of next trip").number().greater_than(2))
+        .negate()
+        .unwrap();
+
+    assert_eq!(
+        negated,
+        prop("Status")
+            .status()
+            .does_not_equal("Done")
+            .or(prop("Cost of next trip").number().less_than_or_equal_to(2))
+    );
+    assert_eq!(
+        serde_json::to_value(Filter::from(prop("Ticket").unique_id().equals(3))).unwrap(),
+        serde_json::json!({ "property": "Ticket", "unique_id": { "equals": 3 } })
+    );
+    assert!(prop("Name")
+        .title()
+        .starts_with("Lacinato")
+        .negate()
+        .is_none());
+    assert!(created_time().past_week().negate().is_none());
+}
+
+#[test]
+fn test_parse_query_with_schema() {
+    let database =
+        serde_json::from_str::<Database>(include_str!("tests/retrieve_200.json")).unwrap();
+
+    let request = parse_query(
+        r#""In stock" = true and ("Food group" = "Vegetable" or "Store availability" contains "Gus's Community Market") and created_time past_week order by Price desc, created_time"#,
+        Some(&database),
+    )
+    .unwrap();
+
+    let expected = prop("In stock")
+        .checkbox()
+        .equals(true)
+        .and(
+            prop("Food group")
+                .select()
+                .equals("Vegetable")
+                .or(prop("Store availability")
+                    .multi_select()
+                    .contains("Gus's Community Market")),
+        )
+        .and(created_time().past_week());
+    assert_eq!(request.filter, Some(expected.into()));
+    assert_eq!(
+        request.sorts,
+        Some(vec![
+            sort_by("Price").desc(),
+            sort_by_timestamp(Timestamp::CreatedTime).asc()
+        ])
+    );
+
+    let error = parse_filter(r#""Food group" > 2"#, Some(&database)).unwrap_err();
+    assert!(matches!(
+        error,
+        NotionClientError::InvalidFilterExpression { position: 13, .. }
+    ));
+    let error = parse_filter("Colour = \"Red\"", Some(&database)).unwrap_err();
+    assert!(matches!(
+        error,
+        NotionClientError::InvalidFilterExpression { position: 0, .. }
+    ));
+}
+
+#[test]
+fn test_parse_filter_without_schema() {
+    let filter = parse_filter(
+        r#"Status:status = "Done" and (Priority > 2 or Tags:multi_select contains "urgent") and created_time past_week"#,
+        None,
+    )
+    .unwrap();
+
+    let expected = prop("Status")
+        .status()
+        .equals("Done")
+        .and(
+            prop("Priority")
+                .number()
+                .greater_than(2)
+                .or(prop("Tags").multi_select().contains("urgent")),
+        )
+        .and(created_time().past_week());
+    assert_eq!(filter, expected.into());
+
+    let negated = parse_filter("not (Priority > 2 or Done = true)", None).unwrap();
+    assert_eq!(
+        negated,
+        prop("Priority")
+            .number()
+            .less_than_or_equal_to(2)
+            .and(prop("Done").checkbox().does_not_equal(true))
+            .into()
+    );
+
+    for (input, position) in [
+        ("Priority >", 10),
+        ("Priority > 2 and", 16),
+        ("(Priority > 2", 13),
+        ("Name = \"Lacinato", 7),
+        ("Name:colour = \"red\"", 5),
+        ("not Name starts_with \"A\"", 0),
+    ] {
+        match parse_filter(input, None) {
+            Err(NotionClientError::InvalidFilterExpression { position: p, .. }) => {
+                assert_eq!(p, position, "{}", input)
+            }
+            result => panic!("{}: {:?}", input, result),
+        }
+    }
+}
+
+#[test]
+fn test_format_filter() {
+    let input = r#"("Cost of next trip":number >= 2 or "In stock":checkbox = true) and Meals:rollup.any.rich_text contains "kale" and Total:formula.number < 10.5 and "Last ordered":date on_or_after "2022-02-01T00:00:00Z" and last_edited_time past_month"#;
+    let filter = parse_filter(input, None).unwrap();
+
+    assert_eq!(format_filter(&filter), input);
+    assert_eq!(parse_filter(&format_filter(&filter), None).unwrap(), filter);
+
+    let request = QueryDatabaseRequest {
+        filter: Some(prop("Name").title().starts_with("Lacinato").into()),
+        sorts: Some(vec![sort_by("Last ordered").desc()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        format_query(&request),
+        r#"Name:title starts_with "Lacinato" order by "Last ordered" desc"#
+    );
+    assert_eq!(parse_query(&format_query(&request), None).unwrap(), request);
+}
+
 #[test]
 fn test_update_request() {
     let mut properties = BTreeMap::new();
@@ -271,7 +521,7 @@ fn test_update_request() {
         Some(DatabaseProperty::Files {
             id: None,
             name: None,
-            files: HashMap::new(),
+            files: EmptyConfig {},
         }),
     );
 
@@ -321,6 +571,58 @@ fn test_retrieve_200() {
     assert!(result.is_ok())
 }
 
+#[test]
+fn test_retrieve_all_properties_round_trip() {
+    let json = include_str!("tests/retrieve_all_properties_200.json");
+    let expected = serde_json::from_str::<serde_json::Value>(json).unwrap();
+    let database = serde_json::from_str::<Database>(json).unwrap();
+
+    assert_eq!(
+        database.properties["Project"],
+        DatabaseProperty::Relation {
+            id: Some("%7DWA~".to_string()),
+            name: Some("Project".to_string()),
+            relation: RelationPropertyValue {
+                database_id: Some("668d797c76fa49349b05ad288df2d136".into()),
+                relation_type: Some(RelationType::DualProperty {
+                    dual_property: DualPropertyValue {
+                        synced_property_id: Some("mxp%5E".to_string()),
+                        synced_property_name: Some("Tasks".to_string()),
+                    },
+                }),
+                ..Default::default()
+            },
+        }
+    );
+    assert_eq!(
+        database.properties["ID"],
+        DatabaseProperty::UniqueID {
+            id: Some("%3EfJK".to_string()),
+            name: Some("ID".to_string()),
+            unique_id: UniqueIDPropertyValue {
+                prefix: Some("TASK".to_string()),
+            },
+        }
+    );
+    assert_eq!(
+        serde_json::to_value(&database.properties).unwrap(),
+        expected["properties"]
+    );
+
+    let request = UpdateADatabaseRequest {
+        properties: database
+            .properties
+            .into_iter()
+            .map(|(name, property)| (name, Some(property)))
+            .collect(),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["properties"],
+        expected["properties"]
+    );
+}
+
 #[test]
 fn test_update_200() {
     let result = serde_json::from_str::<Database>(include_str!("tests/update_200.json"));
@@ -2,7 +2,7 @@ pub mod request;
 
 use crate::{
     endpoints::NOTION_URI,
-    objects::{database::Database, Response},
+    objects::{database::Database, id::DatabaseId, Response},
     NotionClientError,
 };
 
@@ -13,9 +13,10 @@ use super::DatabasesEndpoint;
 impl DatabasesEndpoint {
     pub async fn update_a_database(
         &self,
-        database_id: &str,
+        database_id: impl Into<DatabaseId>,
         request: UpdateADatabaseRequest,
     ) -> Result<Database, NotionClientError> {
+        let database_id = database_id.into();
         let json = serde_json::to_string(&request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
@@ -1,6 +1,7 @@
 use reqwest::Client;
 
 pub mod create;
+pub mod duplicate;
 pub mod retrieve;
 #[cfg(test)]
 mod tests;
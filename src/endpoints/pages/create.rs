@@ -1,8 +1,14 @@
 pub mod request;
 
 use crate::{
-    endpoints::NOTION_URI,
-    objects::{page::Page, Response},
+    endpoints::{
+        blocks::{
+            append::{chunk, request::AppendBlockChildrenRequest},
+            BlocksEndpoint,
+        },
+        NOTION_URI,
+    },
+    objects::{id::BlockId, page::Page, rich_text::normalize, Response},
     NotionClientError,
 };
 
@@ -11,11 +17,56 @@ use self::request::CreateAPageRequest;
 use super::PagesEndpoint;
 
 impl PagesEndpoint {
+    /// Creates a page. Rich text is normalized first, see [`normalize`], and
+    /// children beyond Notion's request limits are appended afterwards, see
+    /// [`BlocksEndpoint::append_block_children`]. Rich text that is still over
+    /// the limits fails with [`NotionClientError::UnfixableRichText`] before
+    /// anything is sent.
+    ///
+    /// If appending those children fails the page already exists, so the error
+    /// is [`NotionClientError::PageCreatedWithoutChildren`], which holds the
+    /// created page; retrying the whole call would create a second page.
     pub async fn create_a_page(
         &self,
-        request: CreateAPageRequest,
+        mut request: CreateAPageRequest,
     ) -> Result<Page, NotionClientError> {
-        let json = serde_json::to_string(&request)
+        let mut unfixable = normalize::properties(&mut request.properties);
+        if let Some(children) = &mut request.children {
+            unfixable.extend(normalize::blocks(children));
+        }
+        if !unfixable.is_empty() {
+            return Err(NotionClientError::UnfixableRichText { unfixable });
+        }
+        let children = match request.children.take() {
+            Some(children) if !chunk::fits(&children) => children,
+            children => {
+                request.children = children;
+                return self.send_create(&request).await;
+            }
+        };
+
+        let page = self.send_create(&request).await?;
+        let blocks = BlocksEndpoint {
+            client: self.client.clone(),
+        };
+        let request = AppendBlockChildrenRequest {
+            children,
+            after: None,
+        };
+        match blocks
+            .append_block_children(BlockId::from(&page.id), request)
+            .await
+        {
+            Ok(_) => Ok(page),
+            Err(e) => Err(NotionClientError::PageCreatedWithoutChildren {
+                page: Box::new(page),
+                source: Box::new(e),
+            }),
+        }
+    }
+
+    async fn send_create(&self, request: &CreateAPageRequest) -> Result<Page, NotionClientError> {
+        let json = serde_json::to_string(request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
         let result = self
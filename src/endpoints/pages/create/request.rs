@@ -4,12 +4,7 @@ use derive_builder::Builder;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
-use crate::objects::{
-    block::Block,
-    file::File,
-    page::{Icon, PageProperty},
-    parent::Parent,
-};
+use crate::objects::{block::Block, file::File, icon::Icon, page::PageProperty, parent::Parent};
 
 #[skip_serializing_none]
 #[derive(Serialize, Debug, Eq, PartialEq, Clone, Default, Builder)]
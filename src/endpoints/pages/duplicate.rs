@@ -0,0 +1,791 @@
+pub mod request;
+pub mod response;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use futures_util::future::BoxFuture;
+use serde_json::Value;
+
+use crate::{
+    endpoints::{
+        blocks::{
+            append::request::{AppendBlockChildrenRequest, MAX_CHILDREN},
+            retrieve::request::RetrieveBlockTreeOptions,
+            update::request::UpdateABlockRequest,
+            BlocksEndpoint,
+        },
+        databases::{
+            create::request::CreateADatabaseRequest, query::request::QueryDatabaseRequest,
+            update::request::UpdateADatabaseRequest, DatabasesEndpoint,
+        },
+    },
+    objects::{
+        block::{Block, BlockType},
+        database::{DatabaseProperty, EmptyConfig, RelationPropertyValue, RelationType},
+        file::File,
+        icon::Icon,
+        id::{BlockId, DatabaseId, PageId},
+        page::{self, Page, PageProperty},
+        parent::Parent,
+        rich_text::RichText,
+    },
+    NotionClientError,
+};
+
+use self::{
+    request::DuplicatePageOptions,
+    response::{DuplicatePageResponse, SkipReason, Skipped},
+};
+
+use super::{
+    create::request::CreateAPageRequest, update::request::UpdatePagePropertiesRequest,
+    PagesEndpoint,
+};
+
+impl PagesEndpoint {
+    /// Copies a page with its icon, cover, properties and content under `parent`.
+    ///
+    /// Child pages and inline databases are copied as well unless disabled in
+    /// `options`; those nested inside other blocks end up at the bottom of the
+    /// copied page, as the API only creates pages at the end of their parent.
+    /// Links and mentions of copied pages are pointed to their copies, and
+    /// anything that cannot be copied is listed in the response. Relations of
+    /// a copied database to itself point to the copy, and relations to other
+    /// databases are copied one-way so the source workspace is left unchanged.
+    pub async fn duplicate_page(
+        &self,
+        source: impl Into<PageId>,
+        parent: Parent,
+        options: DuplicatePageOptions,
+    ) -> Result<DuplicatePageResponse, NotionClientError> {
+        let source = source.into();
+        let mut duplicator = Duplicator::new(self, options);
+
+        let mut page = self.retrieve_a_page(&source, None).await?;
+        if let Some(title) = &duplicator.options.title {
+            for property in page.properties.values_mut() {
+                if let PageProperty::Title {
+                    title: rich_text, ..
+                } = property
+                {
+                    *rich_text = vec![RichText::text(title.clone())];
+                }
+            }
+        }
+        let blocks = duplicator.retrieve_content(&source).await?;
+        let page = duplicator.copy_page(page, blocks, parent, None).await?;
+        duplicator.update_deferred().await;
+
+        Ok(DuplicatePageResponse {
+            page,
+            copies: duplicator.page_copies,
+            skipped: duplicator.skipped,
+        })
+    }
+}
+
+struct Duplicator {
+    pages: PagesEndpoint,
+    blocks: BlocksEndpoint,
+    databases: DatabasesEndpoint,
+    options: DuplicatePageOptions,
+    /// Copies of blocks, pages and databases by source id.
+    copies: HashMap<BlockId, BlockId>,
+    page_copies: HashMap<PageId, PageId>,
+    /// Pages and databases found in the source that will be copied.
+    scheduled: HashSet<BlockId>,
+    /// Copied blocks linking to pages that were not copied yet, with their source.
+    deferred: Vec<(BlockId, Block)>,
+    /// Relations of copied database rows to rows of the same database, by the
+    /// copied row and property name, with the source rows they point to.
+    relations: Vec<(PageId, String, Vec<PageId>)>,
+    skipped: Vec<Skipped>,
+}
+
+impl Duplicator {
+    fn new(pages: &PagesEndpoint, options: DuplicatePageOptions) -> Self {
+        Duplicator {
+            pages: pages.clone(),
+            blocks: BlocksEndpoint {
+                client: pages.client.clone(),
+            },
+            databases: DatabasesEndpoint {
+                client: pages.client.clone(),
+            },
+            options,
+            copies: HashMap::new(),
+            page_copies: HashMap::new(),
+            scheduled: HashSet::new(),
+            deferred: vec![],
+            relations: vec![],
+            skipped: vec![],
+        }
+    }
+
+    async fn retrieve_content(
+        &mut self,
+        page_id: &PageId,
+    ) -> Result<Vec<Block>, NotionClientError> {
+        let options = RetrieveBlockTreeOptions {
+            child_pages: self.options.child_pages,
+            resolve_synced_blocks: false,
+            ..Default::default()
+        };
+        let blocks = self.blocks.retrieve_block_tree(page_id, options).await?;
+        self.schedule(&blocks);
+        Ok(blocks)
+    }
+
+    fn schedule(&mut self, blocks: &[Block]) {
+        for block in blocks {
+            match &block.block_type {
+                BlockType::ChildPage { child_page } if self.options.child_pages => {
+                    self.scheduled.extend(block.id.clone());
+                    self.schedule(child_page.children.as_deref().unwrap_or_default());
+                }
+                BlockType::ChildDatabase { .. } if self.options.child_databases => {
+                    self.scheduled.extend(block.id.clone());
+                }
+                block_type => self.schedule(block_type.children().map_or(&[], Vec::as_slice)),
+            }
+        }
+    }
+
+    fn skip(&mut self, source: impl Into<BlockId>, reason: SkipReason) {
+        self.skipped.push(Skipped {
+            source: source.into(),
+            reason,
+        });
+    }
+
+    fn copy_page(
+        &mut self,
+        page: Page,
+        blocks: Vec<Block>,
+        parent: Parent,
+        schema: Option<&HashMap<String, DatabaseProperty>>,
+    ) -> BoxFuture<'_, Result<Page, NotionClientError>> {
+        let copied = page_properties(&page.properties, &parent, schema);
+        for reason in copied.skipped {
+            self.skip(&page.id, reason);
+        }
+        Box::pin(async move {
+            let icon = match page.icon {
+                Some(Icon::File(File::File { .. })) => {
+                    self.skip(&page.id, SkipReason::HostedFile);
+                    None
+                }
+                icon => icon,
+            };
+            let cover = match page.cover {
+                Some(File::File { .. }) => {
+                    self.skip(&page.id, SkipReason::HostedFile);
+                    None
+                }
+                cover => cover,
+            };
+
+            let request = CreateAPageRequest {
+                parent,
+                icon,
+                cover,
+                properties: copied.properties,
+                children: None,
+            };
+            let copy = self.pages.create_a_page(request).await?;
+            self.copies
+                .insert(BlockId::from(&page.id), BlockId::from(&copy.id));
+            self.relations.extend(
+                copied
+                    .relations
+                    .into_iter()
+                    .map(|(name, targets)| (copy.id.clone(), name, targets)),
+            );
+            self.page_copies.insert(page.id, copy.id.clone());
+
+            self.append(&copy.id, BlockId::from(&copy.id), blocks)
+                .await?;
+            Ok(copy)
+        })
+    }
+
+    /// Appends copies of `blocks` to `parent`, one level per request, and copies
+    /// the child pages and databases among them into `page_id`.
+    fn append<'a>(
+        &'a mut self,
+        page_id: &'a PageId,
+        parent: BlockId,
+        blocks: Vec<Block>,
+    ) -> BoxFuture<'a, Result<(), NotionClientError>> {
+        Box::pin(async move {
+            let mut segment = vec![];
+            for block in blocks {
+                let Some(id) = block.id.clone() else {
+                    continue;
+                };
+                match &block.block_type {
+                    BlockType::ChildPage { .. } if !self.options.child_pages => {
+                        self.skip(id, SkipReason::ChildPage)
+                    }
+                    BlockType::ChildDatabase { .. } if !self.options.child_databases => {
+                        self.skip(id, SkipReason::ChildDatabase)
+                    }
+                    BlockType::ChildPage { child_page } => {
+                        self.flush(page_id, &parent, std::mem::take(&mut segment))
+                            .await?;
+                        let source = self.pages.retrieve_a_page(id.to_string(), None).await?;
+                        let children = child_page.children.clone().unwrap_or_default();
+                        self.copy_page(source, children, Parent::page(page_id), None)
+                            .await?;
+                    }
+                    BlockType::ChildDatabase { .. } => {
+                        self.flush(page_id, &parent, std::mem::take(&mut segment))
+                            .await?;
+                        self.copy_database(id.to_string().into(), page_id).await?;
+                    }
+                    _ => match copy_block(&block, &self.copies) {
+                        Ok(copy) => segment.push((block, copy)),
+                        Err(reason) => self.skip(id, reason),
+                    },
+                }
+            }
+            self.flush(page_id, &parent, segment).await
+        })
+    }
+
+    async fn flush(
+        &mut self,
+        page_id: &PageId,
+        parent: &BlockId,
+        mut segment: Vec<(Block, Block)>,
+    ) -> Result<(), NotionClientError> {
+        while !segment.is_empty() {
+            let rest = segment.split_off(segment.len().min(MAX_CHILDREN));
+            let mut nested_pages = vec![];
+            let children = segment
+                .iter()
+                .map(|(source, copy)| {
+                    with_inline_children(
+                        source,
+                        copy,
+                        &self.copies,
+                        &mut nested_pages,
+                        &mut self.skipped,
+                    )
+                })
+                .collect();
+            let request = AppendBlockChildrenRequest {
+                children,
+                after: None,
+            };
+            let response = self.blocks.append_block_children(parent, request).await?;
+
+            for ((source, copy), created) in segment.into_iter().zip(response.results) {
+                let Some(id) = created.id else {
+                    continue;
+                };
+                self.record(&source, &copy, &id);
+                match &source.block_type {
+                    BlockType::Table { .. } => {}
+                    BlockType::SyncedBlock { synced_block }
+                        if synced_block.synced_from.is_some() => {}
+                    BlockType::ColumnList { column_list } => {
+                        let options = RetrieveBlockTreeOptions {
+                            max_depth: Some(2),
+                            ..Default::default()
+                        };
+                        let columns = self.blocks.retrieve_block_tree(&id, options).await?;
+                        let sources = column_list.children.as_deref().unwrap_or_default();
+                        for (source, column) in sources.iter().zip(&columns) {
+                            self.record_inline(page_id, source, column).await?;
+                        }
+                    }
+                    block_type => {
+                        if let Some(children) = block_type.children() {
+                            self.append(page_id, id, children.clone()).await?;
+                        }
+                    }
+                }
+            }
+            self.append(page_id, parent.clone(), nested_pages).await?;
+            segment = rest;
+        }
+        Ok(())
+    }
+
+    /// Records the copies of a column and its children, created along with
+    /// their column list, and appends what lies below them.
+    async fn record_inline(
+        &mut self,
+        page_id: &PageId,
+        source: &Block,
+        created: &Block,
+    ) -> Result<(), NotionClientError> {
+        let Some(id) = created.id.clone() else {
+            return Ok(());
+        };
+        self.copies
+            .extend(source.id.clone().map(|source| (source, id)));
+
+        let sources = source
+            .block_type
+            .children()
+            .into_iter()
+            .flatten()
+            .filter(|child| copy_block(child, &self.copies).is_ok())
+            .collect::<Vec<_>>();
+        let created = created.block_type.children().into_iter().flatten();
+        for (source, created) in sources.into_iter().zip(created) {
+            let Some(id) = created.id.clone() else {
+                continue;
+            };
+            if let Ok(copy) = copy_block(source, &self.copies) {
+                self.record(source, &copy, &id);
+            }
+            match &source.block_type {
+                BlockType::Table { .. } => {}
+                block_type => {
+                    if let Some(children) = block_type.children() {
+                        self.append(page_id, id, children.clone()).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, source: &Block, copy: &Block, id: &BlockId) {
+        if let Some(source_id) = &source.id {
+            self.copies.insert(source_id.clone(), id.clone());
+        }
+        let mut pending = false;
+        if let Ok(mut value) = serde_json::to_value(&copy.block_type) {
+            visit_references(&mut value, &mut |reference| {
+                let reference = BlockId::from(reference.clone());
+                pending |=
+                    self.scheduled.contains(&reference) && !self.copies.contains_key(&reference);
+            });
+        }
+        if pending {
+            self.deferred.push((id.clone(), source.clone()));
+        }
+    }
+
+    fn copy_database<'a>(
+        &'a mut self,
+        source: DatabaseId,
+        page_id: &'a PageId,
+    ) -> BoxFuture<'a, Result<(), NotionClientError>> {
+        Box::pin(async move {
+            let database = self.databases.retrieve_a_database(&source).await?;
+            let copied = database_properties(&source, &database.properties);
+            for reason in copied.skipped {
+                self.skip(&source, reason);
+            }
+            let icon = match database.icon {
+                Some(Icon::File(File::File { .. })) => {
+                    self.skip(&source, SkipReason::HostedFile);
+                    None
+                }
+                icon => icon,
+            };
+            let request = CreateADatabaseRequest {
+                parent: Parent::page(page_id),
+                icon,
+                cover: None,
+                title: Some(database.title.clone()),
+                properties: copied.properties,
+            };
+            let mut copy = self.databases.create_a_database(request).await?;
+            let Some(copy_id) = copy.id.clone() else {
+                return Ok(());
+            };
+            self.copies
+                .insert(BlockId::from(&source), BlockId::from(&copy_id));
+
+            if !copied.relations.is_empty() {
+                let request = UpdateADatabaseRequest {
+                    properties: copied
+                        .relations
+                        .into_keys()
+                        .map(|name| (name, Some(single_relation(copy_id.clone()))))
+                        .collect(),
+                    ..Default::default()
+                };
+                copy = self.databases.update_a_database(&copy_id, request).await?;
+            }
+
+            let mut cursor = None;
+            let mut rows = vec![];
+            loop {
+                let request = QueryDatabaseRequest {
+                    start_cursor: cursor,
+                    page_size: Some(MAX_CHILDREN as u32),
+                    ..Default::default()
+                };
+                let response = self.databases.query_a_database(&source, request).await?;
+                rows.extend(response.results);
+                match response.next_cursor {
+                    Some(next) if response.has_more => cursor = Some(next),
+                    _ => break,
+                }
+            }
+            self.scheduled
+                .extend(rows.iter().map(|row| BlockId::from(&row.id)));
+
+            for row in rows {
+                let blocks = self.retrieve_content(&row.id).await?;
+                self.copy_page(
+                    row,
+                    blocks,
+                    Parent::database(&copy_id),
+                    Some(&copy.properties),
+                )
+                .await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Points blocks that linked to pages copied after them, and relations
+    /// between rows of copied databases, to the copies.
+    async fn update_deferred(&mut self) {
+        for (id, name, targets) in std::mem::take(&mut self.relations) {
+            let relation = targets
+                .iter()
+                .filter_map(|target| self.page_copies.get(target))
+                .map(|copy| page::RelationPropertyValue { id: copy.clone() })
+                .collect();
+            let property = PageProperty::Relation {
+                id: None,
+                relation,
+                has_more: None,
+            };
+            let request = UpdatePagePropertiesRequest {
+                properties: [(name.clone(), Some(property))].into(),
+                ..Default::default()
+            };
+            if self
+                .pages
+                .update_page_properties(&id, request)
+                .await
+                .is_err()
+            {
+                self.skip(&id, SkipReason::Property { name });
+            }
+        }
+
+        for (id, source) in std::mem::take(&mut self.deferred) {
+            let Ok(copy) = copy_block(&source, &self.copies) else {
+                continue;
+            };
+            let request = UpdateABlockRequest {
+                block: Some(copy),
+                archived: None,
+            };
+            if self.blocks.update_a_block(&id, request).await.is_err() {
+                self.skip(source.id.unwrap_or_default(), SkipReason::Link);
+            }
+        }
+    }
+}
+
+/// Properties to create a copy with, and what was left out of it.
+#[derive(Debug, PartialEq)]
+pub(crate) struct CopiedProperties<P, R> {
+    pub properties: BTreeMap<String, P>,
+    /// Relations to the copied database itself by property name, which can
+    /// only be set once the copy exists.
+    pub relations: BTreeMap<String, R>,
+    pub skipped: Vec<SkipReason>,
+}
+
+/// The properties to create a copy of a page under `parent` with. Computed
+/// properties are left to Notion, and outside a database only the title
+/// remains. Relations to other rows of the copied database are returned with
+/// their targets, to be set once those rows are copied.
+pub(crate) fn page_properties(
+    properties: &HashMap<String, PageProperty>,
+    parent: &Parent,
+    schema: Option<&HashMap<String, DatabaseProperty>>,
+) -> CopiedProperties<PageProperty, Vec<PageId>> {
+    let database_id = match parent {
+        Parent::DatabaseId { database_id } => Some(database_id),
+        _ => None,
+    };
+    let is_self_relation = |name: &str| match schema.and_then(|schema| schema.get(name)) {
+        Some(DatabaseProperty::Relation { relation, .. }) => {
+            relation.database_id.is_some() && relation.database_id.as_ref() == database_id
+        }
+        _ => false,
+    };
+
+    let mut copied = CopiedProperties {
+        properties: BTreeMap::new(),
+        relations: BTreeMap::new(),
+        skipped: vec![],
+    };
+    for (name, property) in properties {
+        match property {
+            PageProperty::Title { .. } if database_id.is_none() => {
+                copied
+                    .properties
+                    .insert("title".to_string(), property.clone());
+            }
+            PageProperty::Formula { .. }
+            | PageProperty::Rollup { .. }
+            | PageProperty::CreatedTime { .. }
+            | PageProperty::CreatedBy { .. }
+            | PageProperty::LastEditedTime { .. }
+            | PageProperty::LastEditedBy { .. }
+            | PageProperty::UniqueID { .. }
+            | PageProperty::Verification { .. }
+            | PageProperty::Button { .. } => {}
+            _ if database_id.is_none()
+                || schema.is_some_and(|schema| !schema.contains_key(name)) =>
+            {
+                copied
+                    .skipped
+                    .push(SkipReason::Property { name: name.clone() });
+            }
+            PageProperty::Relation { relation, .. } if is_self_relation(name) => {
+                if !relation.is_empty() {
+                    let targets = relation.iter().map(|target| target.id.clone()).collect();
+                    copied.relations.insert(name.clone(), targets);
+                }
+            }
+            PageProperty::Files { id, files } => {
+                let external = files
+                    .iter()
+                    .filter(|file| matches!(file.file, File::External { .. }))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if external.len() < files.len() {
+                    copied.skipped.push(SkipReason::HostedFile);
+                }
+                let files = PageProperty::Files {
+                    id: id.clone(),
+                    files: external,
+                };
+                copied.properties.insert(name.clone(), files);
+            }
+            property => {
+                copied.properties.insert(name.clone(), property.clone());
+            }
+        }
+    }
+    copied
+}
+
+/// The schema to create a copy of database `source` with. Relations to other
+/// databases become one-way, so that creating the copy adds no property to
+/// them, and relations of the database to itself are returned by name, to be
+/// pointed to the copy once it exists.
+pub(crate) fn database_properties(
+    source: &DatabaseId,
+    properties: &HashMap<String, DatabaseProperty>,
+) -> CopiedProperties<DatabaseProperty, ()> {
+    let mut copied = CopiedProperties {
+        properties: BTreeMap::new(),
+        relations: BTreeMap::new(),
+        skipped: vec![],
+    };
+    for (name, property) in properties {
+        match property {
+            DatabaseProperty::Status { .. }
+            | DatabaseProperty::Button { .. }
+            | DatabaseProperty::Verification { .. }
+            | DatabaseProperty::Place { .. } => copied
+                .skipped
+                .push(SkipReason::Property { name: name.clone() }),
+            DatabaseProperty::Relation { relation, .. } => match &relation.database_id {
+                Some(database_id) if database_id == source => {
+                    copied.relations.insert(name.clone(), ());
+                }
+                Some(database_id) => {
+                    copied
+                        .properties
+                        .insert(name.clone(), single_relation(database_id.clone()));
+                }
+                None => copied
+                    .skipped
+                    .push(SkipReason::Property { name: name.clone() }),
+            },
+            property => {
+                copied.properties.insert(name.clone(), without_id(property));
+            }
+        }
+    }
+    copied
+}
+
+fn single_relation(database_id: DatabaseId) -> DatabaseProperty {
+    DatabaseProperty::Relation {
+        id: None,
+        name: None,
+        relation: RelationPropertyValue {
+            database_id: Some(database_id),
+            relation_type: Some(RelationType::SingleProperty {
+                single_property: EmptyConfig {},
+            }),
+            ..Default::default()
+        },
+    }
+}
+
+/// A copy of `block` without its children and read-only fields, that can be
+/// sent to the API. Links, mentions and synced block references to pages and
+/// blocks in `copies` are pointed to the copies.
+pub(crate) fn copy_block(
+    block: &Block,
+    copies: &HashMap<BlockId, BlockId>,
+) -> Result<Block, SkipReason> {
+    let mut block_type = block.block_type.clone();
+    if let Some(children) = block_type.children_mut() {
+        *children = None;
+    }
+
+    let hosted = |file: &File| matches!(file, File::File { .. });
+    match &block_type {
+        BlockType::None
+        | BlockType::Button { .. }
+        | BlockType::ChildDatabase { .. }
+        | BlockType::ChildPage { .. }
+        | BlockType::LinkPreview { .. }
+        | BlockType::Transcription { .. }
+        | BlockType::Unsupported { .. } => return Err(SkipReason::UnsupportedBlock),
+        BlockType::Audio { audio } if hosted(&audio.file_type) => {
+            return Err(SkipReason::HostedFile)
+        }
+        BlockType::File { file } if hosted(&file.file_type) => return Err(SkipReason::HostedFile),
+        BlockType::Image { image } if hosted(&image.file_type) => {
+            return Err(SkipReason::HostedFile)
+        }
+        BlockType::Pdf { pdf } if hosted(&pdf.file_type) => return Err(SkipReason::HostedFile),
+        BlockType::Video { video } if hosted(&video.file_type) => {
+            return Err(SkipReason::HostedFile)
+        }
+        _ => {}
+    }
+
+    let mut value = serde_json::to_value(&block_type).map_err(|_| SkipReason::UnsupportedBlock)?;
+    visit_references(&mut value, &mut |reference| {
+        if let Some(copy) = copies.get(&BlockId::from(reference.clone())) {
+            *reference = copy.to_string();
+        }
+    });
+    let block_type =
+        serde_json::from_value::<BlockType>(value).map_err(|_| SkipReason::UnsupportedBlock)?;
+
+    Ok(Block::from(block_type))
+}
+
+/// Tables and column lists must be created together with their rows and
+/// columns, and tables in columns with their rows. Child pages and databases
+/// in columns are moved to `nested_pages`.
+pub(crate) fn with_inline_children(
+    source: &Block,
+    copy: &Block,
+    copies: &HashMap<BlockId, BlockId>,
+    nested_pages: &mut Vec<Block>,
+    skipped: &mut Vec<Skipped>,
+) -> Block {
+    let mut copy = copy.clone();
+    let children = match &source.block_type {
+        BlockType::Table { table } => table
+            .children
+            .iter()
+            .flatten()
+            .filter_map(|row| copy_block(row, copies).ok())
+            .collect(),
+        BlockType::ColumnList { column_list } => column_list
+            .children
+            .iter()
+            .flatten()
+            .filter_map(|column| {
+                let mut column_copy = copy_block(column, copies).ok()?;
+                let mut children = vec![];
+                for child in column.block_type.children().into_iter().flatten() {
+                    match &child.block_type {
+                        BlockType::ChildPage { .. } | BlockType::ChildDatabase { .. } => {
+                            nested_pages.push(child.clone())
+                        }
+                        _ => match copy_block(child, copies) {
+                            Ok(child_copy) => children.push(with_inline_children(
+                                child,
+                                &child_copy,
+                                copies,
+                                nested_pages,
+                                skipped,
+                            )),
+                            Err(reason) => skipped.push(Skipped {
+                                source: child.id.clone().unwrap_or_default(),
+                                reason,
+                            }),
+                        },
+                    }
+                }
+                if children.is_empty() {
+                    children.push(Block::from(BlockType::Paragraph {
+                        paragraph: Default::default(),
+                    }));
+                }
+                if let Some(slot) = column_copy.block_type.children_mut() {
+                    *slot = Some(children);
+                }
+                Some(column_copy)
+            })
+            .collect(),
+        _ => return copy,
+    };
+    if let Some(slot) = copy.block_type.children_mut() {
+        *slot = Some(children);
+    }
+    copy
+}
+
+/// Calls `f` with the ids of the pages, databases and blocks a serialized block
+/// links to: `link_to_page` targets, page and database mentions and synced
+/// block originals.
+fn visit_references(value: &mut Value, f: &mut impl FnMut(&mut String)) {
+    match value {
+        Value::Object(object) => {
+            let kind = object
+                .get("type")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            match kind.as_deref() {
+                Some(kind @ ("page_id" | "database_id" | "block_id")) => {
+                    if let Some(Value::String(id)) = object.get_mut(kind) {
+                        f(id);
+                    }
+                }
+                Some(kind @ ("page" | "database")) => {
+                    if let Some(Value::String(id)) =
+                        object.get_mut(kind).and_then(|target| target.get_mut("id"))
+                    {
+                        f(id);
+                    }
+                }
+                _ => {}
+            }
+            for value in object.values_mut() {
+                visit_references(value, f);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                visit_references(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn without_id(property: &DatabaseProperty) -> DatabaseProperty {
+    let mut value = serde_json::to_value(property).unwrap_or_default();
+    if let Some(object) = value.as_object_mut() {
+        object.remove("id");
+    }
+    serde_json::from_value(value).unwrap_or_else(|_| property.clone())
+}
@@ -0,0 +1,24 @@
+use derive_builder::Builder;
+
+/// Options for [`PagesEndpoint::duplicate_page`](super::super::PagesEndpoint::duplicate_page).
+#[derive(Debug, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+pub struct DuplicatePageOptions {
+    /// Replaces the title of the copy. The source title is kept when `None`.
+    pub title: Option<String>,
+    /// Copy child pages with their content; otherwise they are skipped.
+    pub child_pages: bool,
+    /// Copy inline databases with their schema and rows; otherwise they are skipped.
+    pub child_databases: bool,
+}
+
+impl Default for DuplicatePageOptions {
+    fn default() -> Self {
+        DuplicatePageOptions {
+            title: None,
+            child_pages: true,
+            child_databases: true,
+        }
+    }
+}
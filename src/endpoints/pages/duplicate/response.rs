@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::objects::{
+    id::{BlockId, PageId},
+    page::Page,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DuplicatePageResponse {
+    /// The copy of the source page.
+    pub page: Page,
+    /// The copy of every duplicated page, including child pages and database
+    /// rows, by source page id.
+    pub copies: HashMap<PageId, PageId>,
+    /// Everything left out of the copy.
+    pub skipped: Vec<Skipped>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Skipped {
+    /// The block, page or database that was not copied, or that holds what was not copied.
+    pub source: BlockId,
+    pub reason: SkipReason,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SkipReason {
+    /// The block type cannot be created through the API, e.g. buttons,
+    /// link previews and meeting notes.
+    UnsupportedBlock,
+    /// A file, icon or cover hosted by Notion. Its URL expires, so it cannot be reused.
+    HostedFile,
+    /// A child page, with `child_pages` disabled.
+    ChildPage,
+    /// An inline database, with `child_databases` disabled.
+    ChildDatabase,
+    /// A property the API cannot create, or that the new parent has no place for.
+    Property { name: String },
+    /// A link or mention of a page copied later that could not be pointed to its copy.
+    Link,
+}
@@ -4,7 +4,7 @@ use urlencoding::decode;
 
 use crate::{
     endpoints::NOTION_URI,
-    objects::{page::Page, Response},
+    objects::{id::PageId, page::Page, Response},
     NotionClientError,
 };
 
@@ -15,9 +15,10 @@ use super::PagesEndpoint;
 impl PagesEndpoint {
     pub async fn retrieve_a_page(
         &self,
-        page_id: &str,
+        page_id: impl Into<PageId>,
         filter_properties: Option<Vec<&str>>,
     ) -> Result<Page, NotionClientError> {
+        let page_id = page_id.into();
         let filter_properties: Vec<_> = filter_properties
             .iter()
             .flatten()
@@ -52,11 +53,12 @@ impl PagesEndpoint {
 
     pub async fn retrieve_a_page_property_item(
         &self,
-        page_id: &str,
+        page_id: impl Into<PageId>,
         property_id: &str,
         page_size: Option<u32>,
         start_cursor: Option<&str>,
     ) -> Result<RetrieveAPagePropertyItemResponse, NotionClientError> {
+        let page_id = page_id.into();
         let mut query = vec![];
         let page_size = page_size.map(|p| p.to_string());
         if let Some(start_cursor) = start_cursor {
@@ -1,20 +1,41 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use serde_json::Number;
 
 use crate::{
     endpoints::pages::{
-        create::request::CreateAPageRequest, retrieve::response::RetrieveAPagePropertyItemResponse,
+        create::request::CreateAPageRequest,
+        duplicate::{
+            copy_block, database_properties, page_properties,
+            request::{DuplicatePageOptions, DuplicatePageOptionsBuilder},
+            response::SkipReason,
+            with_inline_children, without_id,
+        },
+        retrieve::response::RetrieveAPagePropertyItemResponse,
         update::request::UpdatePagePropertiesRequest,
     },
     objects::{
-        block::{Block, BlockType, HeadingsValue, ParagraphValue},
+        block::{
+            builder::{column, column_list, p, table},
+            Block, BlockType, HeadingsValue, ParagraphValue,
+        },
+        database::DatabaseProperty,
+        date::DateOrDateTime,
         emoji::Emoji,
         file::{ExternalFile, File},
-        page::{Icon, Page, PageProperty, SelectPropertyValue},
+        icon::Icon,
+        id::{BlockId, DatabaseId, PageId, UserId},
+        notion_page::{PropertyError, PropertyKind},
+        page::{
+            accessors::PropertyKey, builder::PropertiesBuilder, row::to_properties, Page,
+            PageProperty, SelectPropertyValue,
+        },
         parent::Parent,
         rich_text::{Link, RichText, Text},
     },
+    NotionClientError,
 };
 
 #[test]
@@ -71,7 +92,7 @@ fn test_create_request() {
 
     let request = CreateAPageRequest {
         parent: Parent::DatabaseId {
-            database_id: "d9824bdc84454327be8b5b47500af6ce".to_string(),
+            database_id: "d9824bdc84454327be8b5b47500af6ce".into(),
         },
         icon: Some(Icon::Emoji(Emoji::Emoji {
             emoji: "🥬".to_string(),
@@ -130,13 +151,89 @@ fn test_create_request() {
     assert_eq!(result, actual.to_string())
 }
 
+#[test]
+fn test_properties_builder() {
+    let properties = PropertiesBuilder::new()
+        .property("Name", PageProperty::title("Tuscan Kale"))
+        .property(
+            "Description",
+            PageProperty::rich_text("A dark green leafy vegetable"),
+        )
+        .property("Food group", PageProperty::select("Vegetable"))
+        .property("Price", PageProperty::try_number(2.5).unwrap())
+        .build();
+
+    let request = CreateAPageRequest {
+        properties,
+        ..Default::default()
+    };
+    let expected =
+        serde_json::from_str::<serde_json::Value>(include_str!("tests/create_request.json"))
+            .unwrap();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["properties"],
+        expected["properties"]
+    );
+}
+
+#[test]
+fn test_number_rejects_non_finite_floats() {
+    assert_eq!(
+        PageProperty::try_number(7u8).unwrap(),
+        PageProperty::number(7u8)
+    );
+    assert_eq!(
+        PageProperty::number(7u8),
+        PageProperty::Number {
+            id: None,
+            number: Some(Number::from(7)),
+        }
+    );
+    assert!(matches!(
+        PageProperty::try_number(f64::NAN),
+        Err(NotionClientError::InvalidNumber { .. })
+    ));
+    assert!(matches!(
+        PageProperty::try_number(f32::INFINITY),
+        Err(NotionClientError::InvalidNumber { .. })
+    ));
+}
+
+#[test]
+fn test_update_clear_request() {
+    let properties = PropertiesBuilder::new()
+        .property("In stock", PageProperty::checkbox(true))
+        .property("Tags", PageProperty::multi_select(["Leafy", "Seasonal"]))
+        .property(
+            "Recipes",
+            PageProperty::relation(["90eeeed82cdd4af49cc13d24aff5f63c"]),
+        )
+        .property(
+            "Last ordered",
+            PageProperty::date(NaiveDate::from_ymd_opt(2022, 2, 22).unwrap(), None),
+        )
+        .clear("Food group", PropertyKind::Select)
+        .clear("Price", PropertyKind::Number)
+        .clear("Description", PropertyKind::RichText)
+        .build_update();
+
+    let request = UpdatePagePropertiesRequest {
+        properties,
+        ..Default::default()
+    };
+
+    let result = serde_json::to_string_pretty(&request).unwrap();
+    let actual = include_str!("tests/update_clear_request.json");
+    assert_eq!(result, actual.to_string())
+}
+
 #[test]
 fn test_update_null_request() {
     let mut properties = BTreeMap::new();
     properties.insert("Date".to_string(), None);
 
     let request = UpdatePagePropertiesRequest {
-        properties: properties,
+        properties,
         ..Default::default()
     };
 
@@ -178,6 +275,173 @@ fn test_retrieve_200() {
     assert!(result.is_ok())
 }
 
+#[test]
+fn test_page_accessors() {
+    let page = serde_json::from_str::<Page>(include_str!("tests/retrieve_200.json")).unwrap();
+
+    assert_eq!(page.title_text().unwrap(), "Tuscan kale");
+    assert_eq!(
+        page.text("Description").unwrap(),
+        "A dark green leafy vegetable"
+    );
+    assert_eq!(page.number("Price").unwrap(), Some(2.5));
+    assert_eq!(
+        page.date("Last ordered").unwrap().unwrap().start,
+        Some(DateOrDateTime::Date(
+            NaiveDate::from_ymd_opt(2022, 2, 22).unwrap()
+        ))
+    );
+    assert_eq!(
+        page.select("Food group").unwrap().unwrap().name.as_deref(),
+        Some("🥬 Vegetable")
+    );
+    assert_eq!(page.multi_select("Store availability").unwrap().len(), 2);
+    assert_eq!(
+        page.relation_ids("Recipes").unwrap(),
+        vec![
+            PageId::from("90eeeed8-2cdd-4af4-9cc1-3d24aff5f63c"),
+            PageId::from("a2da43ee-d43c-4285-8ae2-6d811f12629a"),
+        ]
+    );
+    assert_eq!(
+        page.people("Responsible Person").unwrap()[0].id,
+        UserId::from("cbfe3c6e-71cf-4cd3-b6e7-02f38f371bcc")
+    );
+    assert!(page.checkbox("In stock").unwrap());
+    assert!(page.url("Photo").unwrap().is_some());
+
+    // Ids match both as returned by the API and URL-decoded.
+    assert!(page.checkbox(PropertyKey::id("%60%5Bq%3F")).unwrap());
+    assert!(page.checkbox(PropertyKey::id("`[q?")).unwrap());
+
+    assert_eq!(
+        page.checkbox("Done"),
+        Err(PropertyError::Missing {
+            property: "Done".to_string(),
+            expected: "checkbox",
+        })
+    );
+    assert_eq!(
+        page.number(PropertyKey::id("Iowm")),
+        Err(PropertyError::WrongType {
+            property: "id:Iowm".to_string(),
+            expected: "number",
+            found: "people",
+        })
+    );
+}
+
+#[test]
+fn test_page_deserialize_into() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Food {
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "Price")]
+        price: f64,
+        #[serde(rename = "Food group")]
+        group: Option<String>,
+        #[serde(rename = "Store availability")]
+        stores: Vec<String>,
+        #[serde(rename = "Last ordered")]
+        last_ordered: NaiveDate,
+        #[serde(rename = "Recipes")]
+        recipes: Vec<PageId>,
+        #[serde(rename = "In stock")]
+        in_stock: bool,
+        #[serde(rename = "Cost of next trip")]
+        cost: u32,
+        #[serde(rename = "Number of meals")]
+        meals: u32,
+    }
+
+    let page = serde_json::from_str::<Page>(include_str!("tests/retrieve_200.json")).unwrap();
+    let food: Food = page.deserialize_into().unwrap();
+    assert_eq!(food.name, "Tuscan kale");
+    assert_eq!(food.price, 2.5);
+    assert_eq!(food.group.as_deref(), Some("🥬 Vegetable"));
+    assert_eq!(food.stores.len(), 2);
+    assert_eq!(
+        food.last_ordered,
+        NaiveDate::from_ymd_opt(2022, 2, 22).unwrap()
+    );
+    assert_eq!(
+        food.recipes[0],
+        PageId::from("90eeeed8-2cdd-4af4-9cc1-3d24aff5f63c")
+    );
+    assert!(food.in_stock);
+    assert_eq!((food.cost, food.meals), (0, 2));
+
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Wrong {
+        #[serde(rename = "Price")]
+        price: String,
+    }
+    let error = page.deserialize_into::<Wrong>().unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("property `Price`: invalid type"));
+}
+
+#[test]
+fn test_page_to_properties() {
+    #[derive(Serialize)]
+    struct Food {
+        #[serde(rename = "Name")]
+        name: &'static str,
+        #[serde(rename = "Price")]
+        price: Option<f64>,
+        #[serde(rename = "Food group")]
+        group: &'static str,
+        #[serde(rename = "Last ordered")]
+        last_ordered: NaiveDate,
+    }
+
+    let schema: BTreeMap<_, _> = [
+        ("Name", PropertyKind::Title),
+        ("Price", PropertyKind::Number),
+        ("Food group", PropertyKind::Select),
+        ("Last ordered", PropertyKind::Date),
+    ]
+    .into_iter()
+    .map(|(name, kind)| (name.to_string(), kind.database_property().unwrap()))
+    .collect();
+
+    let food = Food {
+        name: "Tuscan Kale",
+        price: None,
+        group: "Vegetable",
+        last_ordered: NaiveDate::from_ymd_opt(2022, 2, 22).unwrap(),
+    };
+    let properties = to_properties(&food, &schema).unwrap();
+    assert_eq!(
+        properties,
+        PropertiesBuilder::new()
+            .property("Name", PageProperty::title("Tuscan Kale"))
+            .clear("Price", PropertyKind::Number)
+            .property("Food group", PageProperty::select("Vegetable"))
+            .property(
+                "Last ordered",
+                PageProperty::date(NaiveDate::from_ymd_opt(2022, 2, 22).unwrap(), None)
+            )
+            .build()
+    );
+
+    // Round trip through the flattened values.
+    let page_properties = properties.into_iter().collect();
+    let page = Page {
+        properties: page_properties,
+        ..serde_json::from_str(include_str!("tests/retrieve_200.json")).unwrap()
+    };
+    let value: serde_json::Value = page.deserialize_into().unwrap();
+    assert_eq!(value["Food group"], "Vegetable");
+    assert_eq!(value["Price"], serde_json::Value::Null);
+
+    let error = to_properties(&serde_json::json!({ "Missing": 1 }), &schema).unwrap_err();
+    assert_eq!(error.to_string(), "property `Missing` is not in the schema");
+}
+
 #[test]
 fn test_retrieve_a_page_property_list_200() {
     let result = serde_json::from_str::<RetrieveAPagePropertyItemResponse>(include_str!(
@@ -207,3 +471,249 @@ fn test_update_200() {
     let result = serde_json::from_str::<Page>(include_str!("tests/update_200.json"));
     assert!(result.is_ok())
 }
+
+#[test]
+fn test_duplicate_page_options() {
+    let options = DuplicatePageOptionsBuilder::default()
+        .title("Acme onboarding".to_string())
+        .child_databases(false)
+        .build()
+        .unwrap();
+    assert_eq!(
+        options,
+        DuplicatePageOptions {
+            title: Some("Acme onboarding".to_string()),
+            child_pages: true,
+            child_databases: false,
+        }
+    );
+}
+
+#[test]
+fn test_copy_block() {
+    let source = "59833787-2cf9-4fdf-8782-e53db20768a5";
+    let copy = "0f7d2f55-3e39-4b5b-9c5a-2a1d7b2b0e8c";
+    let copies = [(BlockId::from(source), BlockId::from(copy))].into();
+
+    let block = serde_json::from_value::<Block>(serde_json::json!({
+        "object": "block",
+        "id": "c02fc1d3-db8b-45c5-a222-27595b15aea7",
+        "parent": { "type": "page_id", "page_id": source },
+        "created_time": "2022-03-01T19:05:00.000Z",
+        "last_edited_time": "2022-03-01T19:05:00.000Z",
+        "has_children": true,
+        "archived": false,
+        "type": "toggle",
+        "toggle": {
+            "rich_text": [{
+                "type": "mention",
+                "mention": { "type": "page", "page": { "id": source } },
+                "annotations": {
+                    "bold": false,
+                    "italic": false,
+                    "strikethrough": false,
+                    "underline": false,
+                    "code": false,
+                    "color": "default"
+                },
+                "plain_text": "Kale",
+                "href": null
+            }],
+            "color": "default",
+            "children": [{ "object": "block", "type": "divider", "divider": {} }]
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(copy_block(&block, &copies).unwrap()).unwrap(),
+        serde_json::json!({
+            "object": "block",
+            "type": "toggle",
+            "toggle": {
+                "rich_text": [{
+                    "type": "mention",
+                    "mention": { "type": "page", "page": { "id": copy } },
+                    "annotations": {
+                        "bold": false,
+                        "italic": false,
+                        "strikethrough": false,
+                        "underline": false,
+                        "code": false,
+                        "color": "default"
+                    },
+                    "plain_text": "Kale",
+                }],
+                "color": "default"
+            }
+        })
+    );
+
+    let link = Block::from(BlockType::LinkToPage {
+        link_to_page: Parent::page(source),
+    });
+    assert_eq!(
+        copy_block(&link, &copies).unwrap().block_type,
+        BlockType::LinkToPage {
+            link_to_page: Parent::page(copy)
+        }
+    );
+
+    let hosted = serde_json::from_value::<Block>(serde_json::json!({
+        "type": "image",
+        "image": {
+            "type": "file",
+            "file": { "url": "https://files.notion.so/kale.png", "expiry_time": "2022-03-01T20:05:00.000Z" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(copy_block(&hosted, &copies), Err(SkipReason::HostedFile));
+
+    let unsupported = serde_json::from_value::<Block>(serde_json::json!({
+        "type": "unsupported",
+        "unsupported": {}
+    }))
+    .unwrap();
+    assert_eq!(
+        copy_block(&unsupported, &copies),
+        Err(SkipReason::UnsupportedBlock)
+    );
+}
+
+#[test]
+fn test_inline_children_of_columns() {
+    let copies = HashMap::new();
+    let source = column_list([
+        column([p("Left"), table([["a", "b"], ["c", "d"]])]),
+        column([]),
+    ]);
+    let copy = copy_block(&source, &copies).unwrap();
+    let (mut nested_pages, mut skipped) = (vec![], vec![]);
+
+    assert_eq!(
+        with_inline_children(&source, &copy, &copies, &mut nested_pages, &mut skipped),
+        column_list([
+            column([p("Left"), table([["a", "b"], ["c", "d"]])]),
+            column([Block::from(BlockType::Paragraph {
+                paragraph: Default::default(),
+            })]),
+        ])
+    );
+    assert!(nested_pages.is_empty());
+    assert!(skipped.is_empty());
+}
+
+#[test]
+fn test_database_properties() {
+    let source = DatabaseId::from("668d797c-76fa-4934-9b05-ad288df2d136");
+    let other = "2f26ee68-df30-4251-aad4-8ddc420cba3d";
+    let schema = serde_json::from_value::<HashMap<String, DatabaseProperty>>(serde_json::json!({
+        "Name": { "id": "title", "name": "Name", "type": "title", "title": {} },
+        "Parent task": {
+            "id": "a%3Dq",
+            "name": "Parent task",
+            "type": "relation",
+            "relation": {
+                "database_id": source.as_str(),
+                "type": "dual_property",
+                "dual_property": { "synced_property_id": "b%3Dr", "synced_property_name": "Subtasks" }
+            }
+        },
+        "Project": {
+            "id": "c%3Ds",
+            "name": "Project",
+            "type": "relation",
+            "relation": {
+                "database_id": other,
+                "type": "dual_property",
+                "dual_property": { "synced_property_id": "d%3Dt", "synced_property_name": "Tasks" }
+            }
+        },
+        "Status": {
+            "id": "e%3Du",
+            "name": "Status",
+            "type": "status",
+            "status": { "options": [], "groups": [] }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        without_id(&schema["Name"]),
+        DatabaseProperty::Title {
+            id: None,
+            name: Some("Name".to_string()),
+            title: Default::default(),
+        }
+    );
+
+    let copied = database_properties(&source, &schema);
+
+    assert_eq!(
+        serde_json::to_value(&copied.properties).unwrap(),
+        serde_json::json!({
+            "Name": { "name": "Name", "type": "title", "title": {} },
+            "Project": {
+                "type": "relation",
+                "relation": { "database_id": other, "type": "single_property", "single_property": {} }
+            }
+        })
+    );
+    assert_eq!(copied.relations, [("Parent task".to_string(), ())].into());
+    assert_eq!(
+        copied.skipped,
+        vec![SkipReason::Property {
+            name: "Status".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_page_properties() {
+    let copy = DatabaseId::from("0f7d2f55-3e39-4b5b-9c5a-2a1d7b2b0e8c");
+    let other = "2f26ee68-df30-4251-aad4-8ddc420cba3d";
+    let row = "59833787-2cf9-4fdf-8782-e53db20768a5";
+    let schema = serde_json::from_value::<HashMap<String, DatabaseProperty>>(serde_json::json!({
+        "Name": { "id": "title", "type": "title", "title": {} },
+        "Parent task": {
+            "id": "a%3Dq",
+            "type": "relation",
+            "relation": { "database_id": copy.as_str(), "type": "single_property", "single_property": {} }
+        },
+        "Project": {
+            "id": "c%3Ds",
+            "type": "relation",
+            "relation": { "database_id": other, "type": "single_property", "single_property": {} }
+        }
+    }))
+    .unwrap();
+    let properties = serde_json::from_value::<HashMap<String, PageProperty>>(serde_json::json!({
+        "Name": { "id": "title", "type": "title", "title": [] },
+        "Parent task": { "id": "a%3Dq", "type": "relation", "relation": [{ "id": row }], "has_more": false },
+        "Project": { "id": "c%3Ds", "type": "relation", "relation": [{ "id": row }], "has_more": false },
+        "Score": { "id": "f%3Dv", "type": "formula", "formula": { "type": "number", "number": 1 } },
+        "Notes": { "id": "g%3Dw", "type": "rich_text", "rich_text": [] }
+    }))
+    .unwrap();
+
+    let copied = page_properties(&properties, &Parent::database(&copy), Some(&schema));
+
+    assert_eq!(
+        copied.properties.keys().collect::<Vec<_>>(),
+        vec!["Name", "Project"]
+    );
+    assert_eq!(
+        copied.relations,
+        [("Parent task".to_string(), vec![PageId::from(row)])].into()
+    );
+    assert_eq!(
+        copied.skipped,
+        vec![SkipReason::Property {
+            name: "Notes".to_string()
+        }]
+    );
+
+    let copied = page_properties(&properties, &Parent::page(row), None);
+    assert_eq!(copied.properties.keys().collect::<Vec<_>>(), vec!["title"]);
+    assert!(copied.relations.is_empty());
+    assert_eq!(copied.skipped.len(), 3);
+}
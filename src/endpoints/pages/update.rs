@@ -2,7 +2,7 @@ pub mod request;
 
 use crate::{
     endpoints::NOTION_URI,
-    objects::{page::Page, Response},
+    objects::{id::PageId, page::Page, Response},
     NotionClientError,
 };
 
@@ -13,9 +13,10 @@ use super::PagesEndpoint;
 impl PagesEndpoint {
     pub async fn update_page_properties(
         &self,
-        page_id: &str,
+        page_id: impl Into<PageId>,
         request: UpdatePagePropertiesRequest,
     ) -> Result<Page, NotionClientError> {
+        let page_id = page_id.into();
         let json = serde_json::to_string(&request)
             .map_err(|e| NotionClientError::FailedToSerialize { source: e })?;
 
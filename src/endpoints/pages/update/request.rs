@@ -4,10 +4,7 @@ use derive_builder::Builder;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
-use crate::objects::{
-    file::File,
-    page::{Icon, PageProperty},
-};
+use crate::objects::{file::File, icon::Icon, page::PageProperty};
 
 #[skip_serializing_none]
 #[derive(Serialize, Debug, Eq, PartialEq, Clone, Default, Builder)]
@@ -1,13 +1,17 @@
 use crate::{
     endpoints::NOTION_URI,
-    objects::{user::User, Response},
+    objects::{id::UserId, user::User, Response},
     NotionClientError,
 };
 
 use super::UsersEndpoint;
 
 impl UsersEndpoint {
-    pub async fn retrieve_a_user(&self, user_id: &str) -> Result<User, NotionClientError> {
+    pub async fn retrieve_a_user(
+        &self,
+        user_id: impl Into<UserId>,
+    ) -> Result<User, NotionClientError> {
+        let user_id = user_id.into();
         let result = self
             .client
             .get(format!(
@@ -0,0 +1,10 @@
+//! Converts documents in other formats into block trees for
+//! `append_block_children` or `CreateAPageRequest.children`.
+
+#[cfg(feature = "html")]
+pub mod html;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+#[cfg(all(test, any(feature = "html", feature = "markdown")))]
+mod tests;
@@ -0,0 +1,482 @@
+use scraper::{node::Node, ElementRef, Html};
+
+use crate::{
+    endpoints::blocks::append::request::MAX_NESTING,
+    objects::{
+        block::{
+            builder::{
+                bullet, code, divider, h1, h2, h3, image, numbered, p, quote, table,
+                table_with_header, todo,
+            },
+            Block, BlockType, Language,
+        },
+        rich_text::{normalize, RichText, Text, MAX_RICH_TEXT_SPANS},
+    },
+};
+
+/// Converts an HTML fragment or document into blocks.
+///
+/// Headings below `h3` become `heading_3`, images inside text are moved after
+/// it, and unknown tags are flattened to their text. Blocks inside table cells
+/// become lines of the cell's text. Long text is split across
+/// spans and paragraphs to stay within Notion's limits, and blocks nested more
+/// than two levels deep are moved up next to their parent.
+pub fn parse(html: &str) -> Vec<Block> {
+    let document = Html::parse_document(html);
+    let root = document
+        .root_element()
+        .child_elements()
+        .find(|element| element.value().name() == "body")
+        .unwrap_or_else(|| document.root_element());
+
+    let mut converter = Converter::default();
+    converter.children(root);
+    limit_nesting(converter.finish(), 0)
+}
+
+#[derive(Default)]
+struct Converter {
+    blocks: Vec<Block>,
+    inline: Vec<RichText>,
+    /// Blocks found inside inline content, emitted after it.
+    pending: Vec<Block>,
+    style: Style,
+}
+
+#[derive(Default)]
+struct Style {
+    bold: usize,
+    italic: usize,
+    underline: usize,
+    strikethrough: usize,
+    code: usize,
+    links: Vec<String>,
+}
+
+impl Converter {
+    fn children(&mut self, element: ElementRef) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => self.push_text(&collapse_whitespace(text)),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        self.element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn element(&mut self, element: ElementRef) {
+        match element.value().name() {
+            "h1" => self.text_block(element, h1),
+            "h2" => self.text_block(element, h2),
+            "h3" | "h4" | "h5" | "h6" => self.text_block(element, h3),
+            "p" | "figcaption" | "dt" | "dd" | "caption" => self.text_block(element, p),
+            "ul" | "ol" => {
+                self.flush();
+                let ordered = element.value().name() == "ol";
+                for item in element.child_elements() {
+                    if item.value().name() == "li" {
+                        let block = self.list_item(item, ordered);
+                        self.blocks.push(block);
+                    } else {
+                        self.element(item);
+                    }
+                }
+            }
+            "li" => {
+                self.flush();
+                let block = self.list_item(element, false);
+                self.blocks.push(block);
+            }
+            "pre" => {
+                self.flush();
+                let language = element
+                    .child_elements()
+                    .chain([element])
+                    .flat_map(|e| e.value().classes())
+                    .find_map(|class| {
+                        class
+                            .strip_prefix("language-")
+                            .or_else(|| class.strip_prefix("lang-"))
+                    })
+                    .map(Language::from_info_string)
+                    .unwrap_or(Language::PlainText);
+                let source = element.text().collect::<String>();
+                let source = source.strip_suffix('\n').unwrap_or(&source);
+                self.blocks.push(normalized_code(language, source));
+            }
+            "blockquote" => {
+                self.flush();
+                let (text, children) = self.nested(element);
+                let block = quote(text);
+                self.blocks.push(with_children(block, children));
+            }
+            "table" => {
+                self.flush();
+                let (block, moved) = self.table(element);
+                self.blocks.push(block);
+                self.blocks.extend(moved);
+            }
+            "img" => {
+                if let Some(src) = element.attr("src") {
+                    self.pending.push(image(src));
+                }
+                if self.inline.is_empty() {
+                    self.flush();
+                }
+            }
+            "hr" => {
+                self.flush();
+                self.blocks.push(divider());
+            }
+            "br" => self.push_text("\n"),
+            "head" | "script" | "style" | "template" | "noscript" | "title" | "input" => {}
+            "b" | "strong" => self.styled(element, |s| &mut s.bold),
+            "i" | "em" | "cite" | "var" => self.styled(element, |s| &mut s.italic),
+            "u" | "ins" => self.styled(element, |s| &mut s.underline),
+            "s" | "strike" | "del" => self.styled(element, |s| &mut s.strikethrough),
+            "code" | "kbd" | "samp" | "tt" => self.styled(element, |s| &mut s.code),
+            "a" => match element.attr("href") {
+                Some(href) if is_absolute(href) => {
+                    self.style.links.push(href.to_string());
+                    self.children(element);
+                    self.style.links.pop();
+                }
+                _ => self.children(element),
+            },
+            name if is_container(name) => {
+                self.flush();
+                self.children(element);
+                self.flush();
+            }
+            _ => self.children(element),
+        }
+    }
+
+    fn styled(&mut self, element: ElementRef, counter: fn(&mut Style) -> &mut usize) {
+        *counter(&mut self.style) += 1;
+        self.children(element);
+        *counter(&mut self.style) -= 1;
+    }
+
+    /// A block whose text is the element's inline content. Text over the span
+    /// limit continues in paragraphs after it.
+    fn text_block(&mut self, element: ElementRef, block: fn(Vec<RichText>) -> Block) {
+        self.flush();
+        self.children(element);
+        let text = trim(std::mem::take(&mut self.inline));
+        if !text.is_empty() {
+            let mut chunks = chunk_rich_text(text).into_iter();
+            self.blocks.extend(chunks.next().map(block));
+            self.blocks.extend(chunks.map(p));
+        }
+        self.flush();
+    }
+
+    fn list_item(&mut self, item: ElementRef, ordered: bool) -> Block {
+        let checkbox = item.child_elements().find(|child| {
+            child.value().name() == "input" && child.attr("type") == Some("checkbox")
+        });
+        let (text, children) = self.nested(item);
+        let block = match checkbox {
+            Some(checkbox) => todo(text, checkbox.attr("checked").is_some()),
+            None if ordered => numbered(text),
+            None => bullet(text),
+        };
+        with_children(block, children)
+    }
+
+    /// Converts the element's content on its own. A leading paragraph becomes
+    /// the text, the remaining blocks the children.
+    fn nested(&mut self, element: ElementRef) -> (Vec<RichText>, Vec<Block>) {
+        let outer = std::mem::take(self);
+        self.style = Style {
+            links: outer.style.links.clone(),
+            ..Style::default()
+        };
+        self.children(element);
+        let mut blocks = std::mem::replace(self, outer).finish();
+
+        let text = match blocks.first() {
+            Some(Block {
+                block_type: BlockType::Paragraph { paragraph },
+                ..
+            }) if paragraph.children.is_none() => {
+                let text = paragraph.rich_text.clone();
+                blocks.remove(0);
+                text
+            }
+            _ => vec![],
+        };
+        (text, blocks)
+    }
+
+    /// The table and the blocks moved out of its cells: those without text,
+    /// and cell text over the span limit as paragraphs.
+    fn table(&mut self, element: ElementRef) -> (Block, Vec<Block>) {
+        let mut has_column_header = false;
+        let mut moved = vec![];
+        let rows = element
+            .child_elements()
+            .flat_map(|child| match child.value().name() {
+                "thead" | "tbody" | "tfoot" => child
+                    .child_elements()
+                    .map(|row| (child.value().name() == "thead", row))
+                    .collect(),
+                _ => vec![(false, child)],
+            })
+            .filter(|(_, row)| row.value().name() == "tr")
+            .collect::<Vec<_>>();
+
+        let cells = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, (in_head, row))| {
+                let cells = row
+                    .child_elements()
+                    .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+                    .collect::<Vec<_>>();
+                if i == 0 {
+                    has_column_header = in_head
+                        || (!cells.is_empty() && cells.iter().all(|c| c.value().name() == "th"));
+                }
+                cells
+                    .into_iter()
+                    .map(|cell| {
+                        let (mut text, blocks) = self.nested(cell);
+                        flatten_into(&mut text, blocks, &mut moved);
+                        normalize::rich_text(&mut text);
+                        let mut chunks = chunk_rich_text(text).into_iter();
+                        let first = chunks.next().unwrap_or_default();
+                        moved.extend(chunks.map(p));
+                        first
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let block = if has_column_header {
+            table_with_header(cells)
+        } else {
+            table(cells)
+        };
+        (block, moved)
+    }
+
+    fn push_text(&mut self, content: &str) {
+        // Whitespace between elements collapses like in a browser.
+        let follows_space = match self.inline.last() {
+            Some(RichText::Text { text, .. }) => text.content.ends_with([' ', '\n']),
+            Some(_) => false,
+            None => true,
+        };
+        let content = match content.strip_prefix(' ') {
+            Some(rest) if follows_space => rest,
+            _ => content,
+        };
+        if content.is_empty() {
+            return;
+        }
+        let link = self.style.links.last().cloned();
+        let mut span = RichText::text(content);
+        if self.style.bold > 0 {
+            span = span.bold();
+        }
+        if self.style.italic > 0 {
+            span = span.italic();
+        }
+        if self.style.underline > 0 {
+            span = span.underline();
+        }
+        if self.style.strikethrough > 0 {
+            span = span.strikethrough();
+        }
+        if self.style.code > 0 {
+            span = span.code();
+        }
+        if let Some(url) = &link {
+            span = span.link(url.clone());
+        }
+
+        if let (
+            Some(RichText::Text {
+                text:
+                    Text {
+                        content: last_content,
+                        link: last_link,
+                    },
+                annotations: last_annotations,
+                ..
+            }),
+            RichText::Text { annotations, .. },
+        ) = (self.inline.last_mut(), &span)
+        {
+            if last_annotations == annotations
+                && last_link.as_ref().map(|l| &l.url) == link.as_ref()
+            {
+                last_content.push_str(content);
+                return;
+            }
+        }
+        self.inline.push(span);
+    }
+
+    /// Ends the current run of loose inline content as paragraphs.
+    fn flush(&mut self) {
+        let text = trim(std::mem::take(&mut self.inline));
+        if !text.is_empty() {
+            self.blocks.extend(chunk_rich_text(text).into_iter().map(p));
+        }
+        self.blocks.append(&mut self.pending);
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush();
+        self.blocks
+    }
+}
+
+fn with_children(block: Block, children: Vec<Block>) -> Block {
+    if children.is_empty() {
+        block
+    } else {
+        block.children(children)
+    }
+}
+
+/// Appends the text of blocks found in a table cell to the cell, one line per
+/// block. Blocks without text, such as images and tables, go to `moved`.
+fn flatten_into(text: &mut Vec<RichText>, blocks: Vec<Block>, moved: &mut Vec<Block>) {
+    for mut block in blocks {
+        if matches!(block.block_type, BlockType::Table { .. }) {
+            moved.push(block);
+            continue;
+        }
+        let children = block
+            .block_type
+            .children_mut()
+            .and_then(Option::take)
+            .unwrap_or_default();
+        let spans = block
+            .block_type
+            .rich_text_mut()
+            .into_iter()
+            .map(std::mem::take)
+            .collect::<Vec<_>>();
+        if spans.is_empty() && children.is_empty() {
+            moved.push(block);
+            continue;
+        }
+        for spans in spans.into_iter().filter(|spans| !spans.is_empty()) {
+            if !text.is_empty() {
+                text.push(RichText::text("\n"));
+            }
+            text.extend(spans);
+        }
+        flatten_into(text, children, moved);
+    }
+}
+
+fn is_container(name: &str) -> bool {
+    matches!(
+        name,
+        "html"
+            | "body"
+            | "div"
+            | "section"
+            | "article"
+            | "main"
+            | "header"
+            | "footer"
+            | "nav"
+            | "aside"
+            | "figure"
+            | "details"
+            | "summary"
+            | "dl"
+            | "form"
+            | "fieldset"
+            | "address"
+    )
+}
+
+fn is_absolute(href: &str) -> bool {
+    href.starts_with("http://") || href.starts_with("https://") || href.starts_with("mailto:")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
+
+/// Drops whitespace at the edges of a block, as a browser would, and
+/// normalizes the spans, see [`normalize::rich_text`].
+fn trim(mut text: Vec<RichText>) -> Vec<RichText> {
+    if let Some(RichText::Text { text: first, .. }) = text.first_mut() {
+        first.content = first.content.trim_start_matches(' ').to_string();
+    }
+    if let Some(RichText::Text { text: last, .. }) = text.last_mut() {
+        last.content = last.content.trim_end_matches(' ').to_string();
+    }
+    text.retain(|span| !matches!(span, RichText::Text { text, .. } if text.content.is_empty()));
+    normalize::rich_text(&mut text);
+    text
+}
+
+fn chunk_rich_text(text: Vec<RichText>) -> Vec<Vec<RichText>> {
+    text.chunks(MAX_RICH_TEXT_SPANS)
+        .map(<[_]>::to_vec)
+        .collect()
+}
+
+/// A code block with its source split across spans, see [`normalize`].
+fn normalized_code(language: Language, source: &str) -> Block {
+    let mut block = code(language, source);
+    normalize::block(&mut block);
+    block
+}
+
+/// Moves children of blocks at the deepest level a request allows up to
+/// follow their parent. Tables keep their rows.
+fn limit_nesting(blocks: Vec<Block>, depth: usize) -> Vec<Block> {
+    let mut limited = vec![];
+    for mut block in blocks {
+        if matches!(block.block_type, BlockType::Table { .. }) {
+            limited.push(block);
+            continue;
+        }
+        let children = block
+            .block_type
+            .children_mut()
+            .and_then(Option::take)
+            .unwrap_or_default();
+        if children.is_empty() {
+            limited.push(block);
+        } else if depth < MAX_NESTING {
+            let children = limit_nesting(children, depth + 1);
+            if let Some(slot) = block.block_type.children_mut() {
+                *slot = Some(children);
+            }
+            limited.push(block);
+        } else {
+            limited.push(block);
+            limited.extend(limit_nesting(children, depth));
+        }
+    }
+    limited
+}
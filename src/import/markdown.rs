@@ -0,0 +1,408 @@
+use pulldown_cmark::{
+    BlockQuoteKind, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd,
+};
+
+use crate::objects::{
+    block::{
+        builder::{
+            bullet, callout, code, divider, equation, h1, h2, h3, image, numbered, p, quote, table,
+            table_with_header, todo,
+        },
+        Block, Language,
+    },
+    icon::Icon,
+    rich_text::{normalize, RichText, Text},
+};
+
+/// Parses CommonMark with the GFM extensions into blocks.
+///
+/// Constructs without a Notion equivalent degrade as follows:
+/// - headings below level 3 become `heading_3`
+/// - images and display math inside a paragraph are moved after it
+/// - HTML is kept as plain text and front matter is dropped
+/// - GFM alerts (`> [!NOTE]`) become callouts
+///
+/// Rich text is normalized, see [`normalize`].
+pub fn parse(markdown: &str) -> Vec<Block> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_MATH
+        | Options::ENABLE_GFM
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+
+    let mut converter = Converter::default();
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    let mut blocks = converter.finish();
+    normalize::blocks(&mut blocks);
+    blocks
+}
+
+struct Converter {
+    frames: Vec<Frame>,
+    /// Whether each open list is ordered.
+    lists: Vec<bool>,
+    /// Rich text of the open paragraph, heading or table cell.
+    inline: Option<Vec<RichText>>,
+    /// Blocks found inside inline content, emitted after it.
+    pending: Vec<Block>,
+    style: Style,
+    heading: Option<HeadingLevel>,
+    code: Option<(Language, String)>,
+    table: Option<TableState>,
+    in_image: usize,
+    in_metadata: bool,
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Converter {
+            frames: vec![Frame::new(FrameKind::Root)],
+            lists: vec![],
+            inline: None,
+            pending: vec![],
+            style: Style::default(),
+            heading: None,
+            code: None,
+            table: None,
+            in_image: 0,
+            in_metadata: false,
+        }
+    }
+}
+
+struct Frame {
+    kind: FrameKind,
+    /// The first paragraph of a list item or quote, used as its own text.
+    text: Option<Vec<RichText>>,
+    blocks: Vec<Block>,
+}
+
+impl Frame {
+    fn new(kind: FrameKind) -> Self {
+        Frame {
+            kind,
+            text: None,
+            blocks: vec![],
+        }
+    }
+}
+
+enum FrameKind {
+    Root,
+    Item {
+        ordered: bool,
+        checked: Option<bool>,
+    },
+    Quote(Option<BlockQuoteKind>),
+}
+
+#[derive(Default)]
+struct Style {
+    bold: usize,
+    italic: usize,
+    strikethrough: usize,
+    links: Vec<String>,
+}
+
+#[derive(Default)]
+struct TableState {
+    has_column_header: bool,
+    rows: Vec<Vec<Vec<RichText>>>,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event) {
+        if self.in_metadata {
+            self.in_metadata = !matches!(event, Event::End(TagEnd::MetadataBlock(_)));
+            return;
+        }
+
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, source)) = &mut self.code {
+                    source.push_str(&text);
+                } else if self.in_image == 0 {
+                    self.push_text(&text, false);
+                }
+            }
+            Event::Code(text) => self.push_text(&text, true),
+            Event::InlineMath(expression) => {
+                let span = self.styled(RichText::equation(expression.to_string()));
+                self.inline.get_or_insert_with(Vec::new).push(span);
+            }
+            Event::DisplayMath(expression) => self.pending.push(equation(expression.trim())),
+            Event::Html(html) | Event::InlineHtml(html) => self.push_text(&html, false),
+            Event::FootnoteReference(label) => self.push_text(&format!("[^{}]", label), false),
+            Event::SoftBreak => self.push_text(" ", false),
+            Event::HardBreak => self.push_text("\n", false),
+            Event::Rule => {
+                self.flush_inline();
+                self.push_block(divider());
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(Frame {
+                    kind: FrameKind::Item { checked: item, .. },
+                    ..
+                }) = self.frames.last_mut()
+                {
+                    *item = Some(checked);
+                }
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock => {
+                self.flush_inline();
+                self.inline = Some(vec![]);
+            }
+            Tag::Heading { level, .. } => {
+                self.flush_inline();
+                self.heading = Some(level);
+                self.inline = Some(vec![]);
+            }
+            Tag::BlockQuote(kind) => {
+                self.flush_inline();
+                self.frames.push(Frame::new(FrameKind::Quote(kind)));
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush_inline();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => Language::from_info_string(&info),
+                    CodeBlockKind::Indented => Language::PlainText,
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush_inline();
+                self.lists.push(start.is_some());
+            }
+            Tag::Item => {
+                let ordered = self.lists.last().copied().unwrap_or_default();
+                self.frames.push(Frame::new(FrameKind::Item {
+                    ordered,
+                    checked: None,
+                }));
+            }
+            Tag::Table(_) => {
+                self.flush_inline();
+                self.table = Some(TableState::default());
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.has_column_header |= matches!(tag, Tag::TableHead);
+                    table.rows.push(vec![]);
+                }
+            }
+            Tag::TableCell => self.inline = Some(vec![]),
+            Tag::Emphasis => self.style.italic += 1,
+            Tag::Strong => self.style.bold += 1,
+            Tag::Strikethrough => self.style.strikethrough += 1,
+            Tag::Link { dest_url, .. } => self.style.links.push(dest_url.to_string()),
+            Tag::Image { dest_url, .. } => {
+                self.in_image += 1;
+                self.pending.push(image(dest_url.to_string()));
+            }
+            Tag::MetadataBlock(_) => self.in_metadata = true,
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => self.flush_inline(),
+            TagEnd::Heading(_) => {
+                let text = self.inline.take().unwrap_or_default();
+                let block = match self.heading.take() {
+                    Some(HeadingLevel::H1) => h1(text),
+                    Some(HeadingLevel::H2) => h2(text),
+                    _ => h3(text),
+                };
+                self.push_block(block);
+                self.flush_pending();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_inline();
+                let frame = self.frames.pop().expect("quote frame");
+                let text = frame.text.unwrap_or_default();
+                let block = match frame.kind {
+                    FrameKind::Quote(Some(kind)) => callout(text, Icon::emoji(alert_emoji(kind))),
+                    _ => quote(text),
+                };
+                self.push_block(with_children(block, frame.blocks));
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, source)) = self.code.take() {
+                    let source = source.strip_suffix('\n').unwrap_or(&source);
+                    self.push_block(code(language, source));
+                }
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.flush_inline();
+                let frame = self.frames.pop().expect("item frame");
+                let text = frame.text.unwrap_or_default();
+                let block = match frame.kind {
+                    FrameKind::Item {
+                        checked: Some(checked),
+                        ..
+                    } => todo(text, checked),
+                    FrameKind::Item { ordered: true, .. } => numbered(text),
+                    _ => bullet(text),
+                };
+                self.push_block(with_children(block, frame.blocks));
+            }
+            TagEnd::TableCell => {
+                let cell = self.inline.take().unwrap_or_default();
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(cell);
+                }
+            }
+            TagEnd::Table => {
+                if let Some(state) = self.table.take() {
+                    let block = if state.has_column_header {
+                        table_with_header(state.rows)
+                    } else {
+                        table(state.rows)
+                    };
+                    self.push_block(block);
+                    self.flush_pending();
+                }
+            }
+            TagEnd::Emphasis => self.style.italic -= 1,
+            TagEnd::Strong => self.style.bold -= 1,
+            TagEnd::Strikethrough => self.style.strikethrough -= 1,
+            TagEnd::Link => {
+                self.style.links.pop();
+            }
+            TagEnd::Image => self.in_image -= 1,
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush_inline();
+        self.frames
+            .into_iter()
+            .next()
+            .map(|root| root.blocks)
+            .unwrap_or_default()
+    }
+
+    /// Appends text to the open inline content, opening one for tight list items.
+    fn push_text(&mut self, content: &str, code: bool) {
+        let link = self.style.links.last().cloned();
+        let span = self.styled(RichText::text(content));
+        let span = if code { span.code() } else { span };
+        let inline = self.inline.get_or_insert_with(Vec::new);
+
+        if let (
+            Some(RichText::Text {
+                text:
+                    Text {
+                        content: last_content,
+                        link: last_link,
+                    },
+                annotations: last_annotations,
+                ..
+            }),
+            RichText::Text { annotations, .. },
+        ) = (inline.last_mut(), &span)
+        {
+            if last_annotations == annotations
+                && last_link.as_ref().map(|l| &l.url) == link.as_ref()
+            {
+                last_content.push_str(content);
+                return;
+            }
+        }
+        inline.push(span);
+    }
+
+    fn styled(&self, mut span: RichText) -> RichText {
+        if self.style.bold > 0 {
+            span = span.bold();
+        }
+        if self.style.italic > 0 {
+            span = span.italic();
+        }
+        if self.style.strikethrough > 0 {
+            span = span.strikethrough();
+        }
+        if let Some(url) = self.style.links.last() {
+            span = span.link(url.clone());
+        }
+        span
+    }
+
+    /// Ends the open paragraph. The first paragraph of a list item or quote
+    /// becomes its text, later ones its children.
+    fn flush_inline(&mut self) {
+        if let Some(text) = self.inline.take() {
+            let text = trim(text);
+            let frame = self.frames.last_mut().expect("root frame");
+            if !matches!(frame.kind, FrameKind::Root)
+                && frame.text.is_none()
+                && frame.blocks.is_empty()
+            {
+                frame.text = Some(text);
+            } else if !text.is_empty() {
+                frame.blocks.push(p(text));
+            }
+        }
+        self.flush_pending();
+    }
+
+    fn flush_pending(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        for block in pending {
+            self.push_block(block);
+        }
+    }
+
+    fn push_block(&mut self, block: Block) {
+        let frame = self.frames.last_mut().expect("root frame");
+        if !matches!(frame.kind, FrameKind::Root) && frame.text.is_none() {
+            frame.text = Some(vec![]);
+        }
+        frame.blocks.push(block);
+    }
+}
+
+fn with_children(block: Block, children: Vec<Block>) -> Block {
+    if children.is_empty() {
+        block
+    } else {
+        block.children(children)
+    }
+}
+
+/// Drops whitespace left at the edges of a paragraph by line breaks and HTML.
+fn trim(mut text: Vec<RichText>) -> Vec<RichText> {
+    if let Some(RichText::Text { text: first, .. }) = text.first_mut() {
+        first.content = first.content.trim_start().to_string();
+    }
+    if let Some(RichText::Text { text: last, .. }) = text.last_mut() {
+        last.content = last.content.trim_end().to_string();
+    }
+    text.retain(|span| !matches!(span, RichText::Text { text, .. } if text.content.is_empty()));
+    text
+}
+
+fn alert_emoji(kind: BlockQuoteKind) -> &'static str {
+    match kind {
+        BlockQuoteKind::Note => "ℹ️",
+        BlockQuoteKind::Tip => "💡",
+        BlockQuoteKind::Important => "❗",
+        BlockQuoteKind::Warning => "⚠️",
+        BlockQuoteKind::Caution => "🛑",
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(feature = "markdown")]
+mod markdown {
+    use crate::{
+        import::markdown,
+        objects::{
+            block::{
+                builder::{
+                    bullet, callout, code, divider, equation, h1, h2, h3, image, numbered, p,
+                    quote, table_with_header, todo,
+                },
+                Language,
+            },
+            icon::Icon,
+            rich_text::RichText,
+        },
+        render, rich_text,
+    };
+
+    #[test]
+    fn test_parse_markdown() {
+        let blocks = markdown::parse(include_str!("tests/document.md"));
+
+        let expected = crate::blocks![
+            h1("Deploying"),
+            p(rich_text![
+                "Run ",
+                RichText::text("make deploy").bold().code(),
+                " from the ",
+                RichText::text("repository root").italic(),
+                ", see ",
+                RichText::text("the runbook").link("https://example.com/runbook"),
+                ". It takes ",
+                RichText::text("ten").strikethrough(),
+                " two minutes."
+            ]),
+            h2("Checklist"),
+            todo("Tag the release", true),
+            todo("Announce it", false),
+            numbered("Build"),
+            numbered("Upload").children([bullet("to staging"), bullet("to production")]),
+            quote("Measure twice, cut once."),
+            callout("Deploys are not reversible.", Icon::emoji("⚠️")),
+            code(Language::Rust, "fn main() {}"),
+            table_with_header([
+                vec![rich_text!["Service"], rich_text!["Owner"]],
+                vec![rich_text!["api"], rich_text![RichText::text("core").bold()]],
+            ]),
+            image("https://example.com/architecture.png"),
+            p(rich_text![
+                "Energy is ",
+                RichText::equation("E = mc^2"),
+                "."
+            ]),
+            equation("a^2 + b^2 = c^2"),
+            divider(),
+            h3("Notes"),
+            p("<b>HTML</b> stays as text."),
+        ];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn test_parse_rendered_markdown() {
+        let blocks = crate::blocks![
+            h1("Groceries"),
+            p(rich_text!["Buy ", RichText::text("fresh").bold(), " kale."]),
+            bullet("Vegetables").children([bullet("Kale")]),
+            quote("Eat your greens."),
+            code(Language::Python, "print(\"kale\")"),
+        ];
+
+        assert_eq!(markdown::parse(&render::markdown::render(&blocks)), blocks);
+    }
+
+    #[test]
+    fn test_language_info_string() {
+        assert_eq!(Language::from_info_string("rust"), Language::Rust);
+        assert_eq!(
+            Language::from_info_string("ts {title=a.ts}"),
+            Language::Typescript
+        );
+        assert_eq!(Language::from_info_string("C++"), Language::CPlusPlus);
+        assert_eq!(Language::from_info_string("brainfuck"), Language::PlainText);
+        assert_eq!(Language::from_info_string(""), Language::PlainText);
+        assert_eq!(Language::PlainText.info_string(), "text");
+    }
+}
+
+#[cfg(feature = "html")]
+mod html {
+    use crate::{
+        import::html,
+        objects::{
+            block::{
+                builder::{
+                    bullet, code, divider, h1, h3, image, numbered, p, quote, table,
+                    table_with_header, todo,
+                },
+                Block, BlockType, Language,
+            },
+            rich_text::RichText,
+        },
+        rich_text,
+    };
+
+    #[test]
+    fn test_parse_html() {
+        let blocks = html::parse(include_str!("tests/article.html"));
+
+        let expected = crate::blocks![
+            h1("Deploying"),
+            p(rich_text![
+                "Run ",
+                RichText::text("make deploy").bold().code(),
+                " from the ",
+                RichText::text("repository root").italic(),
+                ", see ",
+                RichText::text("the runbook").link("https://example.com/runbook"),
+                ". It takes ",
+                RichText::text("ten").strikethrough(),
+                " two minutes."
+            ]),
+            h3("Checklist"),
+            todo("Tag the release", true),
+            todo("Announce it", false),
+            numbered("Build"),
+            numbered("Upload").children([bullet("to staging"), bullet("to production")]),
+            quote(rich_text![
+                "Measure twice, ",
+                RichText::text("cut once").underline(),
+                "."
+            ]),
+            code(Language::Rust, "fn main() {\n    println!(\"hi\");\n}"),
+            table_with_header([
+                vec![rich_text!["Service"], rich_text!["Owner"]],
+                vec![rich_text!["api"], rich_text![RichText::text("core").bold()]],
+            ]),
+            p("See the diagram below.\nThen continue."),
+            image("https://example.com/architecture.png"),
+            divider(),
+            p("Unknown tags keep their text."),
+        ];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn test_parse_html_limits() {
+        let long = "a".repeat(4500);
+        let blocks = html::parse(&format!("<p>{}</p>", long));
+        let Some(BlockType::Paragraph { paragraph }) = blocks.first().map(|b| &b.block_type) else {
+            panic!("expected a paragraph, got {:?}", blocks);
+        };
+        let lengths = paragraph
+            .rich_text
+            .iter()
+            .map(|span| match span {
+                RichText::Text { text, .. } => text.content.len(),
+                _ => 0,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, [2000, 2000, 500]);
+
+        let spans = "<b>x</b>y".repeat(60);
+        let blocks = html::parse(&format!("<p>{}</p>", spans));
+        assert_eq!(blocks.len(), 2);
+
+        let blocks = html::parse(
+            "<ul><li>one<ul><li>two<ul><li>three<ul><li>four</li></ul></li></ul></li></ul></li></ul>",
+        );
+        let expected: Vec<Block> =
+            crate::blocks![bullet("one")
+                .children([bullet("two").children([bullet("three"), bullet("four")]),]),];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn test_parse_html_table_cells() {
+        let blocks = html::parse(
+            "<table><tr>\
+             <td><p>a</p><p>b</p></td>\
+             <td><ul><li>x</li><li>y<ul><li>z</li></ul></li></ul></td>\
+             <td>see<img src=\"https://example.com/c.png\"></td>\
+             </tr></table>",
+        );
+        let expected: Vec<Block> = crate::blocks![
+            table([[rich_text!["a\nb"], rich_text!["x\ny\nz"], rich_text!["see"]]]),
+            image("https://example.com/c.png"),
+        ];
+        assert_eq!(blocks, expected);
+
+        let spans = "<b>x</b>y".repeat(60);
+        let blocks = html::parse(&format!("<table><tr><td>{}</td></tr></table>", spans));
+        let Some(BlockType::Table { table }) = blocks.first().map(|b| &b.block_type) else {
+            panic!("expected a table, got {:?}", blocks);
+        };
+        let Some(BlockType::TableRow { table_row }) = table
+            .children
+            .as_ref()
+            .and_then(|rows| rows.first())
+            .map(|row| &row.block_type)
+        else {
+            panic!("expected a row, got {:?}", table.children);
+        };
+        assert_eq!(table_row.cells[0].len(), 100);
+        let Some(BlockType::Paragraph { paragraph }) = blocks.get(1).map(|b| &b.block_type) else {
+            panic!("expected the rest of the cell, got {:?}", blocks);
+        };
+        assert_eq!(paragraph.rich_text.len(), 20);
+    }
+}
@@ -1,7 +1,14 @@
 pub mod endpoints;
+pub mod import;
 pub mod objects;
+pub mod render;
+#[cfg(feature = "webhook-server")]
+pub mod webhook_server;
 
-use objects::error::Error;
+#[cfg(feature = "derive")]
+pub use notion_client_derive::NotionPage;
+
+use objects::{error::Error, page::Page, rich_text::normalize::Unfixable};
 use reqwest::header::InvalidHeaderValue;
 
 #[derive(Debug, thiserror::Error)]
@@ -29,4 +36,25 @@ pub enum NotionClientError {
 
     #[error("Invalid header {}", source)]
     InvalidHeader { source: InvalidHeaderValue },
+
+    #[error("Invalid Notion id: {}", value)]
+    InvalidId { value: String },
+
+    #[error("Invalid number {}, Notion numbers must be finite", value)]
+    InvalidNumber { value: String },
+
+    #[error("Invalid webhook signature")]
+    InvalidWebhookSignature,
+
+    #[error("Invalid filter expression at {}: {}", position, message)]
+    InvalidFilterExpression { message: String, position: usize },
+
+    #[error("Rich text over Notion's limits in {} place(s)", .unfixable.len())]
+    UnfixableRichText { unfixable: Vec<Unfixable> },
+
+    #[error("Created page {} but failed to append its children: {}", .page.id, source)]
+    PageCreatedWithoutChildren {
+        page: Box<Page>,
+        source: Box<NotionClientError>,
+    },
 }
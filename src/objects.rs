@@ -1,11 +1,17 @@
 use serde::Deserialize;
 
 pub mod block;
+pub mod color;
 pub mod comment;
 pub mod database;
+pub mod date;
 pub mod emoji;
 pub mod error;
 pub mod file;
+pub mod icon;
+pub mod id;
+pub mod link;
+pub mod notion_page;
 pub mod page;
 pub mod parent;
 pub mod property;
@@ -13,6 +19,7 @@ pub mod rich_text;
 #[cfg(test)]
 mod tests;
 pub mod user;
+pub mod webhook;
 
 use error::Error;
 
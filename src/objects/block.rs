@@ -2,13 +2,23 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use super::{emoji::Emoji, file::File, parent::Parent, rich_text::RichText, user::User};
+use super::{
+    color,
+    file::File,
+    icon,
+    id::{BlockId, UserId},
+    parent::Parent,
+    rich_text::RichText,
+    user::User,
+};
+
+pub mod builder;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct Block {
     pub object: Option<String>,
-    pub id: Option<String>,
+    pub id: Option<BlockId>,
     pub parent: Option<Parent>,
     #[serde(flatten)]
     pub block_type: BlockType,
@@ -25,15 +35,21 @@ pub struct Block {
 pub enum BlockType {
     #[default]
     None,
+    Audio {
+        audio: AudioValue,
+    },
     Bookmark {
         bookmark: BookmarkValue,
     },
     Breadcrumb {
-        breadcrump: BreadcrumpValue,
+        breadcrumb: BreadcrumbValue,
     },
     BulletedListItem {
         bulleted_list_item: BulletedListItemValue,
     },
+    Button {
+        button: ButtonValue,
+    },
     Callout {
         callout: CalloutValue,
     },
@@ -76,6 +92,10 @@ pub enum BlockType {
     Heading3 {
         heading_3: HeadingsValue,
     },
+    #[serde(rename = "heading_4")]
+    Heading4 {
+        heading_4: HeadingsValue,
+    },
     Image {
         image: ImageValue,
     },
@@ -97,6 +117,9 @@ pub enum BlockType {
     SyncedBlock {
         synced_block: SyncedBlockValue,
     },
+    Tab {
+        tab: TabValue,
+    },
     Table {
         table: TableValue,
     },
@@ -115,13 +138,31 @@ pub enum BlockType {
     Toggle {
         toggle: ToggleValue,
     },
+    /// AI meeting notes, returned as `meeting_notes` by newer API versions.
+    #[serde(alias = "meeting_notes")]
+    Transcription {
+        #[serde(alias = "meeting_notes")]
+        transcription: TranscriptionValue,
+    },
     Video {
         video: VideoValue,
     },
     LinkToPage {
         link_to_page: Parent,
     },
-    Unsupported,
+    /// A block the API cannot represent. The payload is kept as returned.
+    Unsupported {
+        #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+        unsupported: serde_json::Value,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct AudioValue {
+    #[serde(default)]
+    pub caption: Vec<RichText>,
+    #[serde(flatten)]
+    pub file_type: File,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -130,32 +171,46 @@ pub struct BookmarkValue {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-pub struct BreadcrumpValue {}
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct BreadcrumbValue {}
+
+#[deprecated(note = "use `BreadcrumbValue` instead")]
+pub type BreadcrumpValue = BreadcrumbValue;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct BulletedListItemValue {
     pub rich_text: Vec<RichText>,
-    pub color: TextColor,
+    pub color: color::TextColor,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct ButtonValue {}
+
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct CalloutValue {
     pub rich_text: Vec<RichText>,
-    pub icon: Option<Icon>,
-    pub color: TextColor,
+    pub icon: Option<icon::Icon>,
+    pub color: color::TextColor,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Block>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct ChildDatabaseValue {
     pub title: String,
+    /// The database rows as `child_page` blocks, filled in by `retrieve_block_tree`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Block>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct ChildPageValue {
     pub title: String,
+    /// The page content, filled in by `retrieve_block_tree`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Block>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -166,10 +221,16 @@ pub struct CodeValue {
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-pub struct ColumnListValue {}
+pub struct ColumnListValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Block>>,
+}
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-pub struct ColumnValue {}
+pub struct ColumnValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Block>>,
+}
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct DividerValue {}
@@ -196,8 +257,9 @@ pub struct FileValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct HeadingsValue {
     pub rich_text: Vec<RichText>,
-    pub color: Option<TextColor>,
+    pub color: Option<color::TextColor>,
     pub is_toggleable: Option<bool>,
+    pub children: Option<Vec<Block>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -214,7 +276,7 @@ pub struct LinkPreviewValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct NumberedListItemValue {
     pub rich_text: Vec<RichText>,
-    pub color: TextColor,
+    pub color: color::TextColor,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
@@ -223,7 +285,7 @@ pub struct NumberedListItemValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct ParagraphValue {
     pub rich_text: Vec<RichText>,
-    pub color: Option<TextColor>,
+    pub color: Option<color::TextColor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
@@ -238,7 +300,7 @@ pub struct PdfValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct QuoteValue {
     pub rich_text: Vec<RichText>,
-    pub color: TextColor,
+    pub color: color::TextColor,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
@@ -253,7 +315,13 @@ pub struct SyncedBlockValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SyncedFrom {
-    BlockId { block_id: String },
+    BlockId { block_id: BlockId },
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct TabValue {
+    pub children: Option<Vec<Block>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -272,7 +340,7 @@ pub struct TableRowsValue {
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct TableOfContentsValue {
-    pub color: TextColor,
+    pub color: color::TextColor,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -287,7 +355,7 @@ pub struct TemplateValue {
 pub struct ToDoValue {
     pub rich_text: Vec<RichText>,
     pub checked: Option<bool>,
-    pub color: Option<TextColor>,
+    pub color: Option<color::TextColor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
@@ -295,48 +363,70 @@ pub struct ToDoValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct ToggleValue {
     pub rich_text: Vec<RichText>,
-    pub color: TextColor,
+    pub color: color::TextColor,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<Block>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-pub struct VideoValue {
-    #[serde(flatten)]
-    pub file_type: File,
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct TranscriptionValue {
+    #[serde(default)]
+    pub title: Vec<RichText>,
+    pub status: Option<TranscriptionStatus>,
+    pub children: Option<TranscriptionChildren>,
+    pub calendar_event: Option<TranscriptionCalendarEvent>,
+    pub recording: Option<TranscriptionRecording>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "snake_case")]
-pub enum TextColor {
-    Blue,
-    BlueBackground,
-    Brown,
-    BrownBackground,
-    Default,
-    Gray,
-    GrayBackground,
-    Green,
-    GreenBackground,
-    Orange,
-    OrangeBackground,
-    Yellow,
-    YellowBackground,
-    Pink,
-    PinkBackground,
-    Purple,
-    PurpleBackground,
-    Red,
-    RedBackground,
+pub enum TranscriptionStatus {
+    TranscriptionNotStarted,
+    TranscriptionPaused,
+    TranscriptionInProgress,
+    SummaryInProgress,
+    NotesReady,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The sections of a meeting note, retrievable with `retrieve_block_children`.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct TranscriptionChildren {
+    pub summary_block_id: Option<BlockId>,
+    pub notes_block_id: Option<BlockId>,
+    pub transcript_block_id: Option<BlockId>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct TranscriptionCalendarEvent {
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub attendees: Vec<UserId>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct TranscriptionRecording {
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-#[serde(rename_all = "snake_case", untagged)]
-pub enum Icon {
-    File(File),
-    Emoji(Emoji),
+pub struct VideoValue {
+    #[serde(flatten)]
+    pub file_type: File,
 }
 
+#[deprecated(note = "use `objects::color::TextColor` instead")]
+pub type TextColor = super::color::TextColor;
+
+#[deprecated(note = "use `objects::icon::Icon` instead")]
+pub type Icon = super::icon::Icon;
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
@@ -423,133 +513,199 @@ pub enum Language {
     JavaOrCOrCPlusPlusOrCSharp,
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-struct EmptyObject {}
+impl Language {
+    /// The info string of a fenced code block in this language, e.g. `rust`.
+    pub fn info_string(&self) -> String {
+        match self {
+            Language::PlainText => "text".to_string(),
+            Language::VisualBasic => "vb".to_string(),
+            Language::JavaOrCOrCPlusPlusOrCSharp => "java".to_string(),
+            language => serde_json::to_value(language)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Parses the info string of a fenced code block, e.g. `rust` or `py`.
+    /// Unknown languages become plain text.
+    pub fn from_info_string(info: &str) -> Language {
+        let name = info
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let name = match name.as_str() {
+            "" | "text" | "txt" | "plaintext" => "plain text",
+            "rs" => "rust",
+            "py" => "python",
+            "js" | "jsx" | "mjs" => "javascript",
+            "ts" | "tsx" => "typescript",
+            "sh" | "zsh" | "console" => "shell",
+            "yml" => "yaml",
+            "md" => "markdown",
+            "cpp" | "cxx" | "hpp" => "c++",
+            "cs" | "csharp" => "c#",
+            "fs" | "fsharp" => "f#",
+            "objc" => "objective-c",
+            "rb" => "ruby",
+            "kt" => "kotlin",
+            "golang" => "go",
+            "vb" => "visual basic",
+            "dockerfile" => "docker",
+            "make" => "makefile",
+            "tex" => "latex",
+            "proto" => "protobuf",
+            "ps1" => "powershell",
+            "hs" => "haskell",
+            "ex" | "exs" => "elixir",
+            "ml" => "ocaml",
+            "wasm" | "wat" => "webassembly",
+            name => name,
+        };
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .unwrap_or(Language::PlainText)
+    }
+}
 
 impl BlockType {
+    /// The nested blocks of block types that can have children, filled in only
+    /// when built locally or fetched recursively.
+    pub fn children(&self) -> Option<&Vec<Block>> {
+        match self {
+            BlockType::BulletedListItem { bulleted_list_item } => {
+                bulleted_list_item.children.as_ref()
+            }
+            BlockType::Callout { callout } => callout.children.as_ref(),
+            BlockType::ColumnList { column_list } => column_list.children.as_ref(),
+            BlockType::Column { column } => column.children.as_ref(),
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => heading.children.as_ref(),
+            BlockType::NumberedListItem { numbered_list_item } => {
+                numbered_list_item.children.as_ref()
+            }
+            BlockType::Paragraph { paragraph } => paragraph.children.as_ref(),
+            BlockType::Quote { quote } => quote.children.as_ref(),
+            BlockType::SyncedBlock { synced_block } => synced_block.children.as_ref(),
+            BlockType::Tab { tab } => tab.children.as_ref(),
+            BlockType::Table { table } => table.children.as_ref(),
+            BlockType::Template { template } => template.children.as_ref(),
+            BlockType::ToDo { to_do } => to_do.children.as_ref(),
+            BlockType::Toggle { toggle } => toggle.children.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The `children` field of block types that can have children.
+    pub fn children_mut(&mut self) -> Option<&mut Option<Vec<Block>>> {
+        match self {
+            BlockType::BulletedListItem { bulleted_list_item } => {
+                Some(&mut bulleted_list_item.children)
+            }
+            BlockType::Callout { callout } => Some(&mut callout.children),
+            BlockType::ColumnList { column_list } => Some(&mut column_list.children),
+            BlockType::Column { column } => Some(&mut column.children),
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => Some(&mut heading.children),
+            BlockType::NumberedListItem { numbered_list_item } => {
+                Some(&mut numbered_list_item.children)
+            }
+            BlockType::Paragraph { paragraph } => Some(&mut paragraph.children),
+            BlockType::Quote { quote } => Some(&mut quote.children),
+            BlockType::SyncedBlock { synced_block } => Some(&mut synced_block.children),
+            BlockType::Tab { tab } => Some(&mut tab.children),
+            BlockType::Table { table } => Some(&mut table.children),
+            BlockType::Template { template } => Some(&mut template.children),
+            BlockType::ToDo { to_do } => Some(&mut to_do.children),
+            BlockType::Toggle { toggle } => Some(&mut toggle.children),
+            _ => None,
+        }
+    }
+
+    /// Every rich text array of the block itself, captions and table cells
+    /// included, but not those of its children.
+    pub fn rich_text_mut(&mut self) -> Vec<&mut Vec<RichText>> {
+        match self {
+            BlockType::Audio { audio } => vec![&mut audio.caption],
+            BlockType::Bookmark { bookmark } => vec![&mut bookmark.caption],
+            BlockType::BulletedListItem { bulleted_list_item } => {
+                vec![&mut bulleted_list_item.rich_text]
+            }
+            BlockType::Callout { callout } => vec![&mut callout.rich_text],
+            BlockType::Code { code } => vec![&mut code.rich_text, &mut code.caption],
+            BlockType::File { file } => vec![&mut file.caption],
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => vec![&mut heading.rich_text],
+            BlockType::NumberedListItem { numbered_list_item } => {
+                vec![&mut numbered_list_item.rich_text]
+            }
+            BlockType::Paragraph { paragraph } => vec![&mut paragraph.rich_text],
+            BlockType::Pdf { pdf } => vec![&mut pdf.caption],
+            BlockType::Quote { quote } => vec![&mut quote.rich_text],
+            BlockType::TableRow { table_row } => table_row.cells.iter_mut().collect(),
+            BlockType::Template { template } => vec![&mut template.rich_text],
+            BlockType::ToDo { to_do } => vec![&mut to_do.rich_text],
+            BlockType::Toggle { toggle } => vec![&mut toggle.rich_text],
+            BlockType::Transcription { transcription } => vec![&mut transcription.title],
+            _ => vec![],
+        }
+    }
+
+    /// The `plain_text` of every span in the block and its children, in order.
+    /// See [`crate::render::text`] for the text of a block tree as one string.
     pub fn plain_text(&self) -> Vec<Option<String>> {
         match self {
             BlockType::None => vec![],
-            BlockType::Bookmark { bookmark } => {
-                bookmark.caption.iter().map(|rt| rt.plain_text()).collect()
-            }
-            BlockType::Breadcrumb { breadcrump: _ } => vec![],
+            BlockType::Audio { audio } => text_of(&audio.caption),
+            BlockType::Bookmark { bookmark } => text_of(&bookmark.caption),
+            BlockType::Breadcrumb { breadcrumb: _ } => vec![],
             BlockType::BulletedListItem { bulleted_list_item } => {
-                let mut items = bulleted_list_item
-                    .rich_text
-                    .iter()
-                    .map(|rt| rt.plain_text())
-                    .collect();
-                let children = &bulleted_list_item.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
+                text_with_children(&bulleted_list_item.rich_text, &bulleted_list_item.children)
             }
+            BlockType::Button { button: _ } => vec![],
             BlockType::Callout { callout } => {
-                callout.rich_text.iter().map(|rt| rt.plain_text()).collect()
+                text_with_children(&callout.rich_text, &callout.children)
             }
             BlockType::ChildDatabase { child_database } => vec![Some(child_database.title.clone())],
             BlockType::ChildPage { child_page } => vec![Some(child_page.title.clone())],
-            BlockType::Code { code } => code.caption.iter().map(|rt| rt.plain_text()).collect(),
-            BlockType::ColumnList { column_list: _ } => vec![],
-            BlockType::Column { column: _ } => vec![],
+            BlockType::Code { code } => {
+                let mut items = text_of(&code.rich_text);
+                items.extend(text_of(&code.caption));
+                items
+            }
+            BlockType::ColumnList { column_list } => text_with_children(&[], &column_list.children),
+            BlockType::Column { column } => text_with_children(&[], &column.children),
             BlockType::Divider { divider: _ } => vec![],
             BlockType::Embed { embed: _ } => vec![],
-            BlockType::Equation { equation: _ } => vec![],
-            BlockType::File { file } => file.caption.iter().map(|rt| rt.plain_text()).collect(),
-            BlockType::Heading1 { heading_1 } => heading_1
-                .rich_text
-                .iter()
-                .map(|rt| rt.plain_text())
-                .collect(),
-            BlockType::Heading2 { heading_2 } => heading_2
-                .rich_text
-                .iter()
-                .map(|rt| rt.plain_text())
-                .collect(),
-            BlockType::Heading3 { heading_3 } => heading_3
-                .rich_text
-                .iter()
-                .map(|rt| rt.plain_text())
-                .collect(),
+            BlockType::Equation { equation } => vec![Some(equation.expression.clone())],
+            BlockType::File { file } => text_of(&file.caption),
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => {
+                text_with_children(&heading.rich_text, &heading.children)
+            }
             BlockType::Image { image: _ } => vec![],
             BlockType::LinkPreview { link_preview: _ } => vec![],
             BlockType::NumberedListItem { numbered_list_item } => {
-                let mut items = numbered_list_item
-                    .rich_text
-                    .iter()
-                    .map(|rt| rt.plain_text())
-                    .collect();
-                let children = &numbered_list_item.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
+                text_with_children(&numbered_list_item.rich_text, &numbered_list_item.children)
             }
             BlockType::Paragraph { paragraph } => {
-                let mut items = paragraph
-                    .rich_text
-                    .iter()
-                    .map(|rt| rt.plain_text())
-                    .collect();
-                let children = &paragraph.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
-            }
-            BlockType::Pdf { pdf } => pdf.caption.iter().map(|rt| rt.plain_text()).collect(),
-            BlockType::Quote { quote } => {
-                let mut items = quote.rich_text.iter().map(|rt| rt.plain_text()).collect();
-                let children = &quote.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
+                text_with_children(&paragraph.rich_text, &paragraph.children)
             }
+            BlockType::Pdf { pdf } => text_of(&pdf.caption),
+            BlockType::Quote { quote } => text_with_children(&quote.rich_text, &quote.children),
             BlockType::SyncedBlock { synced_block } => {
-                let Some(children) = &synced_block.children else {
-                    return vec![];
-                };
-                children
-                    .iter()
-                    .flat_map(|b| b.block_type.plain_text())
-                    .collect()
-            }
-            BlockType::Table { table } => {
-                let Some(children) = &table.children else {
-                    return vec![];
-                };
-                children
-                    .iter()
-                    .flat_map(|b| b.block_type.plain_text())
-                    .collect()
+                text_with_children(&[], &synced_block.children)
             }
+            BlockType::Tab { tab } => text_with_children(&[], &tab.children),
+            BlockType::Table { table } => text_with_children(&[], &table.children),
             BlockType::TableOfContents {
                 table_of_contents: _,
             } => vec![],
@@ -560,54 +716,29 @@ impl BlockType {
                 .map(|rt| rt.plain_text())
                 .collect(),
             BlockType::Template { template } => {
-                let mut items = template
-                    .rich_text
-                    .iter()
-                    .map(|rt| rt.plain_text())
-                    .collect();
-                let children = &template.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
-            }
-            BlockType::ToDo { to_do } => {
-                let mut items = to_do.rich_text.iter().map(|rt| rt.plain_text()).collect();
-                let children = &to_do.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
-            }
-            BlockType::Toggle { toggle } => {
-                let mut items = toggle.rich_text.iter().map(|rt| rt.plain_text()).collect();
-                let children = &toggle.children;
-                let Some(children) = children else {
-                    return items;
-                };
-                items.append(
-                    &mut children
-                        .iter()
-                        .flat_map(|b| b.block_type.plain_text())
-                        .collect(),
-                );
-                items
+                text_with_children(&template.rich_text, &template.children)
             }
+            BlockType::ToDo { to_do } => text_with_children(&to_do.rich_text, &to_do.children),
+            BlockType::Toggle { toggle } => text_with_children(&toggle.rich_text, &toggle.children),
+            BlockType::Transcription { transcription } => text_of(&transcription.title),
             BlockType::Video { video: _ } => vec![],
             BlockType::LinkToPage { link_to_page: _ } => vec![],
-            BlockType::Unsupported => vec![],
+            BlockType::Unsupported { unsupported: _ } => vec![],
         }
     }
 }
+
+fn text_of(rich_text: &[RichText]) -> Vec<Option<String>> {
+    rich_text.iter().map(|rt| rt.plain_text()).collect()
+}
+
+fn text_with_children(
+    rich_text: &[RichText],
+    children: &Option<Vec<Block>>,
+) -> Vec<Option<String>> {
+    let mut items = text_of(rich_text);
+    if let Some(children) = children {
+        items.extend(children.iter().flat_map(|b| b.block_type.plain_text()));
+    }
+    items
+}
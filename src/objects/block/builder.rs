@@ -0,0 +1,419 @@
+use crate::objects::{
+    color::TextColor,
+    file::{ExternalFile, File},
+    icon::Icon,
+    id::PageId,
+    parent::Parent,
+    rich_text::RichText,
+};
+
+use super::{
+    AudioValue, Block, BlockType, BookmarkValue, BreadcrumbValue, BulletedListItemValue,
+    CalloutValue, CodeValue, ColumnListValue, ColumnValue, DividerValue, EmbedValue, EquationValue,
+    HeadingsValue, ImageValue, Language, NumberedListItemValue, ParagraphValue, PdfValue,
+    QuoteValue, TableOfContentsValue, TableRowsValue, TableValue, ToDoValue, ToggleValue,
+    VideoValue,
+};
+
+/// Builds a `Vec<Block>`, e.g. `blocks![h1("Title"), p("text"), divider()]`.
+#[macro_export]
+macro_rules! blocks {
+    ($($block:expr),* $(,)?) => {
+        vec![$($crate::objects::block::Block::from($block)),*]
+    };
+}
+
+/// Anything usable as the text of a block: plain strings, a single span or
+/// a list of spans.
+pub trait IntoRichText {
+    fn into_rich_text(self) -> Vec<RichText>;
+}
+
+impl IntoRichText for &str {
+    fn into_rich_text(self) -> Vec<RichText> {
+        vec![RichText::text(self)]
+    }
+}
+
+impl IntoRichText for String {
+    fn into_rich_text(self) -> Vec<RichText> {
+        vec![RichText::text(self)]
+    }
+}
+
+impl IntoRichText for &String {
+    fn into_rich_text(self) -> Vec<RichText> {
+        vec![RichText::text(self.as_str())]
+    }
+}
+
+impl IntoRichText for RichText {
+    fn into_rich_text(self) -> Vec<RichText> {
+        vec![self]
+    }
+}
+
+impl IntoRichText for Vec<RichText> {
+    fn into_rich_text(self) -> Vec<RichText> {
+        self
+    }
+}
+
+impl From<BlockType> for Block {
+    fn from(block_type: BlockType) -> Self {
+        Block {
+            object: Some("block".to_string()),
+            block_type,
+            ..Default::default()
+        }
+    }
+}
+
+impl Block {
+    /// Nests `children` under this block. Headings become toggleable; block
+    /// types that cannot hold children are returned unchanged.
+    pub fn children(mut self, children: impl IntoIterator<Item = Block>) -> Self {
+        let children = Some(children.into_iter().collect());
+        match &mut self.block_type {
+            BlockType::BulletedListItem { bulleted_list_item } => {
+                bulleted_list_item.children = children
+            }
+            BlockType::Callout { callout } => callout.children = children,
+            BlockType::ColumnList { column_list } => column_list.children = children,
+            BlockType::Column { column } => column.children = children,
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => {
+                heading.is_toggleable = Some(true);
+                heading.children = children;
+            }
+            BlockType::NumberedListItem { numbered_list_item } => {
+                numbered_list_item.children = children
+            }
+            BlockType::Paragraph { paragraph } => paragraph.children = children,
+            BlockType::Quote { quote } => quote.children = children,
+            BlockType::SyncedBlock { synced_block } => synced_block.children = children,
+            BlockType::Tab { tab } => tab.children = children,
+            BlockType::Table { table } => table.children = children,
+            BlockType::Template { template } => template.children = children,
+            BlockType::ToDo { to_do } => to_do.children = children,
+            BlockType::Toggle { toggle } => toggle.children = children,
+            _ => {}
+        }
+        self
+    }
+
+    /// Sets the color of blocks that have one; other blocks are returned unchanged.
+    pub fn color(mut self, color: TextColor) -> Self {
+        match &mut self.block_type {
+            BlockType::BulletedListItem { bulleted_list_item } => bulleted_list_item.color = color,
+            BlockType::Callout { callout } => callout.color = color,
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => heading.color = Some(color),
+            BlockType::NumberedListItem { numbered_list_item } => numbered_list_item.color = color,
+            BlockType::Paragraph { paragraph } => paragraph.color = Some(color),
+            BlockType::Quote { quote } => quote.color = color,
+            BlockType::TableOfContents { table_of_contents } => table_of_contents.color = color,
+            BlockType::ToDo { to_do } => to_do.color = Some(color),
+            BlockType::Toggle { toggle } => toggle.color = color,
+            _ => {}
+        }
+        self
+    }
+}
+
+pub fn h1(text: impl IntoRichText) -> Block {
+    BlockType::Heading1 {
+        heading_1: heading(text),
+    }
+    .into()
+}
+
+pub fn h2(text: impl IntoRichText) -> Block {
+    BlockType::Heading2 {
+        heading_2: heading(text),
+    }
+    .into()
+}
+
+pub fn h3(text: impl IntoRichText) -> Block {
+    BlockType::Heading3 {
+        heading_3: heading(text),
+    }
+    .into()
+}
+
+pub fn h4(text: impl IntoRichText) -> Block {
+    BlockType::Heading4 {
+        heading_4: heading(text),
+    }
+    .into()
+}
+
+pub fn p(text: impl IntoRichText) -> Block {
+    BlockType::Paragraph {
+        paragraph: ParagraphValue {
+            rich_text: text.into_rich_text(),
+            ..Default::default()
+        },
+    }
+    .into()
+}
+
+pub fn bullet(text: impl IntoRichText) -> Block {
+    BlockType::BulletedListItem {
+        bulleted_list_item: BulletedListItemValue {
+            rich_text: text.into_rich_text(),
+            color: TextColor::Default,
+            children: None,
+        },
+    }
+    .into()
+}
+
+pub fn numbered(text: impl IntoRichText) -> Block {
+    BlockType::NumberedListItem {
+        numbered_list_item: NumberedListItemValue {
+            rich_text: text.into_rich_text(),
+            color: TextColor::Default,
+            children: None,
+        },
+    }
+    .into()
+}
+
+pub fn todo(text: impl IntoRichText, checked: bool) -> Block {
+    BlockType::ToDo {
+        to_do: ToDoValue {
+            rich_text: text.into_rich_text(),
+            checked: Some(checked),
+            ..Default::default()
+        },
+    }
+    .into()
+}
+
+pub fn toggle(text: impl IntoRichText) -> Block {
+    BlockType::Toggle {
+        toggle: ToggleValue {
+            rich_text: text.into_rich_text(),
+            color: TextColor::Default,
+            children: None,
+        },
+    }
+    .into()
+}
+
+pub fn quote(text: impl IntoRichText) -> Block {
+    BlockType::Quote {
+        quote: QuoteValue {
+            rich_text: text.into_rich_text(),
+            color: TextColor::Default,
+            children: None,
+        },
+    }
+    .into()
+}
+
+pub fn callout(text: impl IntoRichText, icon: impl Into<Option<Icon>>) -> Block {
+    BlockType::Callout {
+        callout: CalloutValue {
+            rich_text: text.into_rich_text(),
+            icon: icon.into(),
+            color: TextColor::Default,
+            children: None,
+        },
+    }
+    .into()
+}
+
+pub fn code(language: Language, source: impl Into<String>) -> Block {
+    BlockType::Code {
+        code: CodeValue {
+            caption: vec![],
+            rich_text: vec![RichText::text(source)],
+            language,
+        },
+    }
+    .into()
+}
+
+pub fn equation(expression: impl Into<String>) -> Block {
+    BlockType::Equation {
+        equation: EquationValue {
+            expression: expression.into(),
+        },
+    }
+    .into()
+}
+
+pub fn divider() -> Block {
+    BlockType::Divider {
+        divider: DividerValue {},
+    }
+    .into()
+}
+
+pub fn table_of_contents() -> Block {
+    BlockType::TableOfContents {
+        table_of_contents: TableOfContentsValue {
+            color: TextColor::Default,
+        },
+    }
+    .into()
+}
+
+pub fn breadcrumb() -> Block {
+    BlockType::Breadcrumb {
+        breadcrumb: BreadcrumbValue {},
+    }
+    .into()
+}
+
+pub fn bookmark(url: impl Into<String>) -> Block {
+    BlockType::Bookmark {
+        bookmark: BookmarkValue {
+            caption: vec![],
+            url: url.into(),
+        },
+    }
+    .into()
+}
+
+pub fn embed(url: impl Into<String>) -> Block {
+    BlockType::Embed {
+        embed: EmbedValue { url: url.into() },
+    }
+    .into()
+}
+
+pub fn image(url: impl Into<String>) -> Block {
+    BlockType::Image {
+        image: ImageValue {
+            file_type: external(url),
+        },
+    }
+    .into()
+}
+
+pub fn video(url: impl Into<String>) -> Block {
+    BlockType::Video {
+        video: VideoValue {
+            file_type: external(url),
+        },
+    }
+    .into()
+}
+
+pub fn audio(url: impl Into<String>) -> Block {
+    BlockType::Audio {
+        audio: AudioValue {
+            caption: vec![],
+            file_type: external(url),
+        },
+    }
+    .into()
+}
+
+pub fn pdf(url: impl Into<String>) -> Block {
+    BlockType::Pdf {
+        pdf: PdfValue {
+            caption: vec![],
+            file_type: external(url),
+        },
+    }
+    .into()
+}
+
+pub fn link_to_page(page_id: impl Into<PageId>) -> Block {
+    BlockType::LinkToPage {
+        link_to_page: Parent::page(page_id),
+    }
+    .into()
+}
+
+pub fn column_list(columns: impl IntoIterator<Item = Block>) -> Block {
+    Block::from(BlockType::ColumnList {
+        column_list: ColumnListValue { children: None },
+    })
+    .children(columns)
+}
+
+pub fn column(children: impl IntoIterator<Item = Block>) -> Block {
+    Block::from(BlockType::Column {
+        column: ColumnValue { children: None },
+    })
+    .children(children)
+}
+
+/// A table whose width is that of its longest row; shorter rows are padded
+/// with empty cells.
+pub fn table<R, C>(rows: impl IntoIterator<Item = R>) -> Block
+where
+    R: IntoIterator<Item = C>,
+    C: IntoRichText,
+{
+    table_block(rows, false)
+}
+
+/// Like [`table`], with the first row rendered as the column header.
+pub fn table_with_header<R, C>(rows: impl IntoIterator<Item = R>) -> Block
+where
+    R: IntoIterator<Item = C>,
+    C: IntoRichText,
+{
+    table_block(rows, true)
+}
+
+pub fn table_row(cells: impl IntoIterator<Item = impl IntoRichText>) -> Block {
+    BlockType::TableRow {
+        table_row: TableRowsValue {
+            cells: cells
+                .into_iter()
+                .map(IntoRichText::into_rich_text)
+                .collect(),
+        },
+    }
+    .into()
+}
+
+fn table_block<R, C>(rows: impl IntoIterator<Item = R>, has_column_header: bool) -> Block
+where
+    R: IntoIterator<Item = C>,
+    C: IntoRichText,
+{
+    let mut rows: Vec<Vec<Vec<RichText>>> = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(IntoRichText::into_rich_text).collect())
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    for row in &mut rows {
+        row.resize(width, vec![]);
+    }
+
+    Block::from(BlockType::Table {
+        table: TableValue {
+            table_width: width as u32,
+            has_column_header,
+            has_row_header: false,
+            children: None,
+        },
+    })
+    .children(rows.into_iter().map(table_row))
+}
+
+fn heading(text: impl IntoRichText) -> HeadingsValue {
+    HeadingsValue {
+        rich_text: text.into_rich_text(),
+        ..Default::default()
+    }
+}
+
+fn external(url: impl Into<String>) -> File {
+    File::External {
+        external: ExternalFile { url: url.into() },
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+/// The colors of select, multi-select and status options.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Color {
+    Default,
+    Gray,
+    Brown,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Red,
+}
+
+/// The colors of blocks and rich text, including the background variants.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TextColor {
+    #[default]
+    Default,
+    Gray,
+    Brown,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Red,
+    GrayBackground,
+    BrownBackground,
+    OrangeBackground,
+    YellowBackground,
+    GreenBackground,
+    BlueBackground,
+    PurpleBackground,
+    PinkBackground,
+    RedBackground,
+}
+
+impl TextColor {
+    pub fn is_background(&self) -> bool {
+        matches!(
+            self,
+            TextColor::GrayBackground
+                | TextColor::BrownBackground
+                | TextColor::OrangeBackground
+                | TextColor::YellowBackground
+                | TextColor::GreenBackground
+                | TextColor::BlueBackground
+                | TextColor::PurpleBackground
+                | TextColor::PinkBackground
+                | TextColor::RedBackground
+        )
+    }
+}
+
+impl From<Color> for TextColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Default => TextColor::Default,
+            Color::Gray => TextColor::Gray,
+            Color::Brown => TextColor::Brown,
+            Color::Orange => TextColor::Orange,
+            Color::Yellow => TextColor::Yellow,
+            Color::Green => TextColor::Green,
+            Color::Blue => TextColor::Blue,
+            Color::Purple => TextColor::Purple,
+            Color::Pink => TextColor::Pink,
+            Color::Red => TextColor::Red,
+        }
+    }
+}
+
+impl From<TextColor> for Color {
+    fn from(color: TextColor) -> Self {
+        match color {
+            TextColor::Default => Color::Default,
+            TextColor::Gray | TextColor::GrayBackground => Color::Gray,
+            TextColor::Brown | TextColor::BrownBackground => Color::Brown,
+            TextColor::Orange | TextColor::OrangeBackground => Color::Orange,
+            TextColor::Yellow | TextColor::YellowBackground => Color::Yellow,
+            TextColor::Green | TextColor::GreenBackground => Color::Green,
+            TextColor::Blue | TextColor::BlueBackground => Color::Blue,
+            TextColor::Purple | TextColor::PurpleBackground => Color::Purple,
+            TextColor::Pink | TextColor::PinkBackground => Color::Pink,
+            TextColor::Red | TextColor::RedBackground => Color::Red,
+        }
+    }
+}
@@ -2,13 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use super::{parent::Parent, rich_text::RichText, user::User};
+use super::{id::CommentId, parent::Parent, rich_text::RichText, user::User};
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct Comment {
     pub object: String,
-    pub id: String,
+    pub id: CommentId,
     pub parent: Parent,
     pub discussion_id: String,
     pub created_time: DateTime<Utc>,
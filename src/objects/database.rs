@@ -4,19 +4,22 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use super::{emoji::Emoji, file::File, parent::Parent, rich_text::RichText, user::User};
+use super::{
+    color, emoji::Emoji, file::File, icon, id::DatabaseId, parent::Parent, rich_text::RichText,
+    user::User,
+};
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
 pub struct Database {
-    pub id: Option<String>,
+    pub id: Option<DatabaseId>,
     pub created_time: DateTime<Utc>,
     pub created_by: Option<User>,
     pub last_edited_time: DateTime<Utc>,
     pub last_edited_by: Option<User>,
     pub title: Vec<RichText>,
     pub description: Vec<RichText>,
-    pub icon: Option<Icon>,
+    pub icon: Option<icon::Icon>,
     pub cover: Option<File>,
     pub properties: HashMap<String, DatabaseProperty>,
     pub parent: Parent,
@@ -26,8 +29,12 @@ pub struct Database {
     pub public_url: Option<bool>,
 }
 
+/// The icon type this module used before icons were shared, with `None`
+/// standing for no icon.
+#[deprecated(note = "use `objects::icon::Icon` instead")]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "snake_case", untagged)]
+#[allow(deprecated)]
 pub enum Icon {
     #[default]
     None,
@@ -35,6 +42,27 @@ pub enum Icon {
     Emoji(Emoji),
 }
 
+#[allow(deprecated)]
+impl From<icon::Icon> for Icon {
+    fn from(icon: icon::Icon) -> Self {
+        match icon {
+            icon::Icon::File(file) => Icon::File(file),
+            icon::Icon::Emoji(emoji) => Icon::Emoji(emoji),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<Icon> for Option<icon::Icon> {
+    fn from(icon: Icon) -> Self {
+        match icon {
+            Icon::None => None,
+            Icon::File(file) => Some(icon::Icon::File(file)),
+            Icon::Emoji(emoji) => Some(icon::Icon::Emoji(emoji)),
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -42,32 +70,32 @@ pub enum DatabaseProperty {
     Checkbox {
         id: Option<String>,
         name: Option<String>,
-        checkbox: HashMap<(), ()>,
+        checkbox: EmptyConfig,
     },
     CreatedBy {
         id: Option<String>,
         name: Option<String>,
-        created_by: HashMap<(), ()>,
+        created_by: EmptyConfig,
     },
     CreatedTime {
         id: Option<String>,
         name: Option<String>,
-        created_time: HashMap<(), ()>,
+        created_time: EmptyConfig,
     },
     Date {
         id: Option<String>,
         name: Option<String>,
-        date: HashMap<(), ()>,
+        date: EmptyConfig,
     },
     Email {
         id: Option<String>,
         name: Option<String>,
-        email: HashMap<(), ()>,
+        email: EmptyConfig,
     },
     Files {
         id: Option<String>,
         name: Option<String>,
-        files: HashMap<(), ()>,
+        files: EmptyConfig,
     },
     Formula {
         id: Option<String>,
@@ -77,12 +105,12 @@ pub enum DatabaseProperty {
     LastEditedBy {
         id: Option<String>,
         name: Option<String>,
-        last_edited_by: HashMap<(), ()>,
+        last_edited_by: EmptyConfig,
     },
     LastEditedTime {
         id: Option<String>,
         name: Option<String>,
-        last_edited_time: HashMap<(), ()>,
+        last_edited_time: EmptyConfig,
     },
     MultiSelect {
         id: Option<String>,
@@ -97,12 +125,12 @@ pub enum DatabaseProperty {
     People {
         id: Option<String>,
         name: Option<String>,
-        people: HashMap<(), ()>,
+        people: EmptyConfig,
     },
     PhoneNumber {
         id: Option<String>,
         name: Option<String>,
-        phone_number: HashMap<(), ()>,
+        phone_number: EmptyConfig,
     },
     Relation {
         id: Option<String>,
@@ -112,7 +140,7 @@ pub enum DatabaseProperty {
     RichText {
         id: Option<String>,
         name: Option<String>,
-        rich_text: HashMap<(), ()>,
+        rich_text: EmptyConfig,
     },
     Rollup {
         id: Option<String>,
@@ -132,22 +160,46 @@ pub enum DatabaseProperty {
     Title {
         id: Option<String>,
         name: Option<String>,
-        title: HashMap<(), ()>,
+        title: EmptyConfig,
     },
     Url {
         id: Option<String>,
         name: Option<String>,
-        url: HashMap<(), ()>,
+        url: EmptyConfig,
     },
     Button {
         id: Option<String>,
         name: Option<String>,
-        button: HashMap<(), ()>,
+        button: EmptyConfig,
+    },
+    #[serde(rename = "unique_id")]
+    UniqueID {
+        id: Option<String>,
+        name: Option<String>,
+        unique_id: UniqueIDPropertyValue,
+    },
+    Verification {
+        id: Option<String>,
+        name: Option<String>,
+        verification: EmptyConfig,
+    },
+    Place {
+        id: Option<String>,
+        name: Option<String>,
+        place: EmptyConfig,
     },
 }
 
+/// Configuration of property types that have no options, serialized as `{}`.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct EmptyConfig {}
+
+/// The color type this module used before colors were shared, defaulting
+/// to blue.
+#[deprecated(note = "use `objects::color::Color` instead")]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "snake_case")]
+#[allow(deprecated)]
 pub enum Color {
     #[default]
     Blue,
@@ -162,6 +214,42 @@ pub enum Color {
     Yellow,
 }
 
+#[allow(deprecated)]
+impl From<color::Color> for Color {
+    fn from(color: color::Color) -> Self {
+        match color {
+            color::Color::Default => Color::Default,
+            color::Color::Gray => Color::Gray,
+            color::Color::Brown => Color::Brown,
+            color::Color::Orange => Color::Orange,
+            color::Color::Yellow => Color::Yellow,
+            color::Color::Green => Color::Green,
+            color::Color::Blue => Color::Blue,
+            color::Color::Purple => Color::Purple,
+            color::Color::Pink => Color::Pink,
+            color::Color::Red => Color::Red,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<Color> for color::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Default => color::Color::Default,
+            Color::Gray => color::Color::Gray,
+            Color::Brown => color::Color::Brown,
+            Color::Orange => color::Color::Orange,
+            Color::Yellow => color::Color::Yellow,
+            Color::Green => color::Color::Green,
+            Color::Blue => color::Color::Blue,
+            Color::Purple => color::Color::Purple,
+            Color::Pink => color::Color::Pink,
+            Color::Red => color::Color::Red,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct FormulaPropertyValue {
     pub expression: String,
@@ -221,11 +309,33 @@ pub enum NumberFormat {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct RelationPropertyValue {
-    pub database_id: Option<String>,
+    pub database_id: Option<DatabaseId>,
+    #[serde(flatten)]
+    pub relation_type: Option<RelationType>,
+    /// Older API versions put the synced property here instead of in `dual_property`.
+    pub synced_property_id: Option<String>,
+    pub synced_property_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RelationType {
+    SingleProperty { single_property: EmptyConfig },
+    DualProperty { dual_property: DualPropertyValue },
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct DualPropertyValue {
     pub synced_property_id: Option<String>,
     pub synced_property_name: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct UniqueIDPropertyValue {
+    pub prefix: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct RollupPropertyValue {
@@ -275,7 +385,7 @@ pub struct StatusPropertyValue {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct Group {
-    pub color: Color,
+    pub color: color::Color,
     pub id: Option<String>,
     pub name: String,
     pub option_ids: Vec<String>,
@@ -290,6 +400,6 @@ pub struct SelectPropertyValue {
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct OptionValue {
     pub name: String,
-    pub color: Option<Color>,
+    pub color: Option<color::Color>,
     pub id: Option<String>,
 }
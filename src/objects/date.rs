@@ -0,0 +1,47 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct DatePropertyValue {
+    pub start: Option<DateOrDateTime>,
+    pub end: Option<DateOrDateTime>,
+    pub time_zone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(untagged)]
+pub enum DateOrDateTime {
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+impl DatePropertyValue {
+    pub fn new(start: impl Into<DateOrDateTime>, end: Option<DateOrDateTime>) -> Self {
+        Self {
+            start: Some(start.into()),
+            end,
+            time_zone: None,
+        }
+    }
+}
+
+impl DateOrDateTime {
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            DateOrDateTime::Date(date) => *date,
+            DateOrDateTime::DateTime(date_time) => date_time.date_naive(),
+        }
+    }
+}
+
+impl From<NaiveDate> for DateOrDateTime {
+    fn from(date: NaiveDate) -> Self {
+        DateOrDateTime::Date(date)
+    }
+}
+
+impl From<DateTime<Utc>> for DateOrDateTime {
+    fn from(date_time: DateTime<Utc>) -> Self {
+        DateOrDateTime::DateTime(date_time)
+    }
+}
@@ -8,6 +8,16 @@ pub enum File {
     File { file: HostedFile },
 }
 
+impl File {
+    /// Hosted file URLs expire after an hour, see `HostedFile::expiry_time`.
+    pub fn url(&self) -> &str {
+        match self {
+            File::External { external } => &external.url,
+            File::File { file } => &file.url,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct HostedFile {
     pub url: String,
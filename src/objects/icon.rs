@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    emoji::Emoji,
+    file::{ExternalFile, File},
+};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "snake_case", untagged)]
+pub enum Icon {
+    File(File),
+    Emoji(Emoji),
+}
+
+impl Icon {
+    pub fn emoji(emoji: impl Into<String>) -> Self {
+        Icon::Emoji(Emoji::Emoji {
+            emoji: emoji.into(),
+        })
+    }
+
+    pub fn external(url: impl Into<String>) -> Self {
+        Icon::File(File::External {
+            external: ExternalFile { url: url.into() },
+        })
+    }
+}
+
+impl From<Emoji> for Icon {
+    fn from(emoji: Emoji) -> Self {
+        Icon::Emoji(emoji)
+    }
+}
+
+impl From<File> for Icon {
+    fn from(file: File) -> Self {
+        Icon::File(file)
+    }
+}
@@ -0,0 +1,243 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::NotionClientError;
+
+/// The ids found in a notion.so or notion.site link.
+///
+/// `id` is the page or database id from the path, `view` the `?v=` database
+/// view, `peek` the `?p=` page opened on top of it and `block` the `#` anchor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotionUrl {
+    pub id: String,
+    pub view: Option<String>,
+    pub peek: Option<String>,
+    pub block: Option<String>,
+}
+
+macro_rules! id_type {
+    ($name:ident, $from_url:expr) => {
+        /// A normalized, dashed id. Converting a string with `From`, as every
+        /// endpoint taking `impl Into<Self>` does, keeps the string unchanged
+        /// when it is neither an id nor a Notion URL, so a typo only shows up
+        /// as an API error. Use [`Self::parse`] or [`str::parse`] to validate
+        /// input instead.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Parses a dashed UUID, 32 hex characters or a Notion URL.
+            pub fn parse(value: &str) -> Result<Self, NotionClientError> {
+                let value = value.trim();
+                if let Some(id) = normalize(value) {
+                    return Ok(Self(id));
+                }
+                let url = NotionUrl::parse(value)?;
+                let from_url: fn(NotionUrl) -> String = $from_url;
+                Ok(Self(from_url(url)))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// The id without dashes, as used in notion.so URLs.
+            pub fn simple(&self) -> String {
+                self.0.replace('-', "")
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = NotionClientError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        /// Parses `value` like [`Self::parse`], keeping it unchanged on failure.
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::parse(&value).unwrap_or(Self(value))
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(value: &String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        /// Parses `value` like [`Self::parse`], keeping it unchanged on failure.
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self::parse(value).unwrap_or_else(|_| Self(value.to_string()))
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                normalize(other).as_deref().unwrap_or(other) == self.0
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self == *other
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+macro_rules! id_conversion {
+    ($from:ident => $to:ident) => {
+        impl From<$from> for $to {
+            fn from(value: $from) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl From<&$from> for $to {
+            fn from(value: &$from) -> Self {
+                Self(value.0.clone())
+            }
+        }
+    };
+}
+
+id_type!(PageId, |url| url.peek.unwrap_or(url.id));
+id_type!(BlockId, |url| url.block.unwrap_or(url.id));
+id_type!(DatabaseId, |url| url.id);
+id_type!(UserId, |url| url.id);
+id_type!(CommentId, |url| url.id);
+
+// Pages and databases are blocks, so their ids are accepted wherever a block id is.
+id_conversion!(PageId => BlockId);
+id_conversion!(DatabaseId => BlockId);
+
+impl NotionUrl {
+    pub fn parse(url: &str) -> Result<Self, NotionClientError> {
+        let invalid = || NotionClientError::InvalidId {
+            value: url.to_string(),
+        };
+
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .unwrap_or(url);
+        let (rest, fragment) = match rest.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let (path, query) = match rest.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (rest, None),
+        };
+
+        let (host, path) = path.split_once('/').ok_or_else(invalid)?;
+        if !(host == "notion.so" || host.ends_with(".notion.so") || host.ends_with(".notion.site"))
+        {
+            return Err(invalid());
+        }
+
+        let segment = path
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+        let id = id_suffix(segment).ok_or_else(invalid)?;
+
+        let mut view = None;
+        let mut peek = None;
+        for (key, value) in query
+            .into_iter()
+            .flat_map(|q| q.split('&'))
+            .filter_map(|pair| pair.split_once('='))
+        {
+            match key {
+                "v" => view = normalize(value),
+                "p" => peek = normalize(value),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            id,
+            view,
+            peek,
+            block: fragment.and_then(normalize),
+        })
+    }
+}
+
+/// Returns the canonical dashed, lowercase form of a raw or dashed UUID.
+fn normalize(value: &str) -> Option<String> {
+    let hex: String = match value.len() {
+        32 => value.to_string(),
+        36 if [8, 13, 18, 23].iter().all(|&i| value.as_bytes()[i] == b'-') => {
+            value.replace('-', "")
+        }
+        _ => return None,
+    };
+    if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let hex = hex.to_ascii_lowercase();
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// Finds the id at the end of a URL path segment such as `My-Page-<32 hex>`.
+fn id_suffix(segment: &str) -> Option<String> {
+    [36, 32].iter().find_map(|&len| {
+        let start = segment.len().checked_sub(len)?;
+        if start > 0 && !segment.get(..start)?.ends_with('-') {
+            return None;
+        }
+        normalize(segment.get(start..)?)
+    })
+}
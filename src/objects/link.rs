@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use super::{
+    block::{Block, BlockType},
+    comment::Comment,
+    database::Database,
+    id::{BlockId, DatabaseId, PageId},
+    page::Page,
+    parent::Parent,
+};
+
+const NOTION_WEB_URI: &str = "https://www.notion.so";
+
+impl PageId {
+    pub fn url(&self) -> String {
+        format!("{}/{}", NOTION_WEB_URI, self.simple())
+    }
+}
+
+impl DatabaseId {
+    pub fn url(&self) -> String {
+        format!("{}/{}", NOTION_WEB_URI, self.simple())
+    }
+
+    pub fn view_url(&self, view_id: &str) -> String {
+        with_view(self.url(), view_id)
+    }
+}
+
+impl BlockId {
+    /// Links to the block itself; notion.so redirects to its page and scrolls to it.
+    pub fn url(&self) -> String {
+        format!("{}/{}", NOTION_WEB_URI, self.simple())
+    }
+
+    pub fn url_in(&self, page_id: &PageId) -> String {
+        with_anchor(page_id.url(), self)
+    }
+}
+
+impl Page {
+    pub fn link(&self) -> String {
+        if self.url.is_empty() {
+            self.id.url()
+        } else {
+            self.url.clone()
+        }
+    }
+
+    pub fn block_link(&self, block_id: &BlockId) -> String {
+        with_anchor(self.link(), block_id)
+    }
+}
+
+impl Database {
+    pub fn link(&self) -> Option<String> {
+        if !self.url.is_empty() {
+            return Some(self.url.clone());
+        }
+        self.id.as_ref().map(DatabaseId::url)
+    }
+
+    pub fn view_link(&self, view_id: &str) -> Option<String> {
+        self.link().map(|url| with_view(url, view_id))
+    }
+}
+
+impl Block {
+    /// The page this block is on, following its `parent` through `ancestors`,
+    /// the blocks above it or the whole tree it was retrieved with.
+    pub fn page_id(&self, ancestors: &[Block]) -> Option<PageId> {
+        let mut parent = self.parent.as_ref()?;
+        let mut visited = HashSet::new();
+        loop {
+            let block_id = match parent {
+                Parent::PageId { page_id } => return Some(page_id.clone()),
+                Parent::BlockId { block_id } => block_id,
+                _ => return None,
+            };
+            if !visited.insert(block_id) {
+                return None;
+            }
+            let block = find(ancestors, block_id)?;
+            if let BlockType::ChildPage { .. } = block.block_type {
+                return Some(PageId::from(block_id.as_str()));
+            }
+            parent = block.parent.as_ref()?;
+        }
+    }
+
+    /// Links to this block inside `page` when given, otherwise inside the page
+    /// found through [`Block::page_id`]. `None` if the page is unknown.
+    pub fn link(&self, page: Option<&Page>, ancestors: &[Block]) -> Option<String> {
+        let id = self.id.as_ref()?;
+        match page {
+            Some(page) => Some(page.block_link(id)),
+            None => self.page_id(ancestors).map(|page_id| id.url_in(&page_id)),
+        }
+    }
+}
+
+impl Comment {
+    /// Links to the comment's discussion, opened on its page or block. Comments
+    /// on blocks need the block's `page`; `None` without it.
+    pub fn discussion_link(&self, page: Option<&Page>) -> Option<String> {
+        let discussion = self.discussion_id.replace('-', "");
+        let url = match (&self.parent, page) {
+            (Parent::BlockId { block_id }, Some(page)) => {
+                with_anchor(with_query(page.link(), "d", &discussion), block_id)
+            }
+            (Parent::BlockId { .. }, None) => return None,
+            (_, Some(page)) => with_query(page.link(), "d", &discussion),
+            (Parent::PageId { page_id }, None) => with_query(page_id.url(), "d", &discussion),
+            (Parent::DatabaseId { database_id }, None) => {
+                with_query(database_id.url(), "d", &discussion)
+            }
+            _ => return None,
+        };
+        Some(url)
+    }
+}
+
+fn find<'a>(blocks: &'a [Block], id: &BlockId) -> Option<&'a Block> {
+    blocks.iter().find_map(|block| {
+        if block.id.as_ref() == Some(id) {
+            return Some(block);
+        }
+        let children = match &block.block_type {
+            BlockType::ChildPage { child_page } => child_page.children.as_deref(),
+            block_type => block_type.children().map(Vec::as_slice),
+        };
+        find(children?, id)
+    })
+}
+
+fn with_view(url: String, view_id: &str) -> String {
+    with_query(url, "v", &view_id.replace('-', ""))
+}
+
+fn with_query(url: String, key: &str, value: &str) -> String {
+    let (url, fragment) = match url.split_once('#') {
+        Some((url, fragment)) => (url.to_string(), Some(fragment.to_string())),
+        None => (url, None),
+    };
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{}{}{}={}", url, separator, key, value);
+    match fragment {
+        Some(fragment) => format!("{}#{}", url, fragment),
+        None => url,
+    }
+}
+
+fn with_anchor(url: String, block_id: &BlockId) -> String {
+    let url = url.split_once('#').map_or(url.as_str(), |(url, _)| url);
+    format!("{}#{}", url, block_id.simple())
+}
@@ -0,0 +1,722 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Number;
+
+use crate::endpoints::{
+    databases::create::request::CreateADatabaseRequest, pages::create::request::CreateAPageRequest,
+};
+
+use super::{
+    database::{self, DatabaseProperty, EmptyConfig, NumberFormat, NumberPropertyValue},
+    date::{DateOrDateTime, DatePropertyValue},
+    id::{PageId, UserId},
+    page::{FilePropertyValue, Page, PageProperty},
+    parent::Parent,
+    rich_text::{self, RichText},
+};
+
+/// Maps a struct to the properties of a database page. Usually derived with
+/// `#[derive(NotionPage)]` (feature `derive`).
+pub trait NotionPage: Sized {
+    fn from_properties(properties: &impl PropertyMap) -> Result<Self, PropertyError>;
+
+    fn to_properties(&self) -> Result<BTreeMap<String, PageProperty>, PropertyError>;
+
+    /// The database schema holding these pages. Status and relation
+    /// properties are left out, the API cannot create them without more
+    /// configuration.
+    fn database_properties() -> BTreeMap<String, DatabaseProperty>;
+
+    fn from_page(page: &Page) -> Result<Self, PropertyError> {
+        Self::from_properties(&page.properties)
+    }
+
+    fn create_page_request(&self, parent: Parent) -> Result<CreateAPageRequest, PropertyError> {
+        Ok(CreateAPageRequest {
+            parent,
+            properties: self.to_properties()?,
+            ..Default::default()
+        })
+    }
+
+    fn create_database_request(parent: Parent, title: impl Into<String>) -> CreateADatabaseRequest {
+        CreateADatabaseRequest {
+            parent,
+            title: Some(vec![RichText::text(title)]),
+            properties: Self::database_properties(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Page properties keyed by name, as found in `Page::properties` and page requests.
+pub trait PropertyMap {
+    fn get_property(&self, name: &str) -> Option<&PageProperty>;
+}
+
+impl PropertyMap for HashMap<String, PageProperty> {
+    fn get_property(&self, name: &str) -> Option<&PageProperty> {
+        self.get(name)
+    }
+}
+
+impl PropertyMap for BTreeMap<String, PageProperty> {
+    fn get_property(&self, name: &str) -> Option<&PageProperty> {
+        self.get(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PropertyError {
+    #[error("Property `{}` is missing or empty, expected {}", property, expected)]
+    Missing {
+        property: String,
+        expected: &'static str,
+    },
+
+    #[error("Property `{}` is {}, expected {}", property, found, expected)]
+    WrongType {
+        property: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    #[error("Property `{}` has an invalid value: {}", property, message)]
+    Invalid { property: String, message: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropertyKind {
+    Title,
+    RichText,
+    Number,
+    Select,
+    MultiSelect,
+    Status,
+    Date,
+    Checkbox,
+    Url,
+    Email,
+    PhoneNumber,
+    People,
+    Relation,
+    Files,
+}
+
+impl PropertyKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            PropertyKind::Title => "title",
+            PropertyKind::RichText => "rich_text",
+            PropertyKind::Number => "number",
+            PropertyKind::Select => "select",
+            PropertyKind::MultiSelect => "multi_select",
+            PropertyKind::Status => "status",
+            PropertyKind::Date => "date",
+            PropertyKind::Checkbox => "checkbox",
+            PropertyKind::Url => "url",
+            PropertyKind::Email => "email",
+            PropertyKind::PhoneNumber => "phone_number",
+            PropertyKind::People => "people",
+            PropertyKind::Relation => "relation",
+            PropertyKind::Files => "files",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_KINDS.iter().copied().find(|kind| kind.name() == name)
+    }
+
+    /// The kind of a writable database property; computed ones such as formulas have none.
+    pub fn of(property: &DatabaseProperty) -> Option<Self> {
+        Some(match property {
+            DatabaseProperty::Title { .. } => PropertyKind::Title,
+            DatabaseProperty::RichText { .. } => PropertyKind::RichText,
+            DatabaseProperty::Number { .. } => PropertyKind::Number,
+            DatabaseProperty::Select { .. } => PropertyKind::Select,
+            DatabaseProperty::MultiSelect { .. } => PropertyKind::MultiSelect,
+            DatabaseProperty::Status { .. } => PropertyKind::Status,
+            DatabaseProperty::Date { .. } => PropertyKind::Date,
+            DatabaseProperty::Checkbox { .. } => PropertyKind::Checkbox,
+            DatabaseProperty::Url { .. } => PropertyKind::Url,
+            DatabaseProperty::Email { .. } => PropertyKind::Email,
+            DatabaseProperty::PhoneNumber { .. } => PropertyKind::PhoneNumber,
+            DatabaseProperty::People { .. } => PropertyKind::People,
+            DatabaseProperty::Relation { .. } => PropertyKind::Relation,
+            DatabaseProperty::Files { .. } => PropertyKind::Files,
+            _ => return None,
+        })
+    }
+
+    /// Used by `#[derive(NotionPage)]` to reject unsupported field types at compile time.
+    pub const fn is_in(self, kinds: &[PropertyKind]) -> bool {
+        let mut i = 0;
+        while i < kinds.len() {
+            if kinds[i] as u8 == self as u8 {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    pub fn database_property(self) -> Option<DatabaseProperty> {
+        Some(match self {
+            PropertyKind::Title => DatabaseProperty::Title {
+                id: None,
+                name: None,
+                title: EmptyConfig {},
+            },
+            PropertyKind::RichText => DatabaseProperty::RichText {
+                id: None,
+                name: None,
+                rich_text: EmptyConfig {},
+            },
+            PropertyKind::Number => DatabaseProperty::Number {
+                id: None,
+                name: None,
+                number: NumberPropertyValue {
+                    format: NumberFormat::Number,
+                },
+            },
+            PropertyKind::Select => DatabaseProperty::Select {
+                id: None,
+                name: None,
+                select: database::SelectPropertyValue { options: vec![] },
+            },
+            PropertyKind::MultiSelect => DatabaseProperty::MultiSelect {
+                id: None,
+                name: None,
+                multi_select: database::SelectPropertyValue { options: vec![] },
+            },
+            PropertyKind::Date => DatabaseProperty::Date {
+                id: None,
+                name: None,
+                date: EmptyConfig {},
+            },
+            PropertyKind::Checkbox => DatabaseProperty::Checkbox {
+                id: None,
+                name: None,
+                checkbox: EmptyConfig {},
+            },
+            PropertyKind::Url => DatabaseProperty::Url {
+                id: None,
+                name: None,
+                url: EmptyConfig {},
+            },
+            PropertyKind::Email => DatabaseProperty::Email {
+                id: None,
+                name: None,
+                email: EmptyConfig {},
+            },
+            PropertyKind::PhoneNumber => DatabaseProperty::PhoneNumber {
+                id: None,
+                name: None,
+                phone_number: EmptyConfig {},
+            },
+            PropertyKind::People => DatabaseProperty::People {
+                id: None,
+                name: None,
+                people: EmptyConfig {},
+            },
+            PropertyKind::Files => DatabaseProperty::Files {
+                id: None,
+                name: None,
+                files: EmptyConfig {},
+            },
+            PropertyKind::Status | PropertyKind::Relation => return None,
+        })
+    }
+}
+
+const ALL_KINDS: [PropertyKind; 14] = [
+    PropertyKind::Title,
+    PropertyKind::RichText,
+    PropertyKind::Number,
+    PropertyKind::Select,
+    PropertyKind::MultiSelect,
+    PropertyKind::Status,
+    PropertyKind::Date,
+    PropertyKind::Checkbox,
+    PropertyKind::Url,
+    PropertyKind::Email,
+    PropertyKind::PhoneNumber,
+    PropertyKind::People,
+    PropertyKind::Relation,
+    PropertyKind::Files,
+];
+
+impl PageProperty {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PageProperty::Checkbox { .. } => "checkbox",
+            PageProperty::CreatedBy { .. } => "created_by",
+            PageProperty::CreatedTime { .. } => "created_time",
+            PageProperty::Date { .. } => "date",
+            PageProperty::Email { .. } => "email",
+            PageProperty::Files { .. } => "files",
+            PageProperty::Formula { .. } => "formula",
+            PageProperty::LastEditedBy { .. } => "last_edited_by",
+            PageProperty::LastEditedTime { .. } => "last_edited_time",
+            PageProperty::MultiSelect { .. } => "multi_select",
+            PageProperty::Number { .. } => "number",
+            PageProperty::People { .. } => "people",
+            PageProperty::PhoneNumber { .. } => "phone_number",
+            PageProperty::Relation { .. } => "relation",
+            PageProperty::Rollup { .. } => "rollup",
+            PageProperty::RichText { .. } => "rich_text",
+            PageProperty::Select { .. } => "select",
+            PageProperty::Status { .. } => "status",
+            PageProperty::Title { .. } => "title",
+            PageProperty::Url { .. } => "url",
+            PageProperty::UniqueID { .. } => "unique_id",
+            PageProperty::Verification { .. } => "verification",
+            PageProperty::Button { .. } => "button",
+        }
+    }
+
+    /// Whether the property holds no value, e.g. an unset select or empty text.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            PageProperty::Date { date, .. } => date.is_none(),
+            PageProperty::Email { email, .. } => email.is_none(),
+            PageProperty::Files { files, .. } => files.is_empty(),
+            PageProperty::Formula { formula, .. } => formula.is_none(),
+            PageProperty::LastEditedTime {
+                last_edited_time, ..
+            } => last_edited_time.is_none(),
+            PageProperty::MultiSelect { multi_select, .. } => multi_select.is_empty(),
+            PageProperty::Number { number, .. } => number.is_none(),
+            PageProperty::People { people, .. } => people.is_empty(),
+            PageProperty::PhoneNumber { phone_number, .. } => phone_number.is_none(),
+            PageProperty::Relation { relation, .. } => relation.is_empty(),
+            PageProperty::Rollup { rollup, .. } => rollup.is_none(),
+            PageProperty::RichText { rich_text, .. } => rich_text.is_empty(),
+            PageProperty::Select { select, .. } => select.is_none(),
+            PageProperty::Status { status, .. } => status.is_none(),
+            PageProperty::Title { title, .. } => title.is_empty(),
+            PageProperty::Url { url, .. } => url.is_none(),
+            PageProperty::UniqueID { unique_id, .. } => unique_id.is_none(),
+            PageProperty::Verification { verification, .. } => verification.is_none(),
+            PageProperty::Checkbox { .. }
+            | PageProperty::CreatedBy { .. }
+            | PageProperty::CreatedTime { .. }
+            | PageProperty::LastEditedBy { .. }
+            | PageProperty::Button { .. } => false,
+        }
+    }
+}
+
+/// A Rust type stored in a page property. `KINDS` lists the property types
+/// it can be read from and written to, the first being the default.
+pub trait PropertyValue: Sized {
+    const KINDS: &'static [PropertyKind];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError>;
+
+    fn to_property(&self, name: &str, kind: PropertyKind) -> Result<PageProperty, PropertyError>;
+}
+
+fn required<'a>(
+    name: &str,
+    property: Option<&'a PageProperty>,
+    kind: PropertyKind,
+) -> Result<&'a PageProperty, PropertyError> {
+    match property {
+        Some(property) if property.type_name() != kind.name() => Err(PropertyError::WrongType {
+            property: name.to_string(),
+            expected: kind.name(),
+            found: property.type_name(),
+        }),
+        Some(property) if !property.is_empty() || kind == PropertyKind::Title => Ok(property),
+        _ => Err(PropertyError::Missing {
+            property: name.to_string(),
+            expected: kind.name(),
+        }),
+    }
+}
+
+impl<T: PropertyValue> PropertyValue for Option<T> {
+    const KINDS: &'static [PropertyKind] = T::KINDS;
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match property {
+            None => Ok(None),
+            Some(p) if p.type_name() == kind.name() && p.is_empty() => Ok(None),
+            Some(_) => T::from_property(name, property, kind).map(Some),
+        }
+    }
+
+    fn to_property(&self, name: &str, kind: PropertyKind) -> Result<PageProperty, PropertyError> {
+        match self {
+            Some(value) => value.to_property(name, kind),
+            None => Ok(PageProperty::cleared(kind)),
+        }
+    }
+}
+
+impl PropertyValue for String {
+    const KINDS: &'static [PropertyKind] = &[
+        PropertyKind::RichText,
+        PropertyKind::Title,
+        PropertyKind::Select,
+        PropertyKind::Status,
+        PropertyKind::Url,
+        PropertyKind::Email,
+        PropertyKind::PhoneNumber,
+    ];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        let missing = || PropertyError::Missing {
+            property: name.to_string(),
+            expected: kind.name(),
+        };
+        match required(name, property, kind)? {
+            PageProperty::Title { title: rt, .. }
+            | PageProperty::RichText { rich_text: rt, .. } => Ok(rich_text::plain_text(rt)),
+            PageProperty::Select {
+                select: Some(value),
+                ..
+            }
+            | PageProperty::Status {
+                status: Some(value),
+                ..
+            } => value.name.clone().ok_or_else(missing),
+            PageProperty::Url { url: Some(s), .. }
+            | PageProperty::Email { email: Some(s), .. }
+            | PageProperty::PhoneNumber {
+                phone_number: Some(s),
+                ..
+            } => Ok(s.clone()),
+            _ => Err(missing()),
+        }
+    }
+
+    fn to_property(&self, _: &str, kind: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(match kind {
+            PropertyKind::Title => PageProperty::title(self),
+            PropertyKind::Select => PageProperty::select(self),
+            PropertyKind::Status => PageProperty::status(self),
+            PropertyKind::Url => PageProperty::url(self),
+            PropertyKind::Email => PageProperty::email(self),
+            PropertyKind::PhoneNumber => PageProperty::phone_number(self),
+            _ => PageProperty::rich_text(self),
+        })
+    }
+}
+
+impl PropertyValue for Vec<RichText> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::RichText, PropertyKind::Title];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match required(name, property, kind)? {
+            PageProperty::Title { title: rt, .. }
+            | PageProperty::RichText { rich_text: rt, .. } => Ok(rt.clone()),
+            _ => unreachable!("`required` checked the property type"),
+        }
+    }
+
+    fn to_property(&self, _: &str, kind: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(match kind {
+            PropertyKind::Title => PageProperty::title(self.clone()),
+            _ => PageProperty::rich_text(self.clone()),
+        })
+    }
+}
+
+impl PropertyValue for bool {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Checkbox];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match required(name, property, kind)? {
+            PageProperty::Checkbox { checkbox, .. } => Ok(*checkbox),
+            _ => unreachable!("`required` checked the property type"),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::checkbox(*self))
+    }
+}
+
+fn number<'a>(
+    name: &str,
+    property: Option<&'a PageProperty>,
+    kind: PropertyKind,
+) -> Result<&'a Number, PropertyError> {
+    match required(name, property, kind)? {
+        PageProperty::Number {
+            number: Some(number),
+            ..
+        } => Ok(number),
+        _ => unreachable!("`required` checked the property type"),
+    }
+}
+
+macro_rules! integer_property {
+    ($($ty:ty => $as:ident),*) => {
+        $(
+            impl PropertyValue for $ty {
+                const KINDS: &'static [PropertyKind] = &[PropertyKind::Number];
+
+                fn from_property(
+                    name: &str,
+                    property: Option<&PageProperty>,
+                    kind: PropertyKind,
+                ) -> Result<Self, PropertyError> {
+                    let number = number(name, property, kind)?;
+                    number
+                        .$as()
+                        .and_then(|n| <$ty>::try_from(n).ok())
+                        .ok_or_else(|| PropertyError::Invalid {
+                            property: name.to_string(),
+                            message: format!("{} does not fit in {}", number, stringify!($ty)),
+                        })
+                }
+
+                fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+                    Ok(PageProperty::number(*self))
+                }
+            }
+        )*
+    };
+}
+
+integer_property!(
+    i8 => as_i64, i16 => as_i64, i32 => as_i64, i64 => as_i64, isize => as_i64,
+    u8 => as_u64, u16 => as_u64, u32 => as_u64, u64 => as_u64, usize => as_u64
+);
+
+impl PropertyValue for f64 {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Number];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        let number = number(name, property, kind)?;
+        number.as_f64().ok_or_else(|| PropertyError::Invalid {
+            property: name.to_string(),
+            message: format!("{} is not a finite number", number),
+        })
+    }
+
+    fn to_property(&self, name: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        PageProperty::try_number(*self).map_err(|_| PropertyError::Invalid {
+            property: name.to_string(),
+            message: format!("{} is not a finite number", self),
+        })
+    }
+}
+
+impl PropertyValue for f32 {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Number];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        f64::from_property(name, property, kind).map(|n| n as f32)
+    }
+
+    fn to_property(&self, name: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        PageProperty::try_number(*self).map_err(|_| PropertyError::Invalid {
+            property: name.to_string(),
+            message: format!("{} is not a finite number", self),
+        })
+    }
+}
+
+impl PropertyValue for Number {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Number];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        number(name, property, kind).cloned()
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::number(self.clone()))
+    }
+}
+
+impl PropertyValue for DatePropertyValue {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Date];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match required(name, property, kind)? {
+            PageProperty::Date {
+                date: Some(date), ..
+            } => Ok(date.clone()),
+            _ => unreachable!("`required` checked the property type"),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::Date {
+            id: None,
+            date: Some(self.clone()),
+        })
+    }
+}
+
+fn date_start(
+    name: &str,
+    property: Option<&PageProperty>,
+    kind: PropertyKind,
+) -> Result<DateOrDateTime, PropertyError> {
+    DatePropertyValue::from_property(name, property, kind)?
+        .start
+        .ok_or_else(|| PropertyError::Missing {
+            property: name.to_string(),
+            expected: kind.name(),
+        })
+}
+
+impl PropertyValue for NaiveDate {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Date];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        date_start(name, property, kind).map(|start| start.date())
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::date(*self, None))
+    }
+}
+
+impl PropertyValue for DateTime<Utc> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Date];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        Ok(match date_start(name, property, kind)? {
+            DateOrDateTime::DateTime(date_time) => date_time,
+            DateOrDateTime::Date(date) => date.and_time(Default::default()).and_utc(),
+        })
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::date(*self, None))
+    }
+}
+
+impl PropertyValue for Vec<String> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::MultiSelect];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match property {
+            Some(PageProperty::MultiSelect { multi_select, .. }) => Ok(multi_select
+                .iter()
+                .filter_map(|option| option.name.clone())
+                .collect()),
+            _ => required(name, property, kind).map(|_| vec![]),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::multi_select(self))
+    }
+}
+
+impl PropertyValue for Vec<PageId> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Relation];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match property {
+            Some(PageProperty::Relation { relation, .. }) => {
+                Ok(relation.iter().map(|r| r.id.clone()).collect())
+            }
+            _ => required(name, property, kind).map(|_| vec![]),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::relation(self.iter().cloned()))
+    }
+}
+
+impl PropertyValue for Vec<UserId> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::People];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match property {
+            Some(PageProperty::People { people, .. }) => {
+                Ok(people.iter().map(|user| user.id.clone()).collect())
+            }
+            _ => required(name, property, kind).map(|_| vec![]),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::people(self.iter().cloned()))
+    }
+}
+
+impl PropertyValue for Vec<FilePropertyValue> {
+    const KINDS: &'static [PropertyKind] = &[PropertyKind::Files];
+
+    fn from_property(
+        name: &str,
+        property: Option<&PageProperty>,
+        kind: PropertyKind,
+    ) -> Result<Self, PropertyError> {
+        match property {
+            Some(PageProperty::Files { files, .. }) => Ok(files.clone()),
+            _ => required(name, property, kind).map(|_| vec![]),
+        }
+    }
+
+    fn to_property(&self, _: &str, _: PropertyKind) -> Result<PageProperty, PropertyError> {
+        Ok(PageProperty::files(self.clone()))
+    }
+}
@@ -1,22 +1,28 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Number;
 use serde_with::skip_serializing_none;
 
-use super::{emoji::Emoji, file::File, parent::Parent, rich_text::RichText, user::User};
+use super::{
+    color, date, file::File, icon, id::PageId, parent::Parent, rich_text::RichText, user::User,
+};
+
+pub mod accessors;
+pub mod builder;
+pub mod row;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct Page {
-    pub id: String,
+    pub id: PageId,
     pub created_time: DateTime<Utc>,
     pub created_by: User,
     pub last_edited_time: DateTime<Utc>,
     pub last_edited_by: User,
     pub archived: bool,
-    pub icon: Option<Icon>,
+    pub icon: Option<icon::Icon>,
     pub cover: Option<File>,
     pub properties: HashMap<String, PageProperty>,
     pub parent: Parent,
@@ -24,12 +30,8 @@ pub struct Page {
     pub public_url: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-#[serde(rename_all = "snake_case", untagged)]
-pub enum Icon {
-    File(File),
-    Emoji(Emoji),
-}
+#[deprecated(note = "use `objects::icon::Icon` instead")]
+pub type Icon = super::icon::Icon;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -49,10 +51,12 @@ pub enum PageProperty {
     },
     Date {
         id: Option<String>,
-        date: Option<DatePropertyValue>,
+        #[serialize_always]
+        date: Option<date::DatePropertyValue>,
     },
     Email {
         id: Option<String>,
+        #[serialize_always]
         email: Option<String>,
     },
     Files {
@@ -77,6 +81,7 @@ pub enum PageProperty {
     },
     Number {
         id: Option<String>,
+        #[serialize_always]
         number: Option<Number>,
     },
     People {
@@ -85,6 +90,7 @@ pub enum PageProperty {
     },
     PhoneNumber {
         id: Option<String>,
+        #[serialize_always]
         phone_number: Option<String>,
     },
     Relation {
@@ -102,10 +108,12 @@ pub enum PageProperty {
     },
     Select {
         id: Option<String>,
+        #[serialize_always]
         select: Option<SelectPropertyValue>,
     },
     Status {
         id: Option<String>,
+        #[serialize_always]
         status: Option<SelectPropertyValue>,
     },
     Title {
@@ -114,6 +122,7 @@ pub enum PageProperty {
     },
     Url {
         id: Option<String>,
+        #[serialize_always]
         url: Option<String>,
     },
     #[serde(rename = "unique_id")]
@@ -130,47 +139,35 @@ pub enum PageProperty {
     },
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-#[serde(rename_all = "snake_case")]
-pub enum Color {
-    Default,
-    Gray,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Purple,
-    Pink,
-}
+#[deprecated(note = "use `objects::color::Color` instead")]
+pub type Color = super::color::Color;
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-pub struct DatePropertyValue {
-    pub start: Option<DateOrDateTime>,
-    pub end: Option<DateOrDateTime>,
-    pub time_zone: Option<String>,
-}
+#[deprecated(note = "use `objects::date::DatePropertyValue` instead")]
+pub type DatePropertyValue = super::date::DatePropertyValue;
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-#[serde(untagged)]
-pub enum DateOrDateTime {
-    Date(NaiveDate),
-    DateTime(DateTime<Utc>),
-}
+#[deprecated(note = "use `objects::date::DateOrDateTime` instead")]
+pub type DateOrDateTime = super::date::DateOrDateTime;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FormulaPropertyValue {
-    String { string: Option<String> },
-    Number { number: Option<Number> },
-    Boolean { boolean: bool },
-    Date { date: Option<DatePropertyValue> },
+    String {
+        string: Option<String>,
+    },
+    Number {
+        number: Option<Number>,
+    },
+    Boolean {
+        boolean: bool,
+    },
+    Date {
+        date: Option<date::DatePropertyValue>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct RelationPropertyValue {
-    pub id: String,
+    pub id: PageId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -239,7 +236,7 @@ pub struct FilePropertyValue {
 pub struct SelectPropertyValue {
     pub id: Option<String>,
     pub name: Option<String>,
-    pub color: Option<Color>,
+    pub color: Option<color::Color>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -254,7 +251,7 @@ pub struct UniqueIDPropertyValue {
 pub struct VerificationPropertyValue {
     pub state: VerificationState,
     pub verified_by: Option<User>,
-    pub date: Option<DatePropertyValue>,
+    pub date: Option<date::DatePropertyValue>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -0,0 +1,253 @@
+use std::fmt;
+
+use crate::objects::{
+    date::DatePropertyValue,
+    id::PageId,
+    notion_page::PropertyError,
+    rich_text::{self, RichText},
+    user::User,
+};
+
+use super::{Page, PageProperty, SelectPropertyValue};
+
+/// Identifies a property of a page, by name or by its id. `&str` converts to a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyKey<'a> {
+    Name(&'a str),
+    /// Property ids may be given URL-encoded, as returned by the API, or decoded.
+    Id(&'a str),
+}
+
+impl<'a> PropertyKey<'a> {
+    pub fn name(name: &'a str) -> Self {
+        PropertyKey::Name(name)
+    }
+
+    pub fn id(id: &'a str) -> Self {
+        PropertyKey::Id(id)
+    }
+}
+
+impl<'a> From<&'a str> for PropertyKey<'a> {
+    fn from(name: &'a str) -> Self {
+        PropertyKey::Name(name)
+    }
+}
+
+impl<'a> From<&'a String> for PropertyKey<'a> {
+    fn from(name: &'a String) -> Self {
+        PropertyKey::Name(name)
+    }
+}
+
+impl fmt::Display for PropertyKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyKey::Name(name) => f.write_str(name),
+            PropertyKey::Id(id) => write!(f, "id:{}", id),
+        }
+    }
+}
+
+impl PageProperty {
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            PageProperty::Checkbox { id, .. }
+            | PageProperty::CreatedBy { id, .. }
+            | PageProperty::CreatedTime { id, .. }
+            | PageProperty::Date { id, .. }
+            | PageProperty::Email { id, .. }
+            | PageProperty::Files { id, .. }
+            | PageProperty::Formula { id, .. }
+            | PageProperty::LastEditedBy { id, .. }
+            | PageProperty::LastEditedTime { id, .. }
+            | PageProperty::MultiSelect { id, .. }
+            | PageProperty::Number { id, .. }
+            | PageProperty::People { id, .. }
+            | PageProperty::PhoneNumber { id, .. }
+            | PageProperty::Relation { id, .. }
+            | PageProperty::Rollup { id, .. }
+            | PageProperty::RichText { id, .. }
+            | PageProperty::Select { id, .. }
+            | PageProperty::Status { id, .. }
+            | PageProperty::Title { id, .. }
+            | PageProperty::Url { id, .. }
+            | PageProperty::UniqueID { id, .. }
+            | PageProperty::Verification { id, .. }
+            | PageProperty::Button { id, .. } => id.as_deref(),
+        }
+    }
+}
+
+impl Page {
+    pub fn property<'a>(&self, key: impl Into<PropertyKey<'a>>) -> Option<&PageProperty> {
+        match key.into() {
+            PropertyKey::Name(name) => self.properties.get(name),
+            PropertyKey::Id(id) => self.properties.values().find(|property| {
+                property.id().is_some_and(|property_id| {
+                    property_id == id
+                        || urlencoding::decode(property_id).is_ok_and(|decoded| decoded == id)
+                })
+            }),
+        }
+    }
+
+    /// The page's title property, whatever its name.
+    pub fn title(&self) -> Result<&[RichText], PropertyError> {
+        self.properties
+            .values()
+            .find_map(|property| match property {
+                PageProperty::Title { title, .. } => Some(title.as_slice()),
+                _ => None,
+            })
+            .ok_or_else(|| PropertyError::Missing {
+                property: "title".to_string(),
+                expected: "title",
+            })
+    }
+
+    pub fn title_text(&self) -> Result<String, PropertyError> {
+        self.title().map(rich_text::plain_text)
+    }
+
+    pub fn rich_text<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<&[RichText], PropertyError> {
+        self.get(key.into(), "rich_text", |property| match property {
+            PageProperty::RichText { rich_text, .. } => Some(rich_text.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Plain text of a title or rich text property.
+    pub fn text<'a>(&self, key: impl Into<PropertyKey<'a>>) -> Result<String, PropertyError> {
+        self.get(key.into(), "rich_text", |property| match property {
+            PageProperty::Title { title: rt, .. }
+            | PageProperty::RichText { rich_text: rt, .. } => Some(rich_text::plain_text(rt)),
+            _ => None,
+        })
+    }
+
+    pub fn number<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<f64>, PropertyError> {
+        self.get(key.into(), "number", |property| match property {
+            PageProperty::Number { number, .. } => Some(number.as_ref().and_then(|n| n.as_f64())),
+            _ => None,
+        })
+    }
+
+    pub fn date<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<&DatePropertyValue>, PropertyError> {
+        self.get(key.into(), "date", |property| match property {
+            PageProperty::Date { date, .. } => Some(date.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn select<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<&SelectPropertyValue>, PropertyError> {
+        self.get(key.into(), "select", |property| match property {
+            PageProperty::Select { select, .. } => Some(select.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn status<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<&SelectPropertyValue>, PropertyError> {
+        self.get(key.into(), "status", |property| match property {
+            PageProperty::Status { status, .. } => Some(status.as_ref()),
+            _ => None,
+        })
+    }
+
+    pub fn multi_select<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<&[SelectPropertyValue], PropertyError> {
+        self.get(key.into(), "multi_select", |property| match property {
+            PageProperty::MultiSelect { multi_select, .. } => Some(multi_select.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Ids of the related pages. Relations with more than 25 pages are
+    /// truncated, see `retrieve_a_page_property_item` for the full list.
+    pub fn relation_ids<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Vec<PageId>, PropertyError> {
+        self.get(key.into(), "relation", |property| match property {
+            PageProperty::Relation { relation, .. } => {
+                Some(relation.iter().map(|r| r.id.clone()).collect())
+            }
+            _ => None,
+        })
+    }
+
+    pub fn people<'a>(&self, key: impl Into<PropertyKey<'a>>) -> Result<&[User], PropertyError> {
+        self.get(key.into(), "people", |property| match property {
+            PageProperty::People { people, .. } => Some(people.as_slice()),
+            _ => None,
+        })
+    }
+
+    pub fn checkbox<'a>(&self, key: impl Into<PropertyKey<'a>>) -> Result<bool, PropertyError> {
+        self.get(key.into(), "checkbox", |property| match property {
+            PageProperty::Checkbox { checkbox, .. } => Some(*checkbox),
+            _ => None,
+        })
+    }
+
+    pub fn url<'a>(&self, key: impl Into<PropertyKey<'a>>) -> Result<Option<&str>, PropertyError> {
+        self.get(key.into(), "url", |property| match property {
+            PageProperty::Url { url, .. } => Some(url.as_deref()),
+            _ => None,
+        })
+    }
+
+    pub fn email<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<&str>, PropertyError> {
+        self.get(key.into(), "email", |property| match property {
+            PageProperty::Email { email, .. } => Some(email.as_deref()),
+            _ => None,
+        })
+    }
+
+    pub fn phone_number<'a>(
+        &self,
+        key: impl Into<PropertyKey<'a>>,
+    ) -> Result<Option<&str>, PropertyError> {
+        self.get(key.into(), "phone_number", |property| match property {
+            PageProperty::PhoneNumber { phone_number, .. } => Some(phone_number.as_deref()),
+            _ => None,
+        })
+    }
+
+    fn get<'s, T>(
+        &'s self,
+        key: PropertyKey,
+        expected: &'static str,
+        value: impl FnOnce(&'s PageProperty) -> Option<T>,
+    ) -> Result<T, PropertyError> {
+        let property = self.property(key).ok_or_else(|| PropertyError::Missing {
+            property: key.to_string(),
+            expected,
+        })?;
+        value(property).ok_or_else(|| PropertyError::WrongType {
+            property: key.to_string(),
+            expected,
+            found: property.type_name(),
+        })
+    }
+}
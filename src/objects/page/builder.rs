@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+
+use serde_json::Number;
+
+use crate::{
+    objects::{
+        block::builder::IntoRichText,
+        date::{DateOrDateTime, DatePropertyValue},
+        file::{ExternalFile, File},
+        id::{PageId, UserId},
+        notion_page::PropertyKind,
+        user::User,
+    },
+    NotionClientError,
+};
+
+use super::{FilePropertyValue, PageProperty, RelationPropertyValue, SelectPropertyValue};
+
+/// Numbers accepted by [`PageProperty::try_number`]. Floats that are not finite
+/// have no JSON representation and are rejected.
+pub trait IntoNumber {
+    fn into_number(self) -> Result<Number, NotionClientError>;
+}
+
+macro_rules! into_number {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoNumber for $ty {
+                fn into_number(self) -> Result<Number, NotionClientError> {
+                    Ok(Number::from(self))
+                }
+            }
+        )*
+    };
+}
+
+into_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl IntoNumber for f64 {
+    fn into_number(self) -> Result<Number, NotionClientError> {
+        Number::from_f64(self).ok_or(NotionClientError::InvalidNumber {
+            value: self.to_string(),
+        })
+    }
+}
+
+impl IntoNumber for f32 {
+    fn into_number(self) -> Result<Number, NotionClientError> {
+        f64::from(self).into_number()
+    }
+}
+
+impl IntoNumber for Number {
+    fn into_number(self) -> Result<Number, NotionClientError> {
+        Ok(self)
+    }
+}
+
+impl PageProperty {
+    pub fn title(text: impl IntoRichText) -> Self {
+        PageProperty::Title {
+            id: None,
+            title: text.into_rich_text(),
+        }
+    }
+
+    pub fn rich_text(text: impl IntoRichText) -> Self {
+        PageProperty::RichText {
+            id: None,
+            rich_text: text.into_rich_text(),
+        }
+    }
+
+    /// Integers and [`Number`]s, which are always finite. Floats go through
+    /// [`PageProperty::try_number`].
+    pub fn number(number: impl Into<Number>) -> Self {
+        PageProperty::Number {
+            id: None,
+            number: Some(number.into()),
+        }
+    }
+
+    /// Fails with [`NotionClientError::InvalidNumber`] for floats that are not
+    /// finite. Sending them as `null` would clear the property, which only
+    /// [`PageProperty::cleared`] does.
+    pub fn try_number(number: impl IntoNumber) -> Result<Self, NotionClientError> {
+        Ok(PageProperty::Number {
+            id: None,
+            number: Some(number.into_number()?),
+        })
+    }
+
+    pub fn checkbox(checked: bool) -> Self {
+        PageProperty::Checkbox {
+            id: None,
+            checkbox: checked,
+        }
+    }
+
+    /// Selects an option by name; options that do not exist yet are created.
+    pub fn select(name: impl Into<String>) -> Self {
+        PageProperty::Select {
+            id: None,
+            select: Some(SelectPropertyValue::named(name)),
+        }
+    }
+
+    pub fn status(name: impl Into<String>) -> Self {
+        PageProperty::Status {
+            id: None,
+            status: Some(SelectPropertyValue::named(name)),
+        }
+    }
+
+    pub fn multi_select<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Self {
+        PageProperty::MultiSelect {
+            id: None,
+            multi_select: names.into_iter().map(SelectPropertyValue::named).collect(),
+        }
+    }
+
+    pub fn date(start: impl Into<DateOrDateTime>, end: Option<DateOrDateTime>) -> Self {
+        PageProperty::Date {
+            id: None,
+            date: Some(DatePropertyValue::new(start, end)),
+        }
+    }
+
+    pub fn relation<I: Into<PageId>>(ids: impl IntoIterator<Item = I>) -> Self {
+        PageProperty::Relation {
+            id: None,
+            relation: ids
+                .into_iter()
+                .map(|id| RelationPropertyValue { id: id.into() })
+                .collect(),
+            has_more: None,
+        }
+    }
+
+    pub fn people<I: Into<UserId>>(ids: impl IntoIterator<Item = I>) -> Self {
+        PageProperty::People {
+            id: None,
+            people: ids
+                .into_iter()
+                .map(|id| User {
+                    object: "user".to_string(),
+                    id: id.into(),
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn files(files: impl IntoIterator<Item = FilePropertyValue>) -> Self {
+        PageProperty::Files {
+            id: None,
+            files: files.into_iter().collect(),
+        }
+    }
+
+    pub fn url(url: impl Into<String>) -> Self {
+        PageProperty::Url {
+            id: None,
+            url: Some(url.into()),
+        }
+    }
+
+    pub fn email(email: impl Into<String>) -> Self {
+        PageProperty::Email {
+            id: None,
+            email: Some(email.into()),
+        }
+    }
+
+    pub fn phone_number(phone_number: impl Into<String>) -> Self {
+        PageProperty::PhoneNumber {
+            id: None,
+            phone_number: Some(phone_number.into()),
+        }
+    }
+
+    /// A property of `kind` without a value. Sent in an update, it clears the property.
+    pub fn cleared(kind: PropertyKind) -> Self {
+        match kind {
+            PropertyKind::Title => PageProperty::title(vec![]),
+            PropertyKind::RichText => PageProperty::rich_text(vec![]),
+            PropertyKind::Number => PageProperty::Number {
+                id: None,
+                number: None,
+            },
+            PropertyKind::Select => PageProperty::Select {
+                id: None,
+                select: None,
+            },
+            PropertyKind::MultiSelect => PageProperty::multi_select(Vec::<String>::new()),
+            PropertyKind::Status => PageProperty::Status {
+                id: None,
+                status: None,
+            },
+            PropertyKind::Date => PageProperty::Date {
+                id: None,
+                date: None,
+            },
+            PropertyKind::Checkbox => PageProperty::checkbox(false),
+            PropertyKind::Url => PageProperty::Url {
+                id: None,
+                url: None,
+            },
+            PropertyKind::Email => PageProperty::Email {
+                id: None,
+                email: None,
+            },
+            PropertyKind::PhoneNumber => PageProperty::PhoneNumber {
+                id: None,
+                phone_number: None,
+            },
+            PropertyKind::People => PageProperty::people(Vec::<UserId>::new()),
+            PropertyKind::Relation => PageProperty::relation(Vec::<PageId>::new()),
+            PropertyKind::Files => PageProperty::files(vec![]),
+        }
+    }
+}
+
+impl SelectPropertyValue {
+    pub fn named(name: impl Into<String>) -> Self {
+        SelectPropertyValue {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl FilePropertyValue {
+    pub fn external(name: impl Into<String>, url: impl Into<String>) -> Self {
+        FilePropertyValue {
+            name: name.into(),
+            file: File::External {
+                external: ExternalFile { url: url.into() },
+            },
+        }
+    }
+}
+
+/// Collects named properties for `CreateAPageRequest` or `UpdatePagePropertiesRequest`.
+#[derive(Debug, Clone, Default)]
+pub struct PropertiesBuilder {
+    properties: BTreeMap<String, PageProperty>,
+}
+
+impl PropertiesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn property(mut self, name: impl Into<String>, property: PageProperty) -> Self {
+        self.properties.insert(name.into(), property);
+        self
+    }
+
+    pub fn clear(self, name: impl Into<String>, kind: PropertyKind) -> Self {
+        self.property(name, PageProperty::cleared(kind))
+    }
+
+    pub fn build(self) -> BTreeMap<String, PageProperty> {
+        self.properties
+    }
+
+    pub fn build_update(self) -> BTreeMap<String, Option<PageProperty>> {
+        self.properties
+            .into_iter()
+            .map(|(name, property)| (name, Some(property)))
+            .collect()
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{
+    de::{self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, MapAccess, Visitor},
+    forward_to_deserialize_any, ser, Deserializer, Serialize,
+};
+use serde_json::Value;
+
+use crate::objects::{
+    database::{Database, DatabaseProperty},
+    date::{DateOrDateTime, DatePropertyValue},
+    id::{PageId, UserId},
+    notion_page::{PropertyError, PropertyKind},
+    rich_text,
+};
+
+use super::{
+    FilePropertyValue, FormulaPropertyValue, Page, PageProperty, RollupPropertyValue,
+    VerificationState,
+};
+
+impl Page {
+    /// Deserializes the page's properties, flattened by [`PageProperty::to_value`],
+    /// into any `Deserialize` type. Fields are matched by property name.
+    pub fn deserialize_into<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(PropertiesDeserializer::new(&self.properties))
+    }
+}
+
+impl PageProperty {
+    /// The property's value as plain JSON:
+    /// - title and rich text become their plain text
+    /// - select and status become the option name, multi-select a list of names
+    /// - relation and people become lists of ids, files a list of URLs
+    /// - a date becomes its start, or `{ "start", "end", "time_zone" }` when it is a range
+    /// - formulas and rollups become their result
+    pub fn to_value(&self) -> Value {
+        match self {
+            PageProperty::Title { title: rt, .. }
+            | PageProperty::RichText { rich_text: rt, .. } => {
+                Value::String(rich_text::plain_text(rt))
+            }
+            PageProperty::Number { number, .. } => {
+                number.clone().map_or(Value::Null, Value::Number)
+            }
+            PageProperty::Select { select: value, .. }
+            | PageProperty::Status { status: value, .. } => value
+                .as_ref()
+                .and_then(|value| value.name.clone())
+                .map_or(Value::Null, Value::String),
+            PageProperty::MultiSelect { multi_select, .. } => multi_select
+                .iter()
+                .filter_map(|option| option.name.clone())
+                .map(Value::String)
+                .collect(),
+            PageProperty::Date { date, .. } => date_value(date.as_ref()),
+            PageProperty::Checkbox { checkbox, .. } => Value::Bool(*checkbox),
+            PageProperty::Url { url: value, .. }
+            | PageProperty::Email { email: value, .. }
+            | PageProperty::PhoneNumber {
+                phone_number: value,
+                ..
+            } => value.clone().map_or(Value::Null, Value::String),
+            PageProperty::People { people, .. } => people
+                .iter()
+                .map(|user| Value::String(user.id.to_string()))
+                .collect(),
+            PageProperty::Relation { relation, .. } => relation
+                .iter()
+                .map(|relation| Value::String(relation.id.to_string()))
+                .collect(),
+            PageProperty::Files { files, .. } => files
+                .iter()
+                .map(|file| Value::String(file.file.url().to_string()))
+                .collect(),
+            PageProperty::Formula { formula, .. } => match formula {
+                Some(FormulaPropertyValue::String { string }) => {
+                    string.clone().map_or(Value::Null, Value::String)
+                }
+                Some(FormulaPropertyValue::Number { number }) => {
+                    number.clone().map_or(Value::Null, Value::Number)
+                }
+                Some(FormulaPropertyValue::Boolean { boolean }) => Value::Bool(*boolean),
+                Some(FormulaPropertyValue::Date { date }) => date_value(date.as_ref()),
+                None => Value::Null,
+            },
+            PageProperty::Rollup { rollup, .. } => match rollup {
+                Some(RollupPropertyValue::Array { array, .. }) => {
+                    array.iter().map(PageProperty::to_value).collect()
+                }
+                Some(RollupPropertyValue::Date { date, .. }) => {
+                    date.map_or(Value::Null, |date| Value::String(date.to_rfc3339()))
+                }
+                Some(RollupPropertyValue::Number { number, .. }) => {
+                    number.clone().map_or(Value::Null, Value::Number)
+                }
+                _ => Value::Null,
+            },
+            PageProperty::CreatedTime { created_time, .. } => {
+                Value::String(created_time.to_rfc3339())
+            }
+            PageProperty::LastEditedTime {
+                last_edited_time, ..
+            } => last_edited_time.map_or(Value::Null, |time| Value::String(time.to_rfc3339())),
+            PageProperty::CreatedBy {
+                created_by: user, ..
+            }
+            | PageProperty::LastEditedBy {
+                last_edited_by: user,
+                ..
+            } => Value::String(user.id.to_string()),
+            PageProperty::UniqueID { unique_id, .. } => match unique_id {
+                Some(unique_id) => match (&unique_id.prefix, &unique_id.number) {
+                    (Some(prefix), Some(number)) => Value::String(format!("{}-{}", prefix, number)),
+                    (None, Some(number)) => Value::Number(number.clone()),
+                    _ => Value::Null,
+                },
+                None => Value::Null,
+            },
+            PageProperty::Verification { verification, .. } => match verification {
+                Some(verification) => Value::String(
+                    match verification.state {
+                        VerificationState::Verified => "verified",
+                        VerificationState::Unverified => "unverified",
+                    }
+                    .to_string(),
+                ),
+                None => Value::Null,
+            },
+            PageProperty::Button { .. } => Value::Null,
+        }
+    }
+
+    /// The inverse of [`PageProperty::to_value`] for a writable property of `kind`.
+    /// `null` clears the property.
+    pub fn from_value(name: &str, kind: PropertyKind, value: Value) -> Result<Self, PropertyError> {
+        let invalid = |value: &Value| PropertyError::Invalid {
+            property: name.to_string(),
+            message: format!("{} cannot be stored as {}", value, kind.name()),
+        };
+
+        if value.is_null() {
+            return Ok(PageProperty::cleared(kind));
+        }
+        let property = match (kind, &value) {
+            (PropertyKind::Title, Value::String(s)) => PageProperty::title(s),
+            (PropertyKind::RichText, Value::String(s)) => PageProperty::rich_text(s),
+            (PropertyKind::Number, Value::Number(n)) => PageProperty::number(n.clone()),
+            (PropertyKind::Select, Value::String(s)) => PageProperty::select(s),
+            (PropertyKind::Status, Value::String(s)) => PageProperty::status(s),
+            (PropertyKind::Checkbox, Value::Bool(b)) => PageProperty::checkbox(*b),
+            (PropertyKind::Url, Value::String(s)) => PageProperty::url(s),
+            (PropertyKind::Email, Value::String(s)) => PageProperty::email(s),
+            (PropertyKind::PhoneNumber, Value::String(s)) => PageProperty::phone_number(s),
+            (PropertyKind::Date, Value::String(_)) => {
+                let start: DateOrDateTime =
+                    serde_json::from_value(value.clone()).map_err(|_| invalid(&value))?;
+                PageProperty::date(start, None)
+            }
+            (PropertyKind::Date, Value::Object(_)) => PageProperty::Date {
+                id: None,
+                date: Some(
+                    serde_json::from_value::<DatePropertyValue>(value.clone())
+                        .map_err(|_| invalid(&value))?,
+                ),
+            },
+            (
+                PropertyKind::MultiSelect
+                | PropertyKind::People
+                | PropertyKind::Relation
+                | PropertyKind::Files,
+                Value::Array(items),
+            ) => {
+                let items = items
+                    .iter()
+                    .map(|item| item.as_str().ok_or_else(|| invalid(&value)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match kind {
+                    PropertyKind::MultiSelect => PageProperty::multi_select(items),
+                    PropertyKind::People => {
+                        PageProperty::people(items.into_iter().map(UserId::from))
+                    }
+                    PropertyKind::Relation => {
+                        PageProperty::relation(items.into_iter().map(PageId::from))
+                    }
+                    _ => PageProperty::files(
+                        items
+                            .into_iter()
+                            .map(|url| FilePropertyValue::external(url, url)),
+                    ),
+                }
+            }
+            _ => return Err(invalid(&value)),
+        };
+        Ok(property)
+    }
+}
+
+/// Database schemas usable by [`to_properties`].
+pub trait PropertySchema {
+    fn get_schema(&self, name: &str) -> Option<&DatabaseProperty>;
+}
+
+impl PropertySchema for Database {
+    fn get_schema(&self, name: &str) -> Option<&DatabaseProperty> {
+        self.properties.get(name)
+    }
+}
+
+impl PropertySchema for HashMap<String, DatabaseProperty> {
+    fn get_schema(&self, name: &str) -> Option<&DatabaseProperty> {
+        self.get(name)
+    }
+}
+
+impl PropertySchema for BTreeMap<String, DatabaseProperty> {
+    fn get_schema(&self, name: &str) -> Option<&DatabaseProperty> {
+        self.get(name)
+    }
+}
+
+/// Serializes `value` into page properties, the counterpart of
+/// [`Page::deserialize_into`]. Each field must name a writable property of `schema`.
+pub fn to_properties<T: Serialize>(
+    value: &T,
+    schema: &impl PropertySchema,
+) -> Result<BTreeMap<String, PageProperty>, serde_json::Error> {
+    let fields = match serde_json::to_value(value)? {
+        Value::Object(fields) => fields,
+        value => {
+            return Err(ser::Error::custom(format!(
+                "expected a struct or map, found {}",
+                value
+            )))
+        }
+    };
+
+    fields
+        .into_iter()
+        .map(|(name, value)| {
+            let kind = match schema.get_schema(&name) {
+                Some(property) => PropertyKind::of(property).ok_or_else(|| {
+                    ser::Error::custom(format!("property `{}` is read-only", name))
+                })?,
+                None => {
+                    return Err(ser::Error::custom(format!(
+                        "property `{}` is not in the schema",
+                        name
+                    )))
+                }
+            };
+            let property =
+                PageProperty::from_value(&name, kind, value).map_err(ser::Error::custom)?;
+            Ok((name, property))
+        })
+        .collect()
+}
+
+/// Presents page properties as a map of their flattened values.
+pub struct PropertiesDeserializer<'a> {
+    properties: Vec<(&'a str, &'a PageProperty)>,
+}
+
+impl<'a> PropertiesDeserializer<'a> {
+    pub fn new(properties: impl IntoIterator<Item = (&'a String, &'a PageProperty)>) -> Self {
+        Self {
+            properties: properties
+                .into_iter()
+                .map(|(name, property)| (name.as_str(), property))
+                .collect(),
+        }
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for PropertiesDeserializer<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(PropertiesAccess {
+            properties: self.properties.into_iter(),
+            current: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct PropertiesAccess<'a> {
+    properties: std::vec::IntoIter<(&'a str, &'a PageProperty)>,
+    current: Option<(&'a str, &'a PageProperty)>,
+}
+
+impl<'de, 'a> MapAccess<'de> for PropertiesAccess<'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.current = self.properties.next();
+        match self.current {
+            Some((name, _)) => seed.deserialize(StrDeserializer::new(name)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (name, property) = self
+            .current
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        seed.deserialize(property.to_value())
+            .map_err(|e| de::Error::custom(format!("property `{}`: {}", name, e)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.properties.len())
+    }
+}
+
+fn date_value(date: Option<&DatePropertyValue>) -> Value {
+    match date {
+        None => Value::Null,
+        Some(DatePropertyValue {
+            start, end: None, ..
+        }) => serde_json::to_value(start).unwrap_or(Value::Null),
+        Some(date) => serde_json::to_value(date).unwrap_or(Value::Null),
+    }
+}
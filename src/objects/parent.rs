@@ -1,32 +1,52 @@
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::id::{BlockId, DatabaseId, PageId};
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Parent {
     #[default]
     None,
     DatabaseId {
-        database_id: String,
+        database_id: DatabaseId,
     },
     PageId {
-        page_id: String,
+        page_id: PageId,
     },
     Workspace {
         workspace: bool,
     },
     BlockId {
-        block_id: String,
+        block_id: BlockId,
     },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-pub struct HostedFile {
-    pub url: String,
-    pub expiry_time: DateTime<Utc>,
-}
+#[deprecated(note = "use `objects::file::HostedFile` instead")]
+pub type HostedFile = super::file::HostedFile;
+
+#[deprecated(note = "use `objects::file::ExternalFile` instead")]
+pub type ExternalFile = super::file::ExternalFile;
+
+impl Parent {
+    pub fn database(database_id: impl Into<DatabaseId>) -> Self {
+        Parent::DatabaseId {
+            database_id: database_id.into(),
+        }
+    }
+
+    pub fn page(page_id: impl Into<PageId>) -> Self {
+        Parent::PageId {
+            page_id: page_id.into(),
+        }
+    }
+
+    pub fn block(block_id: impl Into<BlockId>) -> Self {
+        Parent::BlockId {
+            block_id: block_id.into(),
+        }
+    }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-pub struct ExternalFile {
-    pub url: String,
+    pub fn workspace() -> Self {
+        Parent::Workspace { workspace: true }
+    }
 }
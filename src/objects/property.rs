@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Number;
 use serde_with::skip_serializing_none;
 
-use super::{file::File, rich_text::RichText, user::User};
+use super::{color, date, file::File, id::PageId, rich_text::RichText, user::User};
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -22,12 +22,12 @@ pub enum Property {
     Select {
         id: String,
         name: String,
-        color: Color,
+        color: color::Color,
     },
     Status {
         id: String,
         name: String,
-        color: Color,
+        color: color::Color,
     },
     MultiSelect {
         id: String,
@@ -35,7 +35,7 @@ pub enum Property {
     },
     Date {
         id: String,
-        date: DatePropertyValue,
+        date: date::DatePropertyValue,
     },
     Formula {
         id: String,
@@ -91,29 +91,21 @@ pub enum Property {
     },
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
-#[serde(rename_all = "snake_case")]
-pub enum Color {
-    Default,
-    Gray,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Purple,
-    Pink,
-}
+#[deprecated(note = "use `objects::color::Color` instead")]
+pub type Color = super::color::Color;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct SelectPropertyValue {
     pub id: Option<String>,
     pub name: Option<String>,
-    pub color: Option<Color>,
+    pub color: Option<color::Color>,
 }
 
+/// The date value this module used before dates without a time were
+/// supported. Convert it with `.into()` where a
+/// [`date::DatePropertyValue`] is expected.
+#[deprecated(note = "use `objects::date::DatePropertyValue` instead")]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct DatePropertyValue {
     pub start: DateTime<Utc>,
@@ -121,18 +113,37 @@ pub struct DatePropertyValue {
     pub time_zone: Option<String>,
 }
 
+#[allow(deprecated)]
+impl From<DatePropertyValue> for date::DatePropertyValue {
+    fn from(value: DatePropertyValue) -> Self {
+        date::DatePropertyValue {
+            start: Some(value.start.into()),
+            end: value.end.map(Into::into),
+            time_zone: value.time_zone,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FormulaPropertyValue {
-    String { string: Option<String> },
-    Number { number: Option<Number> },
-    Boolean { boolean: bool },
-    Date { date: Option<DatePropertyValue> },
+    String {
+        string: Option<String>,
+    },
+    Number {
+        number: Option<Number>,
+    },
+    Boolean {
+        boolean: bool,
+    },
+    Date {
+        date: Option<date::DatePropertyValue>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct RelationPropertyValue {
-    pub id: String,
+    pub id: PageId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -1,7 +1,32 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use super::{property::DatePropertyValue, user::User};
+use super::{
+    color,
+    date::{DateOrDateTime, DatePropertyValue},
+    id::{DatabaseId, PageId, UserId},
+    user::User,
+};
+
+pub mod normalize;
+
+/// Notion rejects text spans longer than this many characters.
+pub const MAX_TEXT_LENGTH: usize = 2000;
+/// Notion rejects rich text arrays with more spans than this.
+pub const MAX_RICH_TEXT_SPANS: usize = 100;
+/// Notion rejects links and URLs longer than this many characters.
+pub const MAX_URL_LENGTH: usize = 2000;
+/// Notion rejects equations longer than this many characters.
+pub const MAX_EQUATION_LENGTH: usize = 1000;
+
+/// Builds a `Vec<RichText>` from spans and anything convertible into one,
+/// e.g. `rich_text!["Read the ", RichText::text("docs").bold(), "."]`.
+#[macro_export]
+macro_rules! rich_text {
+    ($($span:expr),* $(,)?) => {
+        vec![$($crate::objects::rich_text::RichText::from($span)),*]
+    };
+}
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
@@ -41,30 +66,8 @@ pub struct Text {
     pub link: Option<Link>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
-#[serde(rename_all = "snake_case")]
-pub enum TextColor {
-    #[default]
-    Default,
-    Gray,
-    Brown,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Purple,
-    Pink,
-    Red,
-    GrayBackground,
-    BrownBackground,
-    OrangeBackground,
-    YellowBackground,
-    GreenBackground,
-    BlueBackground,
-    PurpleBackground,
-    PinkBackground,
-    RedBackground,
-}
+#[deprecated(note = "use `objects::color::TextColor` instead")]
+pub type TextColor = super::color::TextColor;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
 pub struct Annotations {
@@ -73,7 +76,7 @@ pub struct Annotations {
     pub strikethrough: bool,
     pub underline: bool,
     pub code: bool,
-    pub color: TextColor,
+    pub color: color::TextColor,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -96,7 +99,7 @@ pub enum Mention {
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct DatabaseMention {
-    pub id: String,
+    pub id: DatabaseId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -118,7 +121,7 @@ pub struct LinkMentionMention {
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct PageMention {
-    pub id: String,
+    pub id: PageId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
@@ -155,3 +158,152 @@ impl RichText {
         }
     }
 }
+
+/// Concatenated plain text of the spans. Text built locally has no
+/// `plain_text` yet, its content is used instead.
+pub fn plain_text(rich_text: &[RichText]) -> String {
+    rich_text
+        .iter()
+        .filter_map(|rt| match rt {
+            RichText::Text {
+                text,
+                plain_text: None,
+                ..
+            } => Some(text.content.clone()),
+            rt => rt.plain_text(),
+        })
+        .collect()
+}
+
+impl RichText {
+    pub fn text(content: impl Into<String>) -> Self {
+        RichText::Text {
+            text: Text {
+                content: content.into(),
+                link: None,
+            },
+            annotations: None,
+            plain_text: None,
+            href: None,
+        }
+    }
+
+    pub fn equation(expression: impl Into<String>) -> Self {
+        let expression = expression.into();
+        RichText::Equation {
+            plain_text: expression.clone(),
+            equation: Equation { expression },
+            annotations: Annotations::default(),
+            href: None,
+        }
+    }
+
+    pub fn mention(mention: Mention) -> Self {
+        RichText::Mention {
+            mention,
+            annotations: Annotations::default(),
+            plain_text: String::new(),
+            href: None,
+        }
+    }
+
+    pub fn mention_page(page_id: impl Into<PageId>) -> Self {
+        Self::mention(Mention::Page {
+            page: PageMention { id: page_id.into() },
+        })
+    }
+
+    pub fn mention_database(database_id: impl Into<DatabaseId>) -> Self {
+        Self::mention(Mention::Database {
+            database: DatabaseMention {
+                id: database_id.into(),
+            },
+        })
+    }
+
+    pub fn mention_user(user_id: impl Into<UserId>) -> Self {
+        Self::mention(Mention::User {
+            user: User {
+                object: "user".to_string(),
+                id: user_id.into(),
+                ..Default::default()
+            },
+        })
+    }
+
+    pub fn mention_date(start: impl Into<DateOrDateTime>, end: Option<DateOrDateTime>) -> Self {
+        Self::mention(Mention::Date {
+            date: DatePropertyValue::new(start, end),
+        })
+    }
+
+    pub fn bold(self) -> Self {
+        self.annotate(|a| a.bold = true)
+    }
+
+    pub fn italic(self) -> Self {
+        self.annotate(|a| a.italic = true)
+    }
+
+    pub fn strikethrough(self) -> Self {
+        self.annotate(|a| a.strikethrough = true)
+    }
+
+    pub fn underline(self) -> Self {
+        self.annotate(|a| a.underline = true)
+    }
+
+    pub fn code(self) -> Self {
+        self.annotate(|a| a.code = true)
+    }
+
+    pub fn color(self, color: color::TextColor) -> Self {
+        self.annotate(|a| a.color = color)
+    }
+
+    /// Links a text span; other spans only carry the link as `href`.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        let url = url.into();
+        match &mut self {
+            RichText::None => {}
+            RichText::Text { text, .. } => text.link = Some(Link { url }),
+            RichText::Equation { href, .. } | RichText::Mention { href, .. } => *href = Some(url),
+        }
+        self
+    }
+
+    pub fn annotations(&self) -> Option<&Annotations> {
+        match self {
+            RichText::None => None,
+            RichText::Equation { annotations, .. } | RichText::Mention { annotations, .. } => {
+                Some(annotations)
+            }
+            RichText::Text { annotations, .. } => annotations.as_ref(),
+        }
+    }
+
+    fn annotate(mut self, f: impl FnOnce(&mut Annotations)) -> Self {
+        match &mut self {
+            RichText::None => {}
+            RichText::Equation { annotations, .. } | RichText::Mention { annotations, .. } => {
+                f(annotations)
+            }
+            RichText::Text { annotations, .. } => {
+                f(annotations.get_or_insert_with(Default::default))
+            }
+        }
+        self
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(content: &str) -> Self {
+        RichText::text(content)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(content: String) -> Self {
+        RichText::text(content)
+    }
+}
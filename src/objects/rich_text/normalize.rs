@@ -0,0 +1,245 @@
+//! Brings rich text within Notion's limits before it is sent.
+//!
+//! Adjacent text spans with the same annotations and link are merged, and text
+//! longer than [`MAX_TEXT_LENGTH`] is split on character boundaries. What can
+//! only be fixed by dropping content, like an over-long URL, is left as it is
+//! and reported.
+
+use std::collections::BTreeMap;
+
+use crate::objects::{
+    block::{Block, BlockType},
+    page::PageProperty,
+};
+
+use super::{
+    RichText, Text, MAX_EQUATION_LENGTH, MAX_RICH_TEXT_SPANS, MAX_TEXT_LENGTH, MAX_URL_LENGTH,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// More than [`MAX_RICH_TEXT_SPANS`] spans remain after merging.
+    TooManySpans {
+        count: usize,
+    },
+    UrlTooLong {
+        url: String,
+    },
+    EquationTooLong {
+        expression: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// Position of the block in the normalized tree, e.g. `[2, 0]` for the
+    /// first child of the third block.
+    Block(Vec<usize>),
+    Property(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unfixable {
+    pub location: Location,
+    pub problem: Problem,
+}
+
+/// Normalizes `rich_text` in place, returning what is still over the limits.
+pub fn rich_text(rich_text: &mut Vec<RichText>) -> Vec<Problem> {
+    let mut merged: Vec<RichText> = Vec::with_capacity(rich_text.len());
+    for span in rich_text.drain(..) {
+        match merged.last_mut() {
+            Some(last) if same_style(last, &span) => append(last, span),
+            _ => merged.push(span),
+        }
+    }
+
+    let mut problems = vec![];
+    for span in merged {
+        check(&span, &mut problems);
+        rich_text.extend(split(span));
+    }
+    if rich_text.len() > MAX_RICH_TEXT_SPANS {
+        problems.push(Problem::TooManySpans {
+            count: rich_text.len(),
+        });
+    }
+    problems
+}
+
+/// Normalizes the rich text of `blocks` and all their children.
+pub fn blocks(blocks: &mut [Block]) -> Vec<Unfixable> {
+    let mut unfixable = vec![];
+    let mut path = vec![];
+    for (i, block) in blocks.iter_mut().enumerate() {
+        path.push(i);
+        visit(block, &mut path, &mut unfixable);
+        path.pop();
+    }
+    unfixable
+}
+
+/// Like [`blocks`], with locations relative to `block`.
+pub fn block(block: &mut Block) -> Vec<Unfixable> {
+    let mut unfixable = vec![];
+    visit(block, &mut vec![], &mut unfixable);
+    unfixable
+}
+
+pub fn property(property: &mut PageProperty) -> Vec<Problem> {
+    match property {
+        PageProperty::Title { title, .. } => rich_text(title),
+        PageProperty::RichText {
+            rich_text: text, ..
+        } => rich_text(text),
+        PageProperty::Url { url: Some(url), .. } if too_long(url, MAX_URL_LENGTH) => {
+            vec![Problem::UrlTooLong { url: url.clone() }]
+        }
+        _ => vec![],
+    }
+}
+
+pub fn properties(properties: &mut BTreeMap<String, PageProperty>) -> Vec<Unfixable> {
+    properties
+        .iter_mut()
+        .flat_map(|(name, value)| {
+            property(value).into_iter().map(|problem| Unfixable {
+                location: Location::Property(name.clone()),
+                problem,
+            })
+        })
+        .collect()
+}
+
+/// Splits `text` into pieces of at most `max` characters.
+fn chunks(text: &str, max: usize) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest.char_indices().nth(max).map_or(rest.len(), |(i, _)| i);
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+fn visit(block: &mut Block, path: &mut Vec<usize>, unfixable: &mut Vec<Unfixable>) {
+    let mut problems = vec![];
+    for text in block.block_type.rich_text_mut() {
+        problems.extend(rich_text(text));
+    }
+    if let BlockType::Equation { equation } = &block.block_type {
+        if too_long(&equation.expression, MAX_EQUATION_LENGTH) {
+            problems.push(Problem::EquationTooLong {
+                expression: equation.expression.clone(),
+            });
+        }
+    }
+    unfixable.extend(problems.into_iter().map(|problem| Unfixable {
+        location: Location::Block(path.clone()),
+        problem,
+    }));
+
+    if let Some(Some(children)) = block.block_type.children_mut() {
+        for (i, child) in children.iter_mut().enumerate() {
+            path.push(i);
+            visit(child, path, unfixable);
+            path.pop();
+        }
+    }
+}
+
+fn same_style(a: &RichText, b: &RichText) -> bool {
+    match (a, b) {
+        (
+            RichText::Text {
+                text: a,
+                annotations: a_annotations,
+                href: a_href,
+                ..
+            },
+            RichText::Text {
+                text: b,
+                annotations: b_annotations,
+                href: b_href,
+                ..
+            },
+        ) => {
+            a.link == b.link
+                && a_href == b_href
+                && a_annotations.clone().unwrap_or_default()
+                    == b_annotations.clone().unwrap_or_default()
+        }
+        _ => false,
+    }
+}
+
+fn append(to: &mut RichText, span: RichText) {
+    if let (
+        RichText::Text {
+            text, plain_text, ..
+        },
+        RichText::Text {
+            text: next,
+            plain_text: next_plain_text,
+            ..
+        },
+    ) = (to, span)
+    {
+        text.content.push_str(&next.content);
+        *plain_text = match (plain_text.take(), next_plain_text) {
+            (Some(plain_text), Some(next)) => Some(plain_text + &next),
+            _ => None,
+        };
+    }
+}
+
+fn check(span: &RichText, problems: &mut Vec<Problem>) {
+    let (link, href) = match span {
+        RichText::None => return,
+        RichText::Equation { equation, href, .. } => {
+            if too_long(&equation.expression, MAX_EQUATION_LENGTH) {
+                problems.push(Problem::EquationTooLong {
+                    expression: equation.expression.clone(),
+                });
+            }
+            (None, href)
+        }
+        RichText::Mention { href, .. } => (None, href),
+        RichText::Text { text, href, .. } => (text.link.as_ref().map(|link| &link.url), href),
+    };
+    let href = href.as_ref().filter(|href| Some(*href) != link);
+    for url in link.into_iter().chain(href) {
+        if too_long(url, MAX_URL_LENGTH) {
+            problems.push(Problem::UrlTooLong { url: url.clone() });
+        }
+    }
+}
+
+fn split(span: RichText) -> Vec<RichText> {
+    match span {
+        RichText::Text {
+            text,
+            annotations,
+            plain_text,
+            href,
+        } if too_long(&text.content, MAX_TEXT_LENGTH) => chunks(&text.content, MAX_TEXT_LENGTH)
+            .map(|chunk| RichText::Text {
+                text: Text {
+                    content: chunk.to_string(),
+                    link: text.link.clone(),
+                },
+                annotations: annotations.clone(),
+                plain_text: plain_text.as_ref().map(|_| chunk.to_string()),
+                href: href.clone(),
+            })
+            .collect(),
+        span => vec![span],
+    }
+}
+
+fn too_long(text: &str, max: usize) -> bool {
+    text.chars().nth(max).is_some()
+}
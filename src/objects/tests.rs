@@ -1,6 +1,32 @@
-use crate::objects::{
-    block::Block, comment::Comment, emoji::Emoji, error::Error, file::File, page::Page,
-    rich_text::RichText,
+use crate::{
+    objects::{
+        block::{
+            builder::{
+                audio, breadcrumb, bullet, callout, code, divider, equation, h1, h4, p,
+                table_with_header, todo, toggle,
+            },
+            Block, BlockType, Language, TranscriptionStatus,
+        },
+        color::{Color, TextColor},
+        comment::Comment,
+        date::DateOrDateTime,
+        emoji::Emoji,
+        error::Error,
+        file::File,
+        icon::Icon,
+        id::{BlockId, DatabaseId, NotionUrl, PageId},
+        page::{Page, PageProperty},
+        parent::Parent,
+        rich_text::{
+            normalize::{self, Location, Problem, Unfixable},
+            Annotations, Link, Mention, RichText, Text, MAX_TEXT_LENGTH,
+        },
+        webhook::{
+            PropertyAction, WebhookEntity, WebhookEvent, WebhookEventType, WebhookPayload,
+            WebhookVerifier,
+        },
+    },
+    NotionClientError,
 };
 
 use super::block::CalloutValue;
@@ -64,3 +90,664 @@ fn test_callout_icon_null() {
     let result = serde_json::from_str::<CalloutValue>(include_str!("tests/callout_icon_null.json"));
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_webhook_verification() {
+    let result = WebhookPayload::from_slice(include_bytes!("tests/webhook_verification.json"));
+    let Ok(WebhookPayload::Verification(request)) = result else {
+        panic!("expected a verification payload: {:?}", result);
+    };
+    assert_eq!(
+        request.verification_token,
+        "secret_tMrlL1qK5vuQAh1b6cZGhFChZTSYJlce98V0pYn7yBl"
+    );
+}
+
+#[test]
+fn test_webhook_page_created() {
+    let result = WebhookPayload::from_slice(include_bytes!("tests/webhook_page_created.json"));
+    let Ok(WebhookPayload::Event(event)) = result else {
+        panic!("expected an event payload: {:?}", result);
+    };
+    assert_eq!(event.name(), "page.created");
+    assert_eq!(
+        event.entity,
+        WebhookEntity::Page {
+            id: "153104cd-477e-809d-8dc4-ff2d96ae3090".into()
+        }
+    );
+}
+
+#[test]
+fn test_webhook_page_content_updated() {
+    let result =
+        WebhookPayload::from_slice(include_bytes!("tests/webhook_page_content_updated.json"));
+    let Ok(WebhookPayload::Event(event)) = result else {
+        panic!("expected an event payload: {:?}", result);
+    };
+    let WebhookEventType::PageContentUpdated(data) = event.event_type else {
+        panic!("unexpected event type");
+    };
+    assert_eq!(data.updated_blocks.len(), 1);
+}
+
+#[test]
+fn test_webhook_database_schema_updated() {
+    let result =
+        WebhookPayload::from_slice(include_bytes!("tests/webhook_database_schema_updated.json"));
+    let Ok(WebhookPayload::Event(event)) = result else {
+        panic!("expected an event payload: {:?}", result);
+    };
+    let WebhookEventType::DatabaseSchemaUpdated(data) = event.event_type else {
+        panic!("unexpected event type");
+    };
+    assert_eq!(data.updated_properties[0].action, PropertyAction::Created);
+}
+
+#[test]
+fn test_webhook_comment_created() {
+    let result = WebhookPayload::from_slice(include_bytes!("tests/webhook_comment_created.json"));
+    let Ok(WebhookPayload::Event(event)) = result else {
+        panic!("expected an event payload: {:?}", result);
+    };
+    assert_eq!(event.name(), "comment.created");
+    assert_eq!(event.authors.len(), 1);
+}
+
+#[test]
+fn test_webhook_unknown_event_type() {
+    let body = include_bytes!("tests/webhook_data_source_schema_updated.json");
+    let result = WebhookPayload::from_slice(body);
+    let Ok(WebhookPayload::Event(event)) = result else {
+        panic!("expected an event payload: {:?}", result);
+    };
+    assert_eq!(event.name(), "data_source.schema_updated");
+    assert_eq!(
+        event.entity,
+        WebhookEntity::Unknown {
+            type_name: "data_source".to_string(),
+            id: "25e104cd-477e-8025-a7b6-000bb9a1e1a6".to_string(),
+        }
+    );
+    let WebhookEventType::Unknown { data, .. } = &event.event_type else {
+        panic!("unexpected event type");
+    };
+    assert_eq!(data["updated_properties"][0]["name"], "Status");
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "data_source.schema_updated");
+    assert_eq!(json["entity"]["type"], "data_source");
+    assert_eq!(
+        serde_json::from_value::<WebhookEvent>(json).unwrap(),
+        *event
+    );
+}
+
+#[test]
+fn test_webhook_signature() {
+    let body = include_bytes!("tests/webhook_page_created.json");
+    let verifier = WebhookVerifier::new("secret_tMrlL1qK5vuQAh1b6cZGhFChZTSYJlce98V0pYn7yBl");
+    let signature = "sha256=8556b8ca96d498751c124c95264401d75b16f1db5b91db36ad2336029b288ba2";
+
+    assert_eq!(verifier.sign(body), signature);
+    assert!(verifier.verify(body, signature).is_ok());
+    assert!(matches!(
+        verifier.verify(b"{}", signature),
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+    assert!(matches!(
+        verifier.verify(
+            body,
+            "8556b8ca96d498751c124c95264401d75b16f1db5b91db36ad2336029b288ba2"
+        ),
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+}
+
+#[test]
+fn test_id_forms() {
+    let dashed = PageId::parse("1429989F-E8AC-4EFF-BC8F-57F56486DB54").unwrap();
+    let simple = PageId::parse("1429989fe8ac4effbc8f57f56486db54").unwrap();
+
+    assert_eq!(dashed, simple);
+    assert_eq!(dashed.to_string(), "1429989f-e8ac-4eff-bc8f-57f56486db54");
+    assert_eq!(dashed.simple(), "1429989fe8ac4effbc8f57f56486db54");
+    assert_eq!(dashed, "1429989fe8ac4effbc8f57f56486db54");
+    assert!(PageId::parse("not an id").is_err());
+    assert!(PageId::parse("https://example.com/1429989fe8ac4effbc8f57f56486db54").is_err());
+}
+
+#[test]
+fn test_id_from_falls_back_to_input() {
+    let parsed =
+        PageId::from("https://www.notion.so/Meeting-Notes-1429989fe8ac4effbc8f57f56486db54");
+    assert_eq!(parsed.as_str(), "1429989f-e8ac-4eff-bc8f-57f56486db54");
+
+    let typo = PageId::from("1429989fe8ac4effbc8f57f56486db5");
+    assert_eq!(typo.as_str(), "1429989fe8ac4effbc8f57f56486db5");
+    assert!("1429989fe8ac4effbc8f57f56486db5".parse::<PageId>().is_err());
+
+    let other =
+        DatabaseId::from("https://example.com/a1d8501e1ac143e9a6bdea9fe6c8822b".to_string());
+    assert_eq!(
+        other.as_str(),
+        "https://example.com/a1d8501e1ac143e9a6bdea9fe6c8822b"
+    );
+}
+
+#[test]
+fn test_id_from_url() {
+    let page =
+        PageId::parse("https://www.notion.so/acme/Meeting-Notes-1429989fe8ac4effbc8f57f56486db54")
+            .unwrap();
+    assert_eq!(page, "1429989f-e8ac-4eff-bc8f-57f56486db54");
+
+    let database = DatabaseId::parse(
+        "https://www.notion.so/acme/a1d8501e1ac143e9a6bdea9fe6c8822b?v=bd0d8bc5b0d54e8ba2f1d4b8c5b5e2a1",
+    )
+    .unwrap();
+    assert_eq!(database, "a1d8501e-1ac1-43e9-a6bd-ea9fe6c8822b");
+
+    let peeked = PageId::parse(
+        "https://www.notion.so/a1d8501e1ac143e9a6bdea9fe6c8822b?v=bd0d8bc5b0d54e8ba2f1d4b8c5b5e2a1&p=1429989fe8ac4effbc8f57f56486db54&pm=s",
+    )
+    .unwrap();
+    assert_eq!(peeked, "1429989f-e8ac-4eff-bc8f-57f56486db54");
+
+    let block = BlockId::parse(
+        "https://acme.notion.site/Meeting-Notes-1429989fe8ac4effbc8f57f56486db54#be633bf1dfa0436db259571129a590e5",
+    )
+    .unwrap();
+    assert_eq!(block, "be633bf1-dfa0-436d-b259-571129a590e5");
+
+    let url = NotionUrl::parse(
+        "notion.so/a1d8501e-1ac1-43e9-a6bd-ea9fe6c8822b?v=bd0d8bc5b0d54e8ba2f1d4b8c5b5e2a1",
+    )
+    .unwrap();
+    assert_eq!(url.id, "a1d8501e-1ac1-43e9-a6bd-ea9fe6c8822b");
+    assert_eq!(
+        url.view.as_deref(),
+        Some("bd0d8bc5-b0d5-4e8b-a2f1-d4b8c5b5e2a1")
+    );
+    assert_eq!(url.block, None);
+}
+
+#[test]
+fn test_id_parent_constructors() {
+    let page_id = PageId::parse("1429989fe8ac4effbc8f57f56486db54").unwrap();
+    let block_id: BlockId = page_id.clone().into();
+
+    assert_eq!(block_id.as_str(), page_id.as_str());
+    assert_eq!(
+        Parent::page(&page_id),
+        Parent::PageId {
+            page_id: "1429989f-e8ac-4eff-bc8f-57f56486db54".into()
+        }
+    );
+    assert_eq!(
+        serde_json::to_value(Parent::database("a1d8501e1ac143e9a6bdea9fe6c8822b")).unwrap(),
+        serde_json::json!({
+            "type": "database_id",
+            "database_id": "a1d8501e-1ac1-43e9-a6bd-ea9fe6c8822b"
+        })
+    );
+}
+
+#[test]
+fn test_links() {
+    let page = serde_json::from_str::<Page>(include_str!("tests/page.json")).unwrap();
+    let block = serde_json::from_str::<Block>(include_str!("tests/block.json")).unwrap();
+    let comment = serde_json::from_str::<Comment>(include_str!("tests/comment.json")).unwrap();
+
+    assert_eq!(
+        page.link(),
+        "https://www.notion.so/Bug-bash-be633bf1dfa0436db259571129a590e5"
+    );
+    assert_eq!(
+        block.link(Some(&page), &[]).unwrap(),
+        "https://www.notion.so/Bug-bash-be633bf1dfa0436db259571129a590e5#c02fc1d3db8b45c5a22227595b15aea7"
+    );
+    assert_eq!(
+        block.link(None, &[]).unwrap(),
+        "https://www.notion.so/598337872cf94fdf8782e53db20768a5#c02fc1d3db8b45c5a22227595b15aea7"
+    );
+    assert_eq!(
+        comment.discussion_link(None).unwrap(),
+        "https://www.notion.so/5c6a28216bb14a7eb6e1c50111515c3d?d=f4be6752a5394da2a8a9c3953e13bc0b"
+    );
+
+    let nested = Block {
+        id: Some(BlockId::from("0f7d2f55-3e39-4b5b-9c5a-2a1d7b2b0e8c")),
+        parent: Some(Parent::BlockId {
+            block_id: block.id.clone().unwrap(),
+        }),
+        ..p("Nested")
+    };
+    assert_eq!(nested.link(None, &[]), None);
+    assert_eq!(
+        nested.link(None, std::slice::from_ref(&block)).unwrap(),
+        "https://www.notion.so/598337872cf94fdf8782e53db20768a5#0f7d2f553e394b5b9c5a2a1d7b2b0e8c"
+    );
+
+    // page > a > b > c > d, passing only the top-level ancestor.
+    let page_id = PageId::from("b55c9c91-384d-452b-81db-d1ef79372b75");
+    let ids: Vec<_> = (1..=4)
+        .map(|i| BlockId::from(format!("0f7d2f55-3e39-4b5b-9c5a-2a1d7b2b0e8{}", i).as_str()))
+        .collect();
+    let parents = std::iter::once(Parent::PageId {
+        page_id: page_id.clone(),
+    })
+    .chain(ids.iter().map(|id| Parent::BlockId {
+        block_id: id.clone(),
+    }));
+    let mut chain: Vec<_> = ids
+        .iter()
+        .zip(parents)
+        .map(|(id, parent)| Block {
+            id: Some(id.clone()),
+            parent: Some(parent),
+            ..toggle("Level")
+        })
+        .collect();
+    let d = chain.pop().unwrap();
+    let root = chain
+        .into_iter()
+        .rev()
+        .fold(None, |child: Option<Block>, block| {
+            Some(match child {
+                Some(child) => block.children([child]),
+                None => block,
+            })
+        })
+        .unwrap();
+    assert_eq!(d.page_id(std::slice::from_ref(&root)), Some(page_id));
+
+    let looped = Block {
+        parent: Some(Parent::BlockId {
+            block_id: ids[0].clone(),
+        }),
+        ..root
+    };
+    assert_eq!(d.page_id(std::slice::from_ref(&looped)), None);
+
+    let on_block = Comment {
+        parent: Parent::BlockId {
+            block_id: nested.id.clone().unwrap(),
+        },
+        ..comment.clone()
+    };
+    assert_eq!(on_block.discussion_link(None), None);
+    assert_eq!(
+        on_block.discussion_link(Some(&page)).unwrap(),
+        "https://www.notion.so/Bug-bash-be633bf1dfa0436db259571129a590e5?d=f4be6752a5394da2a8a9c3953e13bc0b#0f7d2f553e394b5b9c5a2a1d7b2b0e8c"
+    );
+    assert_eq!(
+        DatabaseId::from("a1d8501e-1ac1-43e9-a6bd-ea9fe6c8822b")
+            .view_url("bd0d8bc5-b0d5-4e8b-a2f1-d4b8c5b5e2a1"),
+        "https://www.notion.so/a1d8501e1ac143e9a6bdea9fe6c8822b?v=bd0d8bc5b0d54e8ba2f1d4b8c5b5e2a1"
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_aliases() {
+    let page_icon: crate::objects::page::Icon = Icon::emoji("🐞");
+    let callout_icon: crate::objects::block::Icon = page_icon.clone();
+    let color: crate::objects::page::Color = crate::objects::database::Color::Green.into();
+    let text_color: crate::objects::rich_text::TextColor = color.into();
+
+    assert_eq!(callout_icon, page_icon);
+    assert_eq!(text_color, TextColor::Green);
+    assert_eq!(Color::from(TextColor::RedBackground), Color::Red);
+
+    assert_eq!(
+        crate::objects::database::Icon::default(),
+        crate::objects::database::Icon::None
+    );
+    assert_eq!(
+        serde_json::to_value(crate::objects::database::Icon::None).unwrap(),
+        serde_json::Value::Null
+    );
+    assert_eq!(
+        Color::from(crate::objects::database::Color::default()),
+        Color::Blue
+    );
+    assert_eq!(
+        Option::<Icon>::from(crate::objects::database::Icon::from(Icon::emoji("🐞"))),
+        Some(Icon::emoji("🐞"))
+    );
+    assert_eq!(
+        Option::<Icon>::from(crate::objects::database::Icon::default()),
+        None
+    );
+
+    let date = serde_json::from_str::<crate::objects::property::DatePropertyValue>(
+        r#"{ "start": "2022-02-22T12:00:00.000Z", "end": null, "time_zone": null }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        crate::objects::date::DatePropertyValue::from(date.clone()),
+        crate::objects::date::DatePropertyValue::new(date.start, None)
+    );
+}
+
+#[test]
+fn test_page_icon_into_callout() {
+    let page = serde_json::from_str::<Page>(include_str!("tests/page.json")).unwrap();
+    let mut callout =
+        serde_json::from_str::<CalloutValue>(include_str!("tests/callout.json")).unwrap();
+
+    callout.icon = page.icon.clone();
+
+    assert_eq!(callout.icon, Some(Icon::emoji("🐞")));
+}
+
+#[test]
+fn test_date_mention() {
+    let result = serde_json::from_value::<Mention>(serde_json::json!({
+        "type": "date",
+        "date": {
+            "start": "2023-02-23",
+            "end": null,
+            "time_zone": null
+        }
+    }));
+
+    let Ok(Mention::Date { date }) = result else {
+        panic!("expected a date mention: {:?}", result);
+    };
+    assert_eq!(
+        date.start,
+        Some(DateOrDateTime::Date(
+            chrono::NaiveDate::from_ymd_opt(2023, 2, 23).unwrap()
+        ))
+    );
+}
+
+#[test]
+fn test_rich_text_builder() {
+    let span = RichText::text("Lacinato kale")
+        .bold()
+        .italic()
+        .color(TextColor::Green)
+        .link("https://en.wikipedia.org/wiki/Lacinato_kale");
+
+    assert_eq!(
+        span,
+        RichText::Text {
+            text: Text {
+                content: "Lacinato kale".to_string(),
+                link: Some(Link {
+                    url: "https://en.wikipedia.org/wiki/Lacinato_kale".to_string()
+                }),
+            },
+            annotations: Some(Annotations {
+                bold: true,
+                italic: true,
+                color: TextColor::Green,
+                ..Default::default()
+            }),
+            plain_text: None,
+            href: None,
+        }
+    );
+}
+
+#[test]
+fn test_rich_text_mentions() {
+    let page = RichText::mention_page("be633bf1dfa0436db259571129a590e5");
+    let equation = RichText::equation("e^{i\\pi} + 1 = 0").code();
+
+    assert_eq!(
+        serde_json::to_value(&page).unwrap()["mention"],
+        serde_json::json!({
+            "type": "page",
+            "page": { "id": "be633bf1-dfa0-436d-b259-571129a590e5" }
+        })
+    );
+    assert!(equation.annotations().unwrap().code);
+    assert!(matches!(
+        RichText::mention_user("c2f20311-9e54-4d11-8c79-7398424ae41e"),
+        RichText::Mention {
+            mention: Mention::User { .. },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_rich_text_macro() {
+    let spans = crate::rich_text![
+        "Read the ",
+        RichText::text("docs").underline(),
+        String::from("."),
+    ];
+
+    assert_eq!(spans.len(), 3);
+    assert_eq!(spans[0], RichText::text("Read the "));
+    assert!(spans[1].annotations().unwrap().underline);
+}
+
+#[test]
+fn test_block_builder() {
+    let blocks = crate::blocks![
+        h1("Groceries"),
+        bullet("Vegetables").children([todo("Lacinato kale", true)]),
+        code(Language::Rust, "fn main() {}"),
+        table_with_header([vec!["Item", "Price"], vec!["Kale"]]),
+        callout("Buy local", Icon::emoji("🥬")).color(TextColor::GreenBackground),
+        divider(),
+    ];
+
+    let expected: serde_json::Value =
+        serde_json::from_str(include_str!("tests/block_tree.json")).unwrap();
+    assert_eq!(serde_json::to_value(&blocks).unwrap(), expected);
+}
+
+#[test]
+fn test_block_types() {
+    let blocks =
+        serde_json::from_str::<Vec<Block>>(include_str!("tests/block_types.json")).unwrap();
+
+    let types = blocks
+        .iter()
+        .map(|block| serde_json::to_value(&block.block_type).unwrap()["type"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            "audio",
+            "breadcrumb",
+            "button",
+            "heading_4",
+            "tab",
+            "transcription",
+            "unsupported"
+        ]
+    );
+
+    let BlockType::Transcription { transcription } = &blocks[5].block_type else {
+        panic!("expected a transcription block");
+    };
+    assert_eq!(transcription.status, Some(TranscriptionStatus::NotesReady));
+    assert_eq!(
+        transcription.children.as_ref().unwrap().notes_block_id,
+        Some(BlockId::from("908382d1-c285-43f5-a486-71d2a39415c6"))
+    );
+    assert_eq!(
+        blocks[5].block_type.plain_text(),
+        vec![Some("Weekly sync".to_string())]
+    );
+
+    assert_eq!(
+        blocks[6].block_type,
+        BlockType::Unsupported {
+            unsupported: serde_json::json!({ "block_type": "form" })
+        }
+    );
+
+    let meeting_notes = serde_json::from_value::<BlockType>(serde_json::json!({
+        "type": "meeting_notes",
+        "meeting_notes": { "title": [], "status": "transcription_paused" }
+    }))
+    .unwrap();
+    assert!(matches!(meeting_notes, BlockType::Transcription { .. }));
+}
+
+#[test]
+fn test_block_plain_text() {
+    let span = |text: &str| serde_json::json!({ "type": "text", "text": { "content": text }, "plain_text": text });
+    let code = serde_json::from_value::<BlockType>(serde_json::json!({
+        "type": "code",
+        "code": {
+            "rich_text": [span("let x = 1;")],
+            "caption": [span("Binding")],
+            "language": "rust"
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        code.plain_text(),
+        vec![Some("let x = 1;".to_string()), Some("Binding".to_string())]
+    );
+
+    let equation = serde_json::from_value::<BlockType>(serde_json::json!({
+        "type": "equation",
+        "equation": { "expression": "x^2" }
+    }))
+    .unwrap();
+    assert_eq!(equation.plain_text(), vec![Some("x^2".to_string())]);
+}
+
+#[test]
+fn test_new_block_builders() {
+    let blocks = crate::blocks![
+        breadcrumb(),
+        audio("https://example.com/episode.mp3"),
+        h4("Details").children([p("Hidden")]),
+    ];
+
+    assert_eq!(
+        serde_json::to_value(&blocks).unwrap(),
+        serde_json::json!([
+            { "object": "block", "type": "breadcrumb", "breadcrumb": {} },
+            {
+                "object": "block",
+                "type": "audio",
+                "audio": {
+                    "caption": [],
+                    "type": "external",
+                    "external": { "url": "https://example.com/episode.mp3" }
+                }
+            },
+            {
+                "object": "block",
+                "type": "heading_4",
+                "heading_4": {
+                    "rich_text": [{ "type": "text", "text": { "content": "Details" } }],
+                    "is_toggleable": true,
+                    "children": [{
+                        "object": "block",
+                        "type": "paragraph",
+                        "paragraph": {
+                            "rich_text": [{ "type": "text", "text": { "content": "Hidden" } }]
+                        }
+                    }]
+                }
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_normalize_rich_text() {
+    let long = "é".repeat(MAX_TEXT_LENGTH + 10);
+    let mut text = vec![
+        RichText::text("Plain "),
+        RichText::text("and plain"),
+        RichText::text(long.as_str())
+            .bold()
+            .link("https://example.com"),
+        RichText::text("bold").bold(),
+        RichText::equation("x"),
+        RichText::text("after"),
+    ];
+    assert_eq!(normalize::rich_text(&mut text), vec![]);
+
+    let linked = |content: &str| RichText::text(content).bold().link("https://example.com");
+    assert_eq!(
+        text,
+        vec![
+            RichText::text("Plain and plain"),
+            linked(&"é".repeat(MAX_TEXT_LENGTH)),
+            linked(&"é".repeat(10)),
+            RichText::text("bold").bold(),
+            RichText::equation("x"),
+            RichText::text("after"),
+        ]
+    );
+}
+
+#[test]
+fn test_normalize_unfixable() {
+    let url = format!("https://example.com/{}", "a".repeat(2000));
+    // Alternating styles cannot be merged.
+    let mut text = (0..101)
+        .map(|i| match i % 2 {
+            0 => RichText::text(i.to_string()).bold(),
+            _ => RichText::text(i.to_string()).italic(),
+        })
+        .collect::<Vec<_>>();
+    text.push(RichText::text("link").link(url.as_str()));
+    assert_eq!(
+        normalize::rich_text(&mut text),
+        vec![
+            Problem::UrlTooLong { url: url.clone() },
+            Problem::TooManySpans { count: 102 },
+        ]
+    );
+
+    let mut blocks = vec![
+        p("Fine"),
+        toggle("Outer").children([p("Inner"), equation("x".repeat(1001))]),
+    ];
+    assert_eq!(
+        normalize::blocks(&mut blocks),
+        vec![Unfixable {
+            location: Location::Block(vec![1, 1]),
+            problem: Problem::EquationTooLong {
+                expression: "x".repeat(1001)
+            },
+        }]
+    );
+
+    let mut property = PageProperty::Url {
+        id: None,
+        url: Some(url.clone()),
+    };
+    assert_eq!(
+        normalize::property(&mut property),
+        vec![Problem::UrlTooLong { url }]
+    );
+}
+
+#[test]
+fn test_normalize_block_tree() {
+    let long = "x".repeat(MAX_TEXT_LENGTH * 2);
+    let mut blocks = vec![toggle("Outer").children([code(Language::Rust, long.as_str())])];
+    assert_eq!(normalize::blocks(&mut blocks), vec![]);
+
+    let Some(children) = blocks[0].block_type.children() else {
+        panic!("children missing");
+    };
+    let BlockType::Code { code } = &children[0].block_type else {
+        panic!("expected code, got {:?}", children[0].block_type);
+    };
+    assert_eq!(
+        code.rich_text,
+        vec![
+            RichText::text("x".repeat(MAX_TEXT_LENGTH)),
+            RichText::text("x".repeat(MAX_TEXT_LENGTH)),
+        ]
+    );
+}
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use super::id::UserId;
+
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 
 pub struct User {
     pub object: String,
-    pub id: String,
+    pub id: UserId,
     #[serde(flatten)]
     pub user_type: Option<UserType>,
     pub name: Option<String>,
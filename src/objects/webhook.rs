@@ -0,0 +1,418 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+use sha2::Sha256;
+
+use crate::NotionClientError;
+
+use super::{
+    id::{BlockId, CommentId, DatabaseId, PageId, UserId},
+    user::User,
+};
+
+pub const SIGNATURE_HEADER: &str = "X-Notion-Signature";
+
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum WebhookPayload {
+    Verification(VerificationRequest),
+    Event(Box<WebhookEvent>),
+}
+
+/// The one-time payload Notion sends when a webhook subscription is created.
+/// The token must be entered in the integration settings and is then used as
+/// the key for `X-Notion-Signature`.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct VerificationRequest {
+    pub verification_token: String,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct WebhookEvent {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    pub workspace_id: String,
+    pub workspace_name: Option<String>,
+    pub subscription_id: String,
+    pub integration_id: String,
+    pub authors: Vec<WebhookAuthor>,
+    pub accessible_by: Option<Vec<WebhookAuthor>>,
+    pub attempt_number: u32,
+    pub entity: WebhookEntity,
+    #[serde(flatten)]
+    pub event_type: WebhookEventType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct WebhookAuthor {
+    pub id: UserId,
+    #[serde(rename = "type")]
+    pub author_type: WebhookAuthorType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookAuthorType {
+    Person,
+    Bot,
+    Agent,
+}
+
+/// The object an event is about. Entity types this crate does not model yet
+/// are kept as [`WebhookEntity::Unknown`].
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
+pub enum WebhookEntity {
+    Page {
+        id: PageId,
+    },
+    Database {
+        id: DatabaseId,
+    },
+    Block {
+        id: BlockId,
+    },
+    Comment {
+        id: CommentId,
+    },
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        id: String,
+    },
+}
+
+const ENTITY_TYPES: &[&str] = &["page", "database", "block", "comment"];
+
+/// The event type and its `data`. Event types this crate does not model yet,
+/// such as newly added ones, are kept as [`WebhookEventType::Unknown`] so that
+/// their deliveries can still be acknowledged.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(remote = "Self", tag = "type", content = "data")]
+pub enum WebhookEventType {
+    #[serde(rename = "page.created")]
+    PageCreated(WebhookEventData),
+    #[serde(rename = "page.properties_updated")]
+    PagePropertiesUpdated(PagePropertiesUpdatedData),
+    #[serde(rename = "page.content_updated")]
+    PageContentUpdated(ContentUpdatedData),
+    #[serde(rename = "page.moved")]
+    PageMoved(WebhookEventData),
+    #[serde(rename = "page.deleted")]
+    PageDeleted(WebhookEventData),
+    #[serde(rename = "page.undeleted")]
+    PageUndeleted(WebhookEventData),
+    #[serde(rename = "page.locked")]
+    PageLocked(WebhookEventData),
+    #[serde(rename = "page.unlocked")]
+    PageUnlocked(WebhookEventData),
+    #[serde(rename = "database.created")]
+    DatabaseCreated(WebhookEventData),
+    #[serde(rename = "database.content_updated")]
+    DatabaseContentUpdated(ContentUpdatedData),
+    #[serde(rename = "database.moved")]
+    DatabaseMoved(WebhookEventData),
+    #[serde(rename = "database.deleted")]
+    DatabaseDeleted(WebhookEventData),
+    #[serde(rename = "database.undeleted")]
+    DatabaseUndeleted(WebhookEventData),
+    #[serde(rename = "database.schema_updated")]
+    DatabaseSchemaUpdated(SchemaUpdatedData),
+    #[serde(rename = "comment.created")]
+    CommentCreated(CommentEventData),
+    #[serde(rename = "comment.updated")]
+    CommentUpdated(CommentEventData),
+    #[serde(rename = "comment.deleted")]
+    CommentDeleted(CommentEventData),
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        data: serde_json::Value,
+    },
+}
+
+const EVENT_TYPES: &[&str] = &[
+    "page.created",
+    "page.properties_updated",
+    "page.content_updated",
+    "page.moved",
+    "page.deleted",
+    "page.undeleted",
+    "page.locked",
+    "page.unlocked",
+    "database.created",
+    "database.content_updated",
+    "database.moved",
+    "database.deleted",
+    "database.undeleted",
+    "database.schema_updated",
+    "comment.created",
+    "comment.updated",
+    "comment.deleted",
+];
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct WebhookEventData {
+    pub parent: Option<WebhookParent>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct PagePropertiesUpdatedData {
+    pub parent: Option<WebhookParent>,
+    #[serde(default)]
+    pub updated_properties: Vec<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct ContentUpdatedData {
+    pub parent: Option<WebhookParent>,
+    #[serde(default)]
+    pub updated_blocks: Vec<WebhookEntity>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct SchemaUpdatedData {
+    pub parent: Option<WebhookParent>,
+    #[serde(default)]
+    pub updated_properties: Vec<UpdatedProperty>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct UpdatedProperty {
+    pub id: String,
+    pub name: String,
+    pub action: PropertyAction,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyAction {
+    Created,
+    Updated,
+    Deleted,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+pub struct CommentEventData {
+    pub page_id: Option<PageId>,
+    pub parent: Option<WebhookParent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct WebhookParent {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub parent_type: WebhookParentType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookParentType {
+    Page,
+    Database,
+    Block,
+    Space,
+    Workspace,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    verification_token: String,
+}
+
+impl WebhookPayload {
+    pub fn from_slice(body: &[u8]) -> Result<Self, NotionClientError> {
+        serde_json::from_slice(body).map_err(|e| NotionClientError::FailedToDeserialize {
+            source: e,
+            body: String::from_utf8_lossy(body).into_owned(),
+        })
+    }
+}
+
+impl WebhookEvent {
+    pub fn name(&self) -> &str {
+        self.event_type.name()
+    }
+}
+
+impl WebhookEventType {
+    pub fn name(&self) -> &str {
+        match self {
+            WebhookEventType::PageCreated(_) => "page.created",
+            WebhookEventType::PagePropertiesUpdated(_) => "page.properties_updated",
+            WebhookEventType::PageContentUpdated(_) => "page.content_updated",
+            WebhookEventType::PageMoved(_) => "page.moved",
+            WebhookEventType::PageDeleted(_) => "page.deleted",
+            WebhookEventType::PageUndeleted(_) => "page.undeleted",
+            WebhookEventType::PageLocked(_) => "page.locked",
+            WebhookEventType::PageUnlocked(_) => "page.unlocked",
+            WebhookEventType::DatabaseCreated(_) => "database.created",
+            WebhookEventType::DatabaseContentUpdated(_) => "database.content_updated",
+            WebhookEventType::DatabaseMoved(_) => "database.moved",
+            WebhookEventType::DatabaseDeleted(_) => "database.deleted",
+            WebhookEventType::DatabaseUndeleted(_) => "database.undeleted",
+            WebhookEventType::DatabaseSchemaUpdated(_) => "database.schema_updated",
+            WebhookEventType::CommentCreated(_) => "comment.created",
+            WebhookEventType::CommentUpdated(_) => "comment.updated",
+            WebhookEventType::CommentDeleted(_) => "comment.deleted",
+            WebhookEventType::Unknown { type_name, .. } => type_name,
+        }
+    }
+}
+
+impl Serialize for WebhookEventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Raw<'a> {
+            #[serde(rename = "type")]
+            type_name: &'a str,
+            data: &'a serde_json::Value,
+        }
+
+        match self {
+            WebhookEventType::Unknown { type_name, data } => {
+                Raw { type_name, data }.serialize(serializer)
+            }
+            known => WebhookEventType::serialize(known, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match type_name(&value)? {
+            name if EVENT_TYPES.contains(&name) => {
+                WebhookEventType::deserialize(value).map_err(de::Error::custom)
+            }
+            name => Ok(WebhookEventType::Unknown {
+                type_name: name.to_string(),
+                data: value.get("data").cloned().unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+impl WebhookEntity {
+    pub fn id(&self) -> &str {
+        match self {
+            WebhookEntity::Page { id } => id.as_str(),
+            WebhookEntity::Database { id } => id.as_str(),
+            WebhookEntity::Block { id } => id.as_str(),
+            WebhookEntity::Comment { id } => id.as_str(),
+            WebhookEntity::Unknown { id, .. } => id,
+        }
+    }
+}
+
+impl Serialize for WebhookEntity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Raw<'a> {
+            id: &'a str,
+            #[serde(rename = "type")]
+            type_name: &'a str,
+        }
+
+        match self {
+            WebhookEntity::Unknown { type_name, id } => Raw { id, type_name }.serialize(serializer),
+            known => WebhookEntity::serialize(known, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEntity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match type_name(&value)? {
+            name if ENTITY_TYPES.contains(&name) => {
+                WebhookEntity::deserialize(value).map_err(de::Error::custom)
+            }
+            name => Ok(WebhookEntity::Unknown {
+                type_name: name.to_string(),
+                id: value
+                    .get("id")
+                    .and_then(serde_json::Value::as_str)
+                    .ok_or_else(|| de::Error::missing_field("id"))?
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+fn type_name<E: de::Error>(value: &serde_json::Value) -> Result<&str, E> {
+    value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| de::Error::missing_field("type"))
+}
+
+impl From<&WebhookAuthor> for User {
+    fn from(author: &WebhookAuthor) -> Self {
+        User {
+            object: "user".to_string(),
+            id: author.id.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl WebhookVerifier {
+    pub fn new(verification_token: impl Into<String>) -> Self {
+        Self {
+            verification_token: verification_token.into(),
+        }
+    }
+
+    /// Returns the `X-Notion-Signature` value Notion would send for `body`.
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac = self.mac();
+        mac.update(body);
+        format!(
+            "{}{}",
+            SIGNATURE_PREFIX,
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
+
+    pub fn verify(&self, body: &[u8], signature: &str) -> Result<(), NotionClientError> {
+        let digest = signature
+            .strip_prefix(SIGNATURE_PREFIX)
+            .and_then(|s| hex::decode(s).ok())
+            .ok_or(NotionClientError::InvalidWebhookSignature)?;
+
+        let mut mac = self.mac();
+        mac.update(body);
+        mac.verify_slice(&digest)
+            .map_err(|_| NotionClientError::InvalidWebhookSignature)
+    }
+
+    pub fn verify_and_parse(
+        &self,
+        body: &[u8],
+        signature: &str,
+    ) -> Result<WebhookPayload, NotionClientError> {
+        self.verify(body, signature)?;
+        WebhookPayload::from_slice(body)
+    }
+
+    fn mac(&self) -> Hmac<Sha256> {
+        Hmac::<Sha256>::new_from_slice(self.verification_token.as_bytes())
+            .expect("HMAC accepts keys of any length")
+    }
+}
+
+impl From<&VerificationRequest> for WebhookVerifier {
+    fn from(request: &VerificationRequest) -> Self {
+        Self::new(request.verification_token.clone())
+    }
+}
@@ -0,0 +1,44 @@
+//! Renders block trees, as returned by `retrieve_block_children`, into other formats.
+
+use crate::objects::rich_text::Mention;
+
+pub mod html;
+pub mod markdown;
+pub mod text;
+
+#[cfg(test)]
+mod tests;
+
+/// The text of a mention, falling back to what can be derived without the API
+/// when `plain_text` is empty, and the URL it links to.
+pub(crate) fn mention_text(mention: &Mention, plain_text: &str) -> (String, Option<String>) {
+    let or = |fallback: String| {
+        if plain_text.is_empty() {
+            fallback
+        } else {
+            plain_text.to_string()
+        }
+    };
+    match mention {
+        Mention::Page { page } => (or("Untitled".to_string()), Some(page.id.url())),
+        Mention::Database { database } => (or("Untitled".to_string()), Some(database.id.url())),
+        Mention::User { user } => (
+            or(format!("@{}", user.name.clone().unwrap_or_default())),
+            None,
+        ),
+        Mention::Date { date } => (
+            or(serde_json::to_value(date.start)
+                .ok()
+                .and_then(|start| start.as_str().map(str::to_string))
+                .unwrap_or_default()),
+            None,
+        ),
+        Mention::LinkPreview { link_preview } => {
+            (or(link_preview.url.clone()), Some(link_preview.url.clone()))
+        }
+        Mention::LinkMention { link_mention } => {
+            (or(link_mention.title.clone()), link_mention.href.clone())
+        }
+        Mention::TemplateMention { .. } => (plain_text.to_string(), None),
+    }
+}
@@ -0,0 +1,665 @@
+use std::{fmt, sync::Arc};
+
+use crate::objects::{
+    block::{Block, BlockType, HeadingsValue, TableValue},
+    color::TextColor,
+    emoji::Emoji,
+    file::File,
+    icon::Icon,
+    page::Page,
+    parent::Parent,
+    rich_text::{self, RichText},
+};
+
+/// Renders a block tree with the default [`HtmlRenderer`].
+pub fn render(blocks: &[Block]) -> String {
+    HtmlRenderer::default().render(blocks)
+}
+
+type BlockTemplate = dyn Fn(&Block, &str) -> Option<String> + Send + Sync;
+
+/// Renders block trees and rich text as semantic HTML.
+///
+/// Annotations map to `<strong>`, `<em>`, `<s>`, `<u>` and `<code>`; colors and
+/// Notion-specific layouts map to classes starting with the class prefix,
+/// `notion-` by default, e.g. `notion-callout` or `notion-red-background`.
+/// Equations are left as TeX between `\(`/`\)` and `\[`/`\]` for a client-side
+/// renderer such as KaTeX.
+#[derive(Clone)]
+pub struct HtmlRenderer {
+    class_prefix: String,
+    page_template: Option<String>,
+    block_template: Option<Arc<BlockTemplate>>,
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        HtmlRenderer {
+            class_prefix: "notion-".to_string(),
+            page_template: None,
+            block_template: None,
+        }
+    }
+}
+
+impl fmt::Debug for HtmlRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HtmlRenderer")
+            .field("class_prefix", &self.class_prefix)
+            .field("page_template", &self.page_template)
+            .field("block_template", &self.block_template.is_some())
+            .finish()
+    }
+}
+
+impl HtmlRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn class_prefix(mut self, class_prefix: impl Into<String>) -> Self {
+        self.class_prefix = class_prefix.into();
+        self
+    }
+
+    /// The document [`render_page`](Self::render_page) fills in. `{title}` is
+    /// replaced with the escaped page title and `{content}` with the rendered blocks.
+    pub fn page_template(mut self, template: impl Into<String>) -> Self {
+        self.page_template = Some(template.into());
+        self
+    }
+
+    /// Overrides how blocks are rendered. The template receives each block
+    /// with its rendered children and returns `None` to keep the default markup.
+    pub fn block_template(
+        mut self,
+        template: impl Fn(&Block, &str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.block_template = Some(Arc::new(template));
+        self
+    }
+
+    pub fn render(&self, blocks: &[Block]) -> String {
+        Context {
+            renderer: self,
+            root: blocks,
+        }
+        .blocks(blocks)
+    }
+
+    pub fn render_page(&self, page: &Page, blocks: &[Block]) -> String {
+        let title = escape(&page.title_text().unwrap_or_default());
+        let content = self.render(blocks);
+        match &self.page_template {
+            Some(template) => fill(template, &title, &content),
+            None => format!(
+                "<article class=\"{p}page\">\n<h1 class=\"{p}page-title\">{}</h1>\n{}</article>\n",
+                title,
+                content,
+                p = self.class_prefix
+            ),
+        }
+    }
+
+    pub fn render_rich_text(&self, rich_text: &[RichText]) -> String {
+        rich_text.iter().map(|rt| self.span(rt)).collect()
+    }
+
+    fn span(&self, rt: &RichText) -> String {
+        let p = &self.class_prefix;
+        let (mut html, link) = match rt {
+            RichText::None => return String::new(),
+            RichText::Text { text, href, .. } => (
+                escape(&text.content).replace('\n', "<br>"),
+                text.link
+                    .as_ref()
+                    .map(|link| link.url.clone())
+                    .or(href.clone()),
+            ),
+            RichText::Equation { equation, href, .. } => (
+                format!(
+                    "<span class=\"{}equation\">\\({}\\)</span>",
+                    p,
+                    escape(&equation.expression)
+                ),
+                href.clone(),
+            ),
+            RichText::Mention {
+                mention,
+                plain_text,
+                href,
+                ..
+            } => {
+                let (text, url) = super::mention_text(mention, plain_text);
+                (
+                    format!("<span class=\"{}mention\">{}</span>", p, escape(&text)),
+                    href.clone().or(url),
+                )
+            }
+        };
+
+        if let Some(annotations) = rt.annotations() {
+            if annotations.code {
+                html = format!("<code>{}</code>", html);
+            }
+            if annotations.underline {
+                html = format!("<u>{}</u>", html);
+            }
+            if annotations.strikethrough {
+                html = format!("<s>{}</s>", html);
+            }
+            if annotations.italic {
+                html = format!("<em>{}</em>", html);
+            }
+            if annotations.bold {
+                html = format!("<strong>{}</strong>", html);
+            }
+            if let Some(class) = self.color_class(annotations.color) {
+                html = format!("<span class=\"{}\">{}</span>", class, html);
+            }
+        }
+        match link {
+            Some(url) => format!("<a href=\"{}\">{}</a>", escape(&url), html),
+            None => html,
+        }
+    }
+
+    fn color_class(&self, color: TextColor) -> Option<String> {
+        if color == TextColor::Default {
+            return None;
+        }
+        let name = serde_json::to_value(color)
+            .ok()?
+            .as_str()?
+            .replace('_', "-");
+        Some(format!("{}{}", self.class_prefix, name))
+    }
+
+    /// `class="…"` for a block, with its color class when it has one.
+    fn class(&self, name: &str, color: Option<TextColor>) -> String {
+        match color.and_then(|color| self.color_class(color)) {
+            Some(color) => format!("class=\"{}{} {}\"", self.class_prefix, name, color),
+            None => format!("class=\"{}{}\"", self.class_prefix, name),
+        }
+    }
+}
+
+struct Context<'a> {
+    renderer: &'a HtmlRenderer,
+    /// The blocks being rendered, for the table of contents.
+    root: &'a [Block],
+}
+
+impl Context<'_> {
+    fn blocks(&self, blocks: &[Block]) -> String {
+        let mut html = String::new();
+        let mut open_list: Option<ListKind> = None;
+
+        for block in blocks {
+            let kind = ListKind::of(&block.block_type);
+            if open_list != kind {
+                if let Some(open) = open_list {
+                    html.push_str(open.close_tag());
+                }
+                if let Some(kind) = kind {
+                    html.push_str(&kind.open_tag(self.renderer));
+                }
+                open_list = kind;
+            }
+            html.push_str(&self.block(block));
+        }
+        if let Some(open) = open_list {
+            html.push_str(open.close_tag());
+        }
+        html
+    }
+
+    fn block(&self, block: &Block) -> String {
+        let children = block
+            .block_type
+            .children()
+            .map(|children| self.blocks(children))
+            .unwrap_or_default();
+        if let Some(template) = &self.renderer.block_template {
+            if let Some(html) = template(block, &children) {
+                return html;
+            }
+        }
+
+        let r = self.renderer;
+        let text = |rich_text: &[RichText]| r.render_rich_text(rich_text);
+        let nested = |html: String| {
+            if children.is_empty() {
+                html
+            } else {
+                format!(
+                    "{}<div {}>\n{}</div>\n",
+                    html,
+                    r.class("indent", None),
+                    children
+                )
+            }
+        };
+
+        match &block.block_type {
+            BlockType::Paragraph { paragraph } => nested(format!(
+                "<p {}>{}</p>\n",
+                r.class("paragraph", paragraph.color),
+                text(&paragraph.rich_text)
+            )),
+            BlockType::Heading1 { heading_1: heading } => {
+                self.heading(block, 1, heading, &children)
+            }
+            BlockType::Heading2 { heading_2: heading } => {
+                self.heading(block, 2, heading, &children)
+            }
+            BlockType::Heading3 { heading_3: heading } => {
+                self.heading(block, 3, heading, &children)
+            }
+            BlockType::Heading4 { heading_4: heading } => {
+                self.heading(block, 4, heading, &children)
+            }
+            BlockType::BulletedListItem { bulleted_list_item } => list_item(
+                &r.class("bulleted-list-item", Some(bulleted_list_item.color)),
+                text(&bulleted_list_item.rich_text),
+                &children,
+            ),
+            BlockType::NumberedListItem { numbered_list_item } => list_item(
+                &r.class("numbered-list-item", Some(numbered_list_item.color)),
+                text(&numbered_list_item.rich_text),
+                &children,
+            ),
+            BlockType::ToDo { to_do } => {
+                let checked = to_do.checked.unwrap_or_default();
+                list_item(
+                    &r.class("to-do", to_do.color),
+                    format!(
+                        "<input type=\"checkbox\" disabled{}> {}",
+                        if checked { " checked" } else { "" },
+                        text(&to_do.rich_text)
+                    ),
+                    &children,
+                )
+            }
+            BlockType::Toggle { toggle } => format!(
+                "<details {}>\n<summary>{}</summary>\n{}</details>\n",
+                r.class("toggle", Some(toggle.color)),
+                text(&toggle.rich_text),
+                children
+            ),
+            BlockType::Quote { quote } => format!(
+                "<blockquote {}>\n<p>{}</p>\n{}</blockquote>\n",
+                r.class("quote", Some(quote.color)),
+                text(&quote.rich_text),
+                children
+            ),
+            BlockType::Callout { callout } => {
+                let icon = match &callout.icon {
+                    Some(Icon::Emoji(Emoji::Emoji { emoji })) => format!(
+                        "<span {} role=\"img\">{}</span>\n",
+                        r.class("callout-icon", None),
+                        escape(emoji)
+                    ),
+                    Some(Icon::File(file)) => format!(
+                        "<img {} src=\"{}\" alt=\"\">\n",
+                        r.class("callout-icon", None),
+                        escape(file.url())
+                    ),
+                    None => String::new(),
+                };
+                format!(
+                    "<aside {}>\n{}<div {}>\n<p>{}</p>\n{}</div>\n</aside>\n",
+                    r.class("callout", Some(callout.color)),
+                    icon,
+                    r.class("callout-content", None),
+                    text(&callout.rich_text),
+                    children
+                )
+            }
+            BlockType::Code { code } => {
+                let language = code.language.info_string();
+                let pre = format!(
+                    "<pre {}><code class=\"language-{}\">{}</code></pre>\n",
+                    r.class("code", None),
+                    escape(&language),
+                    escape(&rich_text::plain_text(&code.rich_text))
+                );
+                figure(r, "code-block", pre, &code.caption)
+            }
+            BlockType::Equation { equation } => format!(
+                "<div {}>\\[{}\\]</div>\n",
+                r.class("equation", None),
+                escape(&equation.expression)
+            ),
+            BlockType::Table { table } => self.table(table),
+            BlockType::Image { image } => figure(
+                r,
+                "image",
+                format!("<img src=\"{}\" alt=\"\">\n", escape(image.file_type.url())),
+                &[],
+            ),
+            BlockType::Video { video } => figure(
+                r,
+                "video",
+                format!(
+                    "<video controls src=\"{}\"></video>\n",
+                    escape(video.file_type.url())
+                ),
+                &[],
+            ),
+            BlockType::Audio { audio } => figure(
+                r,
+                "audio",
+                format!(
+                    "<audio controls src=\"{}\"></audio>\n",
+                    escape(audio.file_type.url())
+                ),
+                &audio.caption,
+            ),
+            BlockType::Pdf { pdf } => figure(
+                r,
+                "pdf",
+                format!(
+                    "<iframe src=\"{}\" loading=\"lazy\"></iframe>\n",
+                    escape(pdf.file_type.url())
+                ),
+                &pdf.caption,
+            ),
+            BlockType::File { file } => {
+                figure(r, "file", link(&file.file_type, &file.name), &file.caption)
+            }
+            BlockType::Embed { embed } => format!(
+                "<iframe {} src=\"{}\" loading=\"lazy\"></iframe>\n",
+                r.class("embed", None),
+                escape(&embed.url)
+            ),
+            BlockType::Bookmark { bookmark } => figure(
+                r,
+                "bookmark",
+                format!(
+                    "<a href=\"{}\">{}</a>\n",
+                    escape(&bookmark.url),
+                    escape(&bookmark.url)
+                ),
+                &bookmark.caption,
+            ),
+            BlockType::LinkPreview { link_preview } => format!(
+                "<a {} href=\"{}\">{}</a>\n",
+                r.class("link-preview", None),
+                escape(&link_preview.url),
+                escape(&link_preview.url)
+            ),
+            BlockType::Divider { .. } => format!("<hr {}>\n", r.class("divider", None)),
+            BlockType::ColumnList { .. } => format!(
+                "<div {}>\n{}</div>\n",
+                r.class("column-list", None),
+                children
+            ),
+            BlockType::Column { .. } => {
+                format!("<div {}>\n{}</div>\n", r.class("column", None), children)
+            }
+            BlockType::Tab { .. } => {
+                format!("<div {}>\n{}</div>\n", r.class("tab", None), children)
+            }
+            BlockType::SyncedBlock { .. } => children,
+            BlockType::Template { template } => nested(format!(
+                "<p {}>{}</p>\n",
+                r.class("template", None),
+                text(&template.rich_text)
+            )),
+            BlockType::ChildPage { child_page } => {
+                self.page_link(block, "child-page", &child_page.title)
+            }
+            BlockType::ChildDatabase { child_database } => {
+                self.page_link(block, "child-database", &child_database.title)
+            }
+            BlockType::LinkToPage { link_to_page } => {
+                let url = match link_to_page {
+                    Parent::PageId { page_id } => page_id.url(),
+                    Parent::DatabaseId { database_id } => database_id.url(),
+                    _ => return String::new(),
+                };
+                format!(
+                    "<p {}><a href=\"{}\">{}</a></p>\n",
+                    r.class("link-to-page", None),
+                    url,
+                    url
+                )
+            }
+            BlockType::TableOfContents { table_of_contents } => {
+                self.table_of_contents(table_of_contents.color)
+            }
+            BlockType::Transcription { transcription } => format!(
+                "<div {}>\n<p>{}</p>\n</div>\n",
+                r.class("transcription", None),
+                text(&transcription.title)
+            ),
+            BlockType::None
+            | BlockType::Breadcrumb { .. }
+            | BlockType::Button { .. }
+            | BlockType::TableRow { .. }
+            | BlockType::Unsupported { .. } => String::new(),
+        }
+    }
+
+    fn heading(&self, block: &Block, level: u8, heading: &HeadingsValue, children: &str) -> String {
+        let r = self.renderer;
+        let id = block
+            .id
+            .as_ref()
+            .map(|id| format!(" id=\"{}\"", id.simple()))
+            .unwrap_or_default();
+        let html = format!(
+            "<h{level}{id} {}>{}</h{level}>",
+            r.class("heading", heading.color),
+            r.render_rich_text(&heading.rich_text),
+        );
+        if heading.is_toggleable.unwrap_or_default() {
+            format!(
+                "<details {}>\n<summary>{}</summary>\n{}</details>\n",
+                r.class("toggle-heading", None),
+                html,
+                children
+            )
+        } else {
+            format!("{}\n{}", html, children)
+        }
+    }
+
+    fn table(&self, table: &TableValue) -> String {
+        let r = self.renderer;
+        let rows = table
+            .children
+            .iter()
+            .flatten()
+            .filter_map(|block| match &block.block_type {
+                BlockType::TableRow { table_row } => Some(&table_row.cells),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let row = |cells: &Vec<Vec<RichText>>, header: bool| {
+            let cells = cells
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let content = r.render_rich_text(cell);
+                    if header {
+                        format!("<th scope=\"col\">{}</th>", content)
+                    } else if i == 0 && table.has_row_header {
+                        format!("<th scope=\"row\">{}</th>", content)
+                    } else {
+                        format!("<td>{}</td>", content)
+                    }
+                })
+                .collect::<String>();
+            format!("<tr>{}</tr>\n", cells)
+        };
+
+        let mut html = format!("<table {}>\n", r.class("table", None));
+        let mut body = rows.as_slice();
+        if table.has_column_header {
+            if let Some((header, rest)) = rows.split_first() {
+                html.push_str(&format!("<thead>\n{}</thead>\n", row(header, true)));
+                body = rest;
+            }
+        }
+        html.push_str("<tbody>\n");
+        for cells in body {
+            html.push_str(&row(cells, false));
+        }
+        html.push_str("</tbody>\n</table>\n");
+        html
+    }
+
+    fn page_link(&self, block: &Block, class: &str, title: &str) -> String {
+        let title = escape(title);
+        match &block.id {
+            Some(id) => format!(
+                "<p {}><a href=\"{}\">{}</a></p>\n",
+                self.renderer.class(class, None),
+                id.url(),
+                title
+            ),
+            None => format!("<p {}>{}</p>\n", self.renderer.class(class, None), title),
+        }
+    }
+
+    /// Links to the headings that have an id, nested by level.
+    fn table_of_contents(&self, color: TextColor) -> String {
+        let mut headings = vec![];
+        collect_headings(self.root, &mut headings);
+
+        let mut html = format!(
+            "<nav {}>\n",
+            self.renderer.class("table-of-contents", Some(color))
+        );
+        for (level, id, rich_text) in headings {
+            html.push_str(&format!(
+                "<a {} href=\"#{}\">{}</a>\n",
+                self.renderer.class(&format!("toc-{}", level), None),
+                id,
+                escape(&rich_text::plain_text(rich_text))
+            ));
+        }
+        html.push_str("</nav>\n");
+        html
+    }
+}
+
+fn collect_headings<'a>(blocks: &'a [Block], headings: &mut Vec<(u8, String, &'a [RichText])>) {
+    for block in blocks {
+        let heading = match &block.block_type {
+            BlockType::Heading1 { heading_1: h } => Some((1, h)),
+            BlockType::Heading2 { heading_2: h } => Some((2, h)),
+            BlockType::Heading3 { heading_3: h } => Some((3, h)),
+            BlockType::Heading4 { heading_4: h } => Some((4, h)),
+            _ => None,
+        };
+        if let (Some((level, heading)), Some(id)) = (heading, &block.id) {
+            headings.push((level, id.simple(), &heading.rich_text));
+        }
+        if let Some(children) = block.block_type.children() {
+            collect_headings(children, headings);
+        }
+    }
+}
+
+fn list_item(class: &str, content: String, children: &str) -> String {
+    if children.is_empty() {
+        format!("<li {}>{}</li>\n", class, content)
+    } else {
+        format!("<li {}>{}\n{}</li>\n", class, content, children)
+    }
+}
+
+fn figure(r: &HtmlRenderer, class: &str, content: String, caption: &[RichText]) -> String {
+    let caption = if caption.is_empty() {
+        String::new()
+    } else {
+        format!("<figcaption>{}</figcaption>\n", r.render_rich_text(caption))
+    };
+    format!(
+        "<figure {}>\n{}{}</figure>\n",
+        r.class(class, None),
+        content,
+        caption
+    )
+}
+
+fn link(file: &File, name: &str) -> String {
+    let url = file.url();
+    let name = if name.is_empty() { url } else { name };
+    format!("<a href=\"{}\">{}</a>\n", escape(url), escape(name))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bulleted,
+    Numbered,
+    ToDo,
+}
+
+impl ListKind {
+    fn of(block_type: &BlockType) -> Option<Self> {
+        match block_type {
+            BlockType::BulletedListItem { .. } => Some(ListKind::Bulleted),
+            BlockType::NumberedListItem { .. } => Some(ListKind::Numbered),
+            BlockType::ToDo { .. } => Some(ListKind::ToDo),
+            _ => None,
+        }
+    }
+
+    fn open_tag(self, r: &HtmlRenderer) -> String {
+        match self {
+            ListKind::Bulleted => "<ul>\n".to_string(),
+            ListKind::Numbered => "<ol>\n".to_string(),
+            ListKind::ToDo => format!("<ul {}>\n", r.class("to-do-list", None)),
+        }
+    }
+
+    fn close_tag(self) -> &'static str {
+        match self {
+            ListKind::Numbered => "</ol>\n",
+            _ => "</ul>\n",
+        }
+    }
+}
+
+/// Fills `{title}` and `{content}` in a single pass, so neither is expanded
+/// inside the other.
+fn fill(template: &str, title: &str, content: &str) -> String {
+    let mut html = String::with_capacity(template.len() + content.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        html.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("{title}") {
+            html.push_str(title);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("{content}") {
+            html.push_str(content);
+            rest = after;
+        } else {
+            html.push('{');
+            rest = &rest[1..];
+        }
+    }
+    html.push_str(rest);
+    html
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
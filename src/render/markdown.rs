@@ -0,0 +1,525 @@
+use serde_json::Value;
+
+use crate::objects::{
+    block::{Block, BlockType, HeadingsValue, TableValue},
+    emoji::Emoji,
+    file::File,
+    icon::Icon,
+    page::{Page, PageProperty},
+    parent::Parent,
+    rich_text::{self, Annotations, RichText},
+};
+
+/// Renders a block tree as GitHub-flavored Markdown. Children must already be
+/// filled in; blocks without a Markdown equivalent, such as breadcrumbs, are left out.
+pub fn render(blocks: &[Block]) -> String {
+    let mut markdown = render_blocks(blocks);
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// Like [`render`], preceded by front matter holding the page title and its
+/// properties, flattened by [`PageProperty::to_value`].
+pub fn render_page(page: &Page, blocks: &[Block]) -> String {
+    format!("{}{}", front_matter(page), render(blocks))
+}
+
+pub fn front_matter(page: &Page) -> String {
+    let mut lines = vec!["---".to_string()];
+    if let Ok(title) = page.title_text() {
+        lines.push(format!("title: {}", Value::String(title)));
+    }
+
+    let mut properties = page
+        .properties
+        .iter()
+        .filter(|(_, property)| !matches!(property, PageProperty::Title { .. }))
+        .collect::<Vec<_>>();
+    properties.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, property) in properties {
+        lines.push(format!("{}: {}", yaml_key(name), property.to_value()));
+    }
+
+    lines.push("---".to_string());
+    lines.join("\n") + "\n\n"
+}
+
+/// Renders rich text inline. Annotations map to `**`, `*`, `~~`, backticks and
+/// `<u>`; colors are dropped.
+pub fn render_rich_text(rich_text: &[RichText]) -> String {
+    let mut spans: Vec<Span> = vec![];
+    for rt in rich_text {
+        let span = Span::from(rt);
+        if let (Some(last), Body::Text(text)) = (spans.last_mut(), &span.body) {
+            if let Body::Text(last_text) = &mut last.body {
+                if last.annotations == span.annotations && last.link == span.link {
+                    last_text.push_str(text);
+                    continue;
+                }
+            }
+        }
+        spans.push(span);
+    }
+    spans.iter().map(Span::render).collect()
+}
+
+struct Span {
+    annotations: Annotations,
+    link: Option<String>,
+    body: Body,
+}
+
+enum Body {
+    /// Raw text, escaped when rendered.
+    Text(String),
+    /// Already rendered Markdown.
+    Markdown(String),
+}
+
+impl From<&RichText> for Span {
+    fn from(rt: &RichText) -> Self {
+        let annotations = rt.annotations().cloned().unwrap_or_default();
+        match rt {
+            RichText::None => Span {
+                annotations,
+                link: None,
+                body: Body::Text(String::new()),
+            },
+            RichText::Text { text, href, .. } => Span {
+                annotations,
+                link: text
+                    .link
+                    .as_ref()
+                    .map(|link| link.url.clone())
+                    .or(href.clone()),
+                body: Body::Text(text.content.clone()),
+            },
+            RichText::Equation { equation, href, .. } => Span {
+                annotations,
+                link: href.clone(),
+                body: Body::Markdown(format!("${}$", equation.expression)),
+            },
+            RichText::Mention {
+                mention,
+                plain_text,
+                href,
+                ..
+            } => {
+                let (text, url) = super::mention_text(mention, plain_text);
+                Span {
+                    annotations,
+                    link: href.clone().or(url),
+                    body: Body::Text(text),
+                }
+            }
+        }
+    }
+}
+
+impl Span {
+    fn render(&self) -> String {
+        let text = match &self.body {
+            Body::Text(text) => text.as_str(),
+            Body::Markdown(markdown) => return self.wrap_link(markdown.clone()),
+        };
+
+        let core = text.trim();
+        if core.is_empty() {
+            return text.to_string();
+        }
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        let annotations = &self.annotations;
+        let mut inner = if annotations.code {
+            code_span(core)
+        } else {
+            escape(core)
+        };
+        if annotations.underline {
+            inner = format!("<u>{}</u>", inner);
+        }
+        if annotations.strikethrough {
+            inner = format!("~~{}~~", inner);
+        }
+        if annotations.italic {
+            inner = format!("*{}*", inner);
+        }
+        if annotations.bold {
+            inner = format!("**{}**", inner);
+        }
+        format!(
+            "{}{}{}",
+            escape(leading),
+            self.wrap_link(inner),
+            escape(trailing)
+        )
+    }
+
+    fn wrap_link(&self, inner: String) -> String {
+        match &self.link {
+            Some(url) => format!("[{}]({})", inner, link_destination(url)),
+            None => inner,
+        }
+    }
+}
+
+fn render_blocks(blocks: &[Block]) -> String {
+    let mut markdown = String::new();
+    let mut previous: Option<ListKind> = None;
+    let mut number = 0;
+
+    for block in blocks {
+        let kind = ListKind::of(&block.block_type);
+        number = match kind {
+            Some(ListKind::Numbered) if previous == kind => number + 1,
+            _ => 1,
+        };
+
+        let rendered = render_block(block, number);
+        if rendered.is_empty() {
+            previous = None;
+            continue;
+        }
+        if !markdown.is_empty() {
+            markdown.push_str(if kind.is_some() && previous == kind {
+                "\n"
+            } else {
+                "\n\n"
+            });
+        }
+        markdown.push_str(&rendered);
+        previous = kind;
+    }
+    markdown
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bulleted,
+    Numbered,
+    ToDo,
+}
+
+impl ListKind {
+    fn of(block_type: &BlockType) -> Option<Self> {
+        match block_type {
+            BlockType::BulletedListItem { .. } => Some(ListKind::Bulleted),
+            BlockType::NumberedListItem { .. } => Some(ListKind::Numbered),
+            BlockType::ToDo { .. } => Some(ListKind::ToDo),
+            _ => None,
+        }
+    }
+}
+
+fn render_block(block: &Block, number: usize) -> String {
+    match &block.block_type {
+        BlockType::Paragraph { paragraph } => {
+            with_children(text(&paragraph.rich_text), &paragraph.children)
+        }
+        BlockType::Heading1 { heading_1: heading } => heading_block(1, heading),
+        BlockType::Heading2 { heading_2: heading } => heading_block(2, heading),
+        BlockType::Heading3 { heading_3: heading } => heading_block(3, heading),
+        BlockType::Heading4 { heading_4: heading } => heading_block(4, heading),
+        BlockType::BulletedListItem { bulleted_list_item } => list_item(
+            "- ",
+            &bulleted_list_item.rich_text,
+            &bulleted_list_item.children,
+        ),
+        BlockType::NumberedListItem { numbered_list_item } => list_item(
+            &format!("{}. ", number),
+            &numbered_list_item.rich_text,
+            &numbered_list_item.children,
+        ),
+        BlockType::ToDo { to_do } => list_item(
+            if to_do.checked.unwrap_or_default() {
+                "- [x] "
+            } else {
+                "- [ ] "
+            },
+            &to_do.rich_text,
+            &to_do.children,
+        ),
+        BlockType::Toggle { toggle } => {
+            let mut details = format!(
+                "<details>\n<summary>{}</summary>",
+                render_rich_text(&toggle.rich_text)
+            );
+            let children = children(&toggle.children);
+            if !children.is_empty() {
+                details.push_str("\n\n");
+                details.push_str(&children);
+            }
+            details.push_str("\n\n</details>");
+            details
+        }
+        BlockType::Quote { quote } => {
+            block_quote(&with_children(text(&quote.rich_text), &quote.children))
+        }
+        BlockType::Callout { callout } => {
+            let icon = match &callout.icon {
+                Some(Icon::Emoji(Emoji::Emoji { emoji })) => format!("{} ", emoji),
+                _ => String::new(),
+            };
+            block_quote(&with_children(
+                format!("{}{}", icon, text(&callout.rich_text)),
+                &callout.children,
+            ))
+        }
+        BlockType::Code { code } => {
+            let source = rich_text::plain_text(&code.rich_text);
+            let fence = "`".repeat(longest_run(&source, '`').max(2) + 1);
+            format!(
+                "{}{}\n{}\n{}",
+                fence,
+                code.language.info_string(),
+                source,
+                fence
+            )
+        }
+        BlockType::Equation { equation } => format!("$$\n{}\n$$", equation.expression),
+        BlockType::Table { table } => render_table(table),
+        BlockType::Image { image } => {
+            format!("![]({})", link_destination(image.file_type.url()))
+        }
+        BlockType::Video { video } => file_link(&[], &video.file_type),
+        BlockType::Audio { audio } => file_link(&audio.caption, &audio.file_type),
+        BlockType::Pdf { pdf } => file_link(&pdf.caption, &pdf.file_type),
+        BlockType::File { file } => {
+            if file.caption.is_empty() && !file.name.is_empty() {
+                format!(
+                    "[{}]({})",
+                    escape(&file.name),
+                    link_destination(file.file_type.url())
+                )
+            } else {
+                file_link(&file.caption, &file.file_type)
+            }
+        }
+        BlockType::Bookmark { bookmark } => {
+            if bookmark.caption.is_empty() {
+                autolink(&bookmark.url)
+            } else {
+                format!(
+                    "[{}]({})",
+                    render_rich_text(&bookmark.caption),
+                    link_destination(&bookmark.url)
+                )
+            }
+        }
+        BlockType::Embed { embed } => autolink(&embed.url),
+        BlockType::LinkPreview { link_preview } => autolink(&link_preview.url),
+        BlockType::Divider { .. } => "---".to_string(),
+        BlockType::ColumnList { column_list } => children(&column_list.children),
+        BlockType::Column { column } => children(&column.children),
+        BlockType::SyncedBlock { synced_block } => children(&synced_block.children),
+        BlockType::Tab { tab } => children(&tab.children),
+        BlockType::Template { template } => {
+            with_children(text(&template.rich_text), &template.children)
+        }
+        BlockType::ChildPage { child_page } => match &block.id {
+            Some(id) => format!("[{}]({})", escape(&child_page.title), id.url()),
+            None => escape_line_start(&escape(&child_page.title)),
+        },
+        BlockType::ChildDatabase { child_database } => match &block.id {
+            Some(id) => format!("[{}]({})", escape(&child_database.title), id.url()),
+            None => escape_line_start(&escape(&child_database.title)),
+        },
+        BlockType::LinkToPage { link_to_page } => match link_to_page {
+            Parent::PageId { page_id } => autolink(&page_id.url()),
+            Parent::DatabaseId { database_id } => autolink(&database_id.url()),
+            _ => String::new(),
+        },
+        BlockType::Transcription { transcription } => text(&transcription.title),
+        BlockType::None
+        | BlockType::Breadcrumb { .. }
+        | BlockType::Button { .. }
+        | BlockType::TableOfContents { .. }
+        | BlockType::TableRow { .. }
+        | BlockType::Unsupported { .. } => String::new(),
+    }
+}
+
+/// Inline text of a block; line breaks within the text become hard breaks.
+fn text(rich_text: &[RichText]) -> String {
+    escape_line_start(&render_rich_text(rich_text).replace('\n', "\\\n"))
+}
+
+fn children(children: &Option<Vec<Block>>) -> String {
+    children.as_deref().map(render_blocks).unwrap_or_default()
+}
+
+fn with_children(text: String, blocks: &Option<Vec<Block>>) -> String {
+    let children = children(blocks);
+    match (text.is_empty(), children.is_empty()) {
+        (_, true) => text,
+        (true, false) => children,
+        (false, false) => format!("{}\n\n{}", text, children),
+    }
+}
+
+fn heading_block(level: usize, heading: &HeadingsValue) -> String {
+    let title = render_rich_text(&heading.rich_text).replace('\n', " ");
+    with_children(
+        format!("{} {}", "#".repeat(level), title),
+        &heading.children,
+    )
+}
+
+fn list_item(marker: &str, rich_text: &[RichText], blocks: &Option<Vec<Block>>) -> String {
+    // Nested lists stay tight; other children need a blank line to not
+    // continue the item's paragraph.
+    let nested_list = blocks
+        .as_deref()
+        .and_then(<[Block]>::first)
+        .is_some_and(|first| ListKind::of(&first.block_type).is_some());
+    let item = match (text(rich_text), children(blocks)) {
+        (text, children) if children.is_empty() => text,
+        (text, children) if nested_list => format!("{}\n{}", text, children),
+        (text, children) => format!("{}\n\n{}", text, children),
+    };
+    let padding = " ".repeat(marker.chars().count());
+    let mut lines = item.lines();
+    let mut rendered = format!("{}{}", marker, lines.next().unwrap_or_default())
+        .trim_end()
+        .to_string();
+    for line in lines {
+        rendered.push('\n');
+        if !line.is_empty() {
+            rendered.push_str(&padding);
+            rendered.push_str(line);
+        }
+    }
+    rendered
+}
+
+fn block_quote(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// GFM tables need a header row; tables without a column header get an empty one.
+fn render_table(table: &TableValue) -> String {
+    let width = table.table_width as usize;
+    let mut rows = table
+        .children
+        .iter()
+        .flatten()
+        .filter_map(|block| match &block.block_type {
+            BlockType::TableRow { table_row } => Some(
+                (0..width)
+                    .map(|i| {
+                        table_row
+                            .cells
+                            .get(i)
+                            .map(|cell| {
+                                render_rich_text(cell)
+                                    .replace('|', "\\|")
+                                    .replace('\n', "<br>")
+                            })
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let header = if table.has_column_header && !rows.is_empty() {
+        rows.remove(0)
+    } else {
+        vec![String::new(); width]
+    };
+
+    let row = |cells: &[String]| format!("| {} |", cells.join(" | "));
+    let mut lines = vec![row(&header), row(&vec!["---".to_string(); width])];
+    lines.extend(rows.iter().map(|cells| row(cells)));
+    lines.join("\n")
+}
+
+fn file_link(caption: &[RichText], file: &File) -> String {
+    let url = file.url();
+    if caption.is_empty() {
+        autolink(url)
+    } else {
+        format!("[{}]({})", render_rich_text(caption), link_destination(url))
+    }
+}
+
+fn autolink(url: &str) -> String {
+    format!("<{}>", url.replace(' ', "%20"))
+}
+
+fn link_destination(url: &str) -> String {
+    if url.contains([' ', '(', ')']) {
+        format!("<{}>", url)
+    } else {
+        url.to_string()
+    }
+}
+
+fn code_span(code: &str) -> String {
+    let fence = "`".repeat(longest_run(code, '`') + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", fence, code, fence)
+    } else {
+        format!("{}{}{}", fence, code, fence)
+    }
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    let (mut longest, mut current) = (0, 0);
+    for ch in text.chars() {
+        current = if ch == c { current + 1 } else { 0 };
+        longest = longest.max(current);
+    }
+    longest
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '~' | '$'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes what would otherwise start a heading, list or thematic break.
+fn escape_line_start(text: &str) -> String {
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    if text.starts_with(['#', '-', '+', '=']) {
+        format!("\\{}", text)
+    } else if digits > 0 && text[digits..].starts_with(['.', ')']) {
+        format!("{}\\{}", &text[..digits], &text[digits..])
+    } else {
+        text.to_string()
+    }
+}
+
+fn yaml_key(name: &str) -> String {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        name.to_string()
+    } else {
+        Value::String(name.to_string()).to_string()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    objects::{
+        block::{
+            builder::{
+                bullet, callout, code, column, column_list, divider, equation, h1, h2, image,
+                numbered, p, quote, table, table_with_header, todo, toggle,
+            },
+            Block, BlockType, Language,
+        },
+        color::TextColor,
+        icon::Icon,
+        page::Page,
+        rich_text::RichText,
+    },
+    render::{
+        html::{self, HtmlRenderer},
+        markdown,
+        text::{self, TextRenderer},
+    },
+    rich_text,
+};
+
+fn blocks() -> Vec<Block> {
+    crate::blocks![
+        h1("Groceries"),
+        p(rich_text![
+            "Buy ",
+            RichText::text("fresh").bold(),
+            " and ",
+            RichText::text("local")
+                .italic()
+                .link("https://example.com/local"),
+            " produce, not ",
+            RichText::text("canned").strikethrough(),
+            ". Use ",
+            RichText::text("list.md").code(),
+            " for *everything*."
+        ]),
+        bullet("Vegetables").children([bullet("Kale"), bullet("Carrots")]),
+        bullet("Fruit"),
+        numbered("Wash"),
+        numbered("Chop").children([p("Into 2 cm pieces.")]),
+        todo("Pay", true),
+        todo("Cook", false),
+        toggle("Storage tips").children([p("Keep kale in the fridge.")]),
+        quote("Eat your greens."),
+        callout("Buy local", Icon::emoji("🥬")),
+        code(Language::Rust, "fn main() {\n    println!(\"```\");\n}"),
+        table_with_header([vec!["Item", "Price"], vec!["Kale | bunch", "2"]]),
+        table([vec!["a", "b"]]),
+        equation("e^{i\\pi} + 1 = 0"),
+        image("https://example.com/kale.png"),
+        divider(),
+        column_list([
+            column([h2("Left")]),
+            column([p(rich_text![
+                "1. not a list, see ",
+                RichText::equation("x^2")
+            ])]),
+        ]),
+    ]
+}
+
+#[test]
+fn test_render_markdown() {
+    assert_eq!(markdown::render(&blocks()), include_str!("tests/blocks.md"));
+}
+
+#[test]
+fn test_render_markdown_page() {
+    let page =
+        serde_json::from_str::<Page>(include_str!("../endpoints/pages/tests/retrieve_200.json"))
+            .unwrap();
+
+    let rendered = markdown::render_page(&page, &[p("Lacinato kale.")]);
+    assert_eq!(rendered, include_str!("tests/page.md"));
+}
+
+#[test]
+fn test_render_html() {
+    assert_eq!(html::render(&blocks()), include_str!("tests/blocks.html"));
+}
+
+#[test]
+fn test_render_html_templates() {
+    let page =
+        serde_json::from_str::<Page>(include_str!("../endpoints/pages/tests/retrieve_200.json"))
+            .unwrap();
+    let renderer = HtmlRenderer::new()
+        .class_prefix("kb-")
+        .page_template("<main><h1>{title}</h1>\n{content}</main>")
+        .block_template(|block, _| match &block.block_type {
+            BlockType::Divider { .. } => Some("<hr class=\"fancy\">\n".to_string()),
+            _ => None,
+        });
+
+    let rendered = renderer.render_page(
+        &page,
+        &[
+            p(RichText::text("{title} & more").color(TextColor::Red)),
+            divider(),
+        ],
+    );
+    assert_eq!(
+        rendered,
+        "<main><h1>Tuscan kale</h1>\n\
+         <p class=\"kb-paragraph\"><span class=\"kb-red\">{title} &amp; more</span></p>\n\
+         <hr class=\"fancy\">\n</main>"
+    );
+}
+
+#[test]
+fn test_render_text() {
+    assert_eq!(text::render(&blocks()), include_str!("tests/blocks.txt"));
+}
+
+#[test]
+fn test_render_text_options() {
+    let page =
+        serde_json::from_str::<Page>(include_str!("../endpoints/pages/tests/retrieve_200.json"))
+            .unwrap();
+    let renderer = TextRenderer::new()
+        .block_separator(" / ")
+        .cell_separator(", ")
+        .indent("")
+        .list_markers(false)
+        .properties(true);
+
+    let rendered = renderer.render_page(
+        &page,
+        &[
+            bullet("Kale").children([todo("Wash", true)]),
+            table([vec!["a", "b"], vec!["c", "d"]]),
+        ],
+    );
+    assert_eq!(
+        rendered,
+        "Tuscan kale / Cost of next trip: 0 / Description: A dark green leafy vegetable / \
+         Food group: 🥬 Vegetable / In stock: true / Last ordered: 2022-02-22 / \
+         Number of meals: 2 / \
+         Photo: https://i.insider.com/612fb23c9ef1e50018f93198?width=1136&format=jpeg / \
+         Price: 2.5 / \
+         Recipes: 90eeeed8-2cdd-4af4-9cc1-3d24aff5f63c, a2da43ee-d43c-4285-8ae2-6d811f12629a / \
+         Responsible Person: Cristina Cordova / \
+         Store availability: Gus's Community Market, Rainbow Grocery / \
+         Kale / Wash / a, b / c, d"
+    );
+}
@@ -0,0 +1,263 @@
+use serde_json::Value;
+
+use crate::objects::{
+    block::{Block, BlockType},
+    page::{Page, PageProperty},
+    rich_text::RichText,
+};
+
+/// Extracts the text of a block tree with the default [`TextRenderer`].
+pub fn render(blocks: &[Block]) -> String {
+    TextRenderer::default().render(blocks)
+}
+
+/// Extracts the text of block trees and pages, e.g. for a search index.
+///
+/// Every block with text, including code sources, equations, table cells and
+/// captions, becomes one entry; entries are joined with the block separator and
+/// nested blocks are indented. Children must already be filled in.
+#[derive(Debug, Clone)]
+pub struct TextRenderer {
+    block_separator: String,
+    cell_separator: String,
+    indent: String,
+    list_markers: bool,
+    captions: bool,
+    properties: bool,
+}
+
+impl Default for TextRenderer {
+    fn default() -> Self {
+        TextRenderer {
+            block_separator: "\n".to_string(),
+            cell_separator: "\t".to_string(),
+            indent: "  ".to_string(),
+            list_markers: true,
+            captions: true,
+            properties: false,
+        }
+    }
+}
+
+impl TextRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Placed between blocks and between table rows, `"\n"` by default.
+    pub fn block_separator(mut self, separator: impl Into<String>) -> Self {
+        self.block_separator = separator.into();
+        self
+    }
+
+    /// Placed between the cells of a table row, `"\t"` by default.
+    pub fn cell_separator(mut self, separator: impl Into<String>) -> Self {
+        self.cell_separator = separator.into();
+        self
+    }
+
+    /// Prefixed once per nesting level to the text of child blocks, two spaces
+    /// by default.
+    pub fn indent(mut self, indent: impl Into<String>) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    /// Whether list items start with `- `, `1. `, `[ ] ` or `[x] `.
+    pub fn list_markers(mut self, list_markers: bool) -> Self {
+        self.list_markers = list_markers;
+        self
+    }
+
+    /// Whether captions of code, file and bookmark blocks are included.
+    pub fn captions(mut self, captions: bool) -> Self {
+        self.captions = captions;
+        self
+    }
+
+    /// Whether [`render_page`](Self::render_page) lists the page properties
+    /// after the title, one `Name: value` entry each.
+    pub fn properties(mut self, properties: bool) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    pub fn render(&self, blocks: &[Block]) -> String {
+        let mut entries = vec![];
+        self.blocks(blocks, 0, &mut entries);
+        entries.join(&self.block_separator)
+    }
+
+    /// Like [`render`](Self::render), preceded by the page title and, if
+    /// enabled, its properties.
+    pub fn render_page(&self, page: &Page, blocks: &[Block]) -> String {
+        let mut entries = vec![];
+        if let Ok(title) = page.title_text() {
+            if !title.is_empty() {
+                entries.push(title);
+            }
+        }
+
+        if self.properties {
+            let mut properties = page
+                .properties
+                .iter()
+                .filter(|(_, property)| !matches!(property, PageProperty::Title { .. }))
+                .collect::<Vec<_>>();
+            properties.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, property) in properties {
+                let value = property_text(property);
+                if !value.is_empty() {
+                    entries.push(format!("{}: {}", name, value));
+                }
+            }
+        }
+
+        self.blocks(blocks, 0, &mut entries);
+        entries.join(&self.block_separator)
+    }
+
+    pub fn render_rich_text(&self, rich_text: &[RichText]) -> String {
+        rich_text.iter().map(span_text).collect()
+    }
+
+    fn blocks(&self, blocks: &[Block], depth: usize, entries: &mut Vec<String>) {
+        let mut number = 0;
+        for block in blocks {
+            number = match block.block_type {
+                BlockType::NumberedListItem { .. } => number + 1,
+                _ => 0,
+            };
+            self.block(block, depth, number, entries);
+        }
+    }
+
+    fn block(&self, block: &Block, depth: usize, number: usize, entries: &mut Vec<String>) {
+        let mut push = |text: String| {
+            if !text.is_empty() {
+                let indent = self.indent.repeat(depth);
+                let text = text.replace('\n', &format!("\n{}", indent));
+                entries.push(format!("{}{}", indent, text));
+            }
+        };
+        let text = |rich_text: &[RichText]| self.render_rich_text(rich_text);
+        let marker = |marker: String, rich_text: &[RichText]| {
+            if self.list_markers {
+                format!("{}{}", marker, text(rich_text))
+            } else {
+                text(rich_text)
+            }
+        };
+
+        match &block.block_type {
+            BlockType::BulletedListItem { bulleted_list_item } => {
+                push(marker("- ".to_string(), &bulleted_list_item.rich_text))
+            }
+            BlockType::NumberedListItem { numbered_list_item } => push(marker(
+                format!("{}. ", number),
+                &numbered_list_item.rich_text,
+            )),
+            BlockType::ToDo { to_do } => {
+                let checkbox = if to_do.checked.unwrap_or_default() {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+                push(marker(checkbox.to_string(), &to_do.rich_text))
+            }
+            BlockType::Paragraph { paragraph } => push(text(&paragraph.rich_text)),
+            BlockType::Heading1 { heading_1: heading }
+            | BlockType::Heading2 { heading_2: heading }
+            | BlockType::Heading3 { heading_3: heading }
+            | BlockType::Heading4 { heading_4: heading } => push(text(&heading.rich_text)),
+            BlockType::Quote { quote } => push(text(&quote.rich_text)),
+            BlockType::Callout { callout } => push(text(&callout.rich_text)),
+            BlockType::Toggle { toggle } => push(text(&toggle.rich_text)),
+            BlockType::Template { template } => push(text(&template.rich_text)),
+            BlockType::Code { code } => {
+                push(text(&code.rich_text));
+                if self.captions {
+                    push(text(&code.caption));
+                }
+            }
+            BlockType::Equation { equation } => push(equation.expression.clone()),
+            BlockType::ChildPage { child_page } => push(child_page.title.clone()),
+            BlockType::ChildDatabase { child_database } => push(child_database.title.clone()),
+            BlockType::Transcription { transcription } => push(text(&transcription.title)),
+            BlockType::Table { table } => {
+                for row in table.children.iter().flatten() {
+                    if let BlockType::TableRow { table_row } = &row.block_type {
+                        let cells = table_row.cells.iter().map(|cell| text(cell));
+                        push(cells.collect::<Vec<_>>().join(&self.cell_separator));
+                    }
+                }
+                return;
+            }
+            BlockType::TableRow { table_row } => {
+                let cells = table_row.cells.iter().map(|cell| text(cell));
+                push(cells.collect::<Vec<_>>().join(&self.cell_separator))
+            }
+            BlockType::Audio { audio } if self.captions => push(text(&audio.caption)),
+            BlockType::Bookmark { bookmark } if self.captions => push(text(&bookmark.caption)),
+            BlockType::File { file } if self.captions => push(text(&file.caption)),
+            BlockType::Pdf { pdf } if self.captions => push(text(&pdf.caption)),
+            _ => {}
+        }
+
+        if let Some(children) = block.block_type.children() {
+            // Columns and synced blocks only group their children.
+            let depth = match block.block_type {
+                BlockType::ColumnList { .. }
+                | BlockType::Column { .. }
+                | BlockType::SyncedBlock { .. }
+                | BlockType::Tab { .. } => depth,
+                _ => depth + 1,
+            };
+            self.blocks(children, depth, entries);
+        }
+    }
+}
+
+fn span_text(rt: &RichText) -> String {
+    match rt {
+        RichText::None => String::new(),
+        RichText::Text { text, .. } => text.content.clone(),
+        RichText::Equation { equation, .. } => equation.expression.clone(),
+        RichText::Mention {
+            mention,
+            plain_text,
+            ..
+        } => super::mention_text(mention, plain_text).0,
+    }
+}
+
+fn property_text(property: &PageProperty) -> String {
+    match property {
+        PageProperty::People { people, .. } => people
+            .iter()
+            .map(|user| user.name.clone().unwrap_or_else(|| user.id.to_string()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        property => value_text(&property.to_value()),
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        Value::Array(values) => values
+            .iter()
+            .map(value_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(values) => values
+            .values()
+            .map(value_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" – "),
+        value => value.to_string(),
+    }
+}
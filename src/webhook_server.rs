@@ -0,0 +1,316 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use tokio::net::{TcpListener, ToSocketAddrs};
+
+use crate::{
+    endpoints::Client,
+    objects::{
+        block::Block,
+        database::Database,
+        page::Page,
+        webhook::{
+            VerificationRequest, WebhookEntity, WebhookEvent, WebhookPayload, WebhookVerifier,
+            SIGNATURE_HEADER,
+        },
+    },
+    NotionClientError,
+};
+
+#[cfg(test)]
+mod tests;
+
+const DEFAULT_DEDUP_CAPACITY: usize = 1024;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type Handler = Arc<dyn Fn(WebhookContext) -> BoxFuture + Send + Sync>;
+type VerificationHandler = Arc<dyn Fn(VerificationRequest) + Send + Sync>;
+
+#[derive(Debug, Clone)]
+pub struct WebhookContext {
+    pub event: WebhookEvent,
+    pub entity: Option<FetchedEntity>,
+    pub fetch_error: Option<Arc<NotionClientError>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum FetchedEntity {
+    Page(Box<Page>),
+    Block(Box<Block>),
+    Database(Box<Database>),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Delivery {
+    Verification,
+    Dispatched,
+    Duplicate,
+}
+
+/// Receives Notion webhook deliveries, checks `X-Notion-Signature`, drops
+/// retried deliveries by event id and dispatches to the registered handlers.
+///
+/// Handlers are awaited before Notion gets its response, see
+/// [`WebhookReceiver::handle`].
+#[derive(Clone)]
+pub struct WebhookReceiver {
+    verifier: Option<WebhookVerifier>,
+    handlers: HashMap<String, Vec<Handler>>,
+    fallback: Vec<Handler>,
+    verification_handler: Option<VerificationHandler>,
+    client: Option<Client>,
+    seen: Arc<Mutex<SeenEvents>>,
+}
+
+struct SeenEvents {
+    capacity: usize,
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl WebhookReceiver {
+    pub fn new(verification_token: impl Into<String>) -> Self {
+        Self::with_verifier(Some(WebhookVerifier::new(verification_token)))
+    }
+
+    /// A receiver without a verification token, for the initial handshake.
+    /// It accepts the verification payload but rejects every event.
+    pub fn unverified() -> Self {
+        Self::with_verifier(None)
+    }
+
+    fn with_verifier(verifier: Option<WebhookVerifier>) -> Self {
+        Self {
+            verifier,
+            handlers: HashMap::new(),
+            fallback: vec![],
+            verification_handler: None,
+            client: None,
+            seen: Arc::new(Mutex::new(SeenEvents::new(DEFAULT_DEDUP_CAPACITY))),
+        }
+    }
+
+    /// Registers a handler for an event type such as `page.content_updated`.
+    pub fn on<F, Fut>(mut self, event_type: &str, handler: F) -> Self
+    where
+        F: Fn(WebhookContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.handlers
+            .entry(event_type.to_string())
+            .or_default()
+            .push(Arc::new(move |context| Box::pin(handler(context))));
+        self
+    }
+
+    /// Registers a handler for every event that has no type-specific handler.
+    pub fn on_any<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.fallback
+            .push(Arc::new(move |context| Box::pin(handler(context))));
+        self
+    }
+
+    pub fn on_verification<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(VerificationRequest) + Send + Sync + 'static,
+    {
+        self.verification_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Re-fetches the affected page, block or database before calling handlers.
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Remembers the ids of the last `capacity` events to drop redeliveries.
+    /// Clones made before this call keep their own record.
+    pub fn dedup_capacity(mut self, capacity: usize) -> Self {
+        self.seen = Arc::new(Mutex::new(SeenEvents::new(capacity)));
+        self
+    }
+
+    /// Handles one delivery. The signature is checked before the body is
+    /// parsed; only the unsigned `verification_token` handshake is accepted
+    /// without one.
+    ///
+    /// The re-fetch through the client and the handlers run before this
+    /// returns, and so before [`WebhookReceiver::into_router`] responds to
+    /// Notion. Handlers that take long should spawn their work, or Notion
+    /// times the delivery out and retries it.
+    pub async fn handle(
+        &self,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> Result<Delivery, NotionClientError> {
+        let Some(signature) = signature else {
+            let request = serde_json::from_slice::<VerificationRequest>(body)
+                .map_err(|_| NotionClientError::InvalidWebhookSignature)?;
+            return Ok(self.verification(request));
+        };
+        self.verifier
+            .as_ref()
+            .ok_or(NotionClientError::InvalidWebhookSignature)?
+            .verify(body, signature)?;
+
+        let event = match WebhookPayload::from_slice(body)? {
+            WebhookPayload::Verification(request) => return Ok(self.verification(request)),
+            WebhookPayload::Event(event) => event,
+        };
+
+        let first_delivery = self
+            .seen
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(&event.id);
+        if !first_delivery {
+            return Ok(Delivery::Duplicate);
+        }
+
+        let handlers = self
+            .handlers
+            .get(event.name())
+            .unwrap_or(&self.fallback)
+            .clone();
+        if handlers.is_empty() {
+            return Ok(Delivery::Dispatched);
+        }
+
+        let context = self.context(*event).await;
+        for handler in handlers {
+            handler(context.clone()).await;
+        }
+
+        Ok(Delivery::Dispatched)
+    }
+
+    fn verification(&self, request: VerificationRequest) -> Delivery {
+        if let Some(handler) = &self.verification_handler {
+            handler(request);
+        }
+        Delivery::Verification
+    }
+
+    pub fn into_router(self, path: &str) -> Router {
+        Router::new()
+            .route(path, post(receive))
+            .with_state(Arc::new(self))
+    }
+
+    pub async fn serve(self, addr: impl ToSocketAddrs, path: &str) -> std::io::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        axum::serve(listener, self.into_router(path)).await
+    }
+
+    async fn context(&self, event: WebhookEvent) -> WebhookContext {
+        let Some(client) = &self.client else {
+            return WebhookContext {
+                event,
+                entity: None,
+                fetch_error: None,
+            };
+        };
+
+        let fetched = match &event.entity {
+            WebhookEntity::Page { id } => Some(
+                client
+                    .pages
+                    .retrieve_a_page(id, None)
+                    .await
+                    .map(|p| FetchedEntity::Page(Box::new(p))),
+            ),
+            WebhookEntity::Block { id } => Some(
+                client
+                    .blocks
+                    .retrieve_a_block(id)
+                    .await
+                    .map(|b| FetchedEntity::Block(Box::new(b))),
+            ),
+            WebhookEntity::Database { id } => Some(
+                client
+                    .databases
+                    .retrieve_a_database(id)
+                    .await
+                    .map(|d| FetchedEntity::Database(Box::new(d))),
+            ),
+            WebhookEntity::Comment { .. } | WebhookEntity::Unknown { .. } => None,
+        };
+
+        match fetched {
+            Some(Ok(entity)) => WebhookContext {
+                event,
+                entity: Some(entity),
+                fetch_error: None,
+            },
+            Some(Err(e)) => WebhookContext {
+                event,
+                entity: None,
+                fetch_error: Some(Arc::new(e)),
+            },
+            None => WebhookContext {
+                event,
+                entity: None,
+                fetch_error: None,
+            },
+        }
+    }
+}
+
+async fn receive(
+    State(receiver): State<Arc<WebhookReceiver>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match receiver.handle(signature, &body).await {
+        Ok(_) => StatusCode::OK,
+        Err(NotionClientError::InvalidWebhookSignature) => StatusCode::UNAUTHORIZED,
+        Err(_) => StatusCode::BAD_REQUEST,
+    }
+}
+
+impl SeenEvents {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn insert(&mut self, id: &str) -> bool {
+        if self.ids.contains(id) {
+            return false;
+        }
+        if self.capacity == 0 {
+            return true;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.ids.insert(id.to_string());
+        self.order.push_back(id.to_string());
+        true
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use crate::{objects::webhook::WebhookVerifier, NotionClientError};
+
+use super::{Delivery, WebhookReceiver};
+
+const TOKEN: &str = "secret_tMrlL1qK5vuQAh1b6cZGhFChZTSYJlce98V0pYn7yBl";
+
+#[tokio::test]
+async fn test_verification() {
+    let token = Arc::new(Mutex::new(None));
+    let captured = token.clone();
+    let receiver = WebhookReceiver::unverified().on_verification(move |request| {
+        *captured.lock().unwrap() = Some(request.verification_token);
+    });
+
+    let result = receiver
+        .handle(None, include_bytes!("tests/webhook_verification.json"))
+        .await;
+
+    assert_eq!(result.unwrap(), Delivery::Verification);
+    assert_eq!(token.lock().unwrap().as_deref(), Some(TOKEN));
+}
+
+#[tokio::test]
+async fn test_dispatch_and_dedup() {
+    let body = include_bytes!("tests/webhook_page_created.json");
+    let signature = WebhookVerifier::new(TOKEN).sign(body);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let receiver = WebhookReceiver::new(TOKEN)
+        .on("page.created", move |context| {
+            let counter = counter.clone();
+            async move {
+                assert_eq!(context.event.name(), "page.created");
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .on_any(|_| async { panic!("fallback must not run") });
+
+    let first = receiver.handle(Some(&signature), body).await;
+    let second = receiver.handle(Some(&signature), body).await;
+
+    assert_eq!(first.unwrap(), Delivery::Dispatched);
+    assert_eq!(second.unwrap(), Delivery::Duplicate);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_dedup_capacity_does_not_reset_clones() {
+    let body = include_bytes!("tests/webhook_page_created.json");
+    let signature = WebhookVerifier::new(TOKEN).sign(body);
+
+    let receiver = WebhookReceiver::new(TOKEN);
+    let first = receiver.handle(Some(&signature), body).await;
+    let resized = receiver.clone().dedup_capacity(10);
+
+    assert_eq!(first.unwrap(), Delivery::Dispatched);
+    assert_eq!(
+        receiver.handle(Some(&signature), body).await.unwrap(),
+        Delivery::Duplicate
+    );
+    assert_eq!(
+        resized.handle(Some(&signature), body).await.unwrap(),
+        Delivery::Dispatched
+    );
+    assert_eq!(
+        resized.handle(Some(&signature), body).await.unwrap(),
+        Delivery::Duplicate
+    );
+}
+
+#[tokio::test]
+async fn test_fallback() {
+    let body = include_bytes!("tests/webhook_page_created.json");
+    let signature = WebhookVerifier::new(TOKEN).sign(body);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let receiver = WebhookReceiver::new(TOKEN).on_any(move |_| {
+        let counter = counter.clone();
+        async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    let result = receiver.handle(Some(&signature), body).await;
+
+    assert_eq!(result.unwrap(), Delivery::Dispatched);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_signature() {
+    let body = include_bytes!("tests/webhook_page_created.json");
+    let signature = WebhookVerifier::new("another token").sign(body);
+
+    let receiver = WebhookReceiver::new(TOKEN);
+
+    assert!(matches!(
+        receiver.handle(Some(&signature), body).await,
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+    assert!(matches!(
+        receiver.handle(None, body).await,
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+    assert!(matches!(
+        WebhookReceiver::unverified()
+            .handle(Some(&signature), body)
+            .await,
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+}
+
+#[tokio::test]
+async fn test_signature_checked_before_parsing() {
+    let body = b"not json";
+    let receiver = WebhookReceiver::new(TOKEN);
+
+    assert!(matches!(
+        receiver.handle(None, body).await,
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+    assert!(matches!(
+        receiver.handle(Some("sha256=00"), body).await,
+        Err(NotionClientError::InvalidWebhookSignature)
+    ));
+    assert!(matches!(
+        receiver
+            .handle(Some(&WebhookVerifier::new(TOKEN).sign(body)), body)
+            .await,
+        Err(NotionClientError::FailedToDeserialize { .. })
+    ));
+}